@@ -0,0 +1,17 @@
+#![no_main]
+
+use clocky::toplevel::{compile, TopLevel, TopLevelError};
+use libfuzzer_sys::fuzz_target;
+use typed_arena::Arena;
+
+// anything that typechecks should compile, and compile validates the
+// module it makes, so an invalid one comes back as a backend error.
+// egglog failing on what we give it is a bug too
+fuzz_target!(|data: &[u8]| {
+    let Ok(code) = std::str::from_utf8(data) else { return };
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    if let Err(err @ (TopLevelError::BackendError(..) | TopLevelError::OptimizerError(_))) = compile(&mut toplevel, code.to_string()) {
+        panic!("{}", err);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use clocky::toplevel::TopLevel;
+use libfuzzer_sys::fuzz_target;
+use typed_arena::Arena;
+
+// any text at all should either parse or give a parse error
+fuzz_target!(|data: &[u8]| {
+    let Ok(code) = std::str::from_utf8(data) else { return };
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let _ = toplevel.make_parser().parse_file(code);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use clocky::toplevel::TopLevel;
+use libfuzzer_sys::fuzz_target;
+use typed_arena::Arena;
+
+// run with tests/accept as a seed corpus, so that the inputs are
+// mostly programs with something changed. the ones that still parse
+// should either check or give type errors that can be shown
+fuzz_target!(|data: &[u8]| {
+    let Ok(code) = std::str::from_utf8(data) else { return };
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let Ok(file) = toplevel.make_parser().parse_file(code) else { return };
+    if let Err(errs) = toplevel.make_typechecker().check_file(&file) {
+        let _ = errs.pretty(&toplevel.interner, code).to_string();
+    }
+});
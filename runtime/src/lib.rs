@@ -17,16 +17,17 @@ pub struct Closure {
     // env: [u32],
 }
 
-// monomorphic for now!
+// monomorphic for now! samples are stored in the stream cell itself,
+// the same as the compiled code stores them
 #[repr(C)]
 pub struct Stream {
-    head: *const f32,
+    head: f32,
     tail: *const Closure,
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn hd_stream(s: *const Stream) -> f32 {
-    *(*s).head
+    (*s).head
 }
 
 #[no_mangle]
@@ -67,10 +68,8 @@ unsafe fn since_last_clock_set_tick(clock_set: &ClockSet) -> f32 {
 
 unsafe extern "C" fn since_last_tick_closure(self_: *const SinceLastTickClosure) -> *const Stream {
     let st: *mut Stream = mem::transmute(alloc(mem::size_of::<Stream>() as u32));
-    let val: *mut f32 = mem::transmute(alloc(mem::size_of::<f32>() as u32));
     let clock_set: &ClockSet = &*(*self_).clock;
-    *val = since_last_clock_set_tick(clock_set);
-    (*st).head = val;
+    (*st).head = since_last_clock_set_tick(clock_set);
     (*st).tail = mem::transmute(self_);
     st
 }
@@ -102,8 +101,8 @@ pub unsafe extern "C" fn alloc(n: u32) -> *mut () {
 }
 
 unsafe extern "C" fn unit_closure(_self_: *const Closure) -> *const () {
-    // do we even need to allocate, really?
-    alloc(4)
+    // unit is the word 0
+    ptr::null()
 }
 
 #[no_mangle]
@@ -12,6 +12,6 @@ pub fn compile(code: String) -> Result<Vec<u8>, String> {
         Ok(wasm_bytes) =>
             Ok(wasm_bytes),
         Err(err) =>
-            Err(format!("{:?}", err)),
+            Err(err.pretty(&toplevel.interner, "<input>").to_string()),
     }
 }
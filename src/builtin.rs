@@ -47,54 +47,54 @@ fn g(interner: &mut DefaultStringInterner, name: &'static str) -> Symbol {
 }
 
 /*
-const fn alloc_f32(e: &'static ir2::Expr<'static>) -> &'static ir2::Expr<'static> {
-    &ir2::Expr::Op(Op::AllocF32, &[e])
+const fn pack_f32(e: &'static ir2::Expr<'static>) -> &'static ir2::Expr<'static> {
+    &ir2::Expr::Op(Op::PackF32, &[e])
 }
 */
 
-macro_rules! alloc_f32 {
+macro_rules! pack_f32 {
     ( $e:expr ) => {
-        &ir2::Expr::Op(Op::AllocF32, &[$e])
+        &ir2::Expr::Op(Op::PackF32, &[$e])
     }
 }
 
-macro_rules! alloc_i32 {
+macro_rules! pack_i32 {
     ( $e:expr ) => {
-        &ir2::Expr::Op(Op::AllocI32, &[$e])
+        &ir2::Expr::Op(Op::PackI32, &[$e])
     }
 }
 
-macro_rules! deref_f32 {
+macro_rules! unpack_f32 {
     ( $e:expr ) => {
-        &ir2::Expr::Op(Op::DerefF32, &[$e])
+        &ir2::Expr::Op(Op::UnpackF32, &[$e])
     }
 }
 
-macro_rules! deref_i32 {
+macro_rules! unpack_i32 {
     ( $e:expr ) => {
-        &ir2::Expr::Op(Op::DerefI32, &[$e])
+        &ir2::Expr::Op(Op::UnpackI32, &[$e])
     }
 }
 
 builtins!(
     pi[0]
       { _ => Type::Sample }
-      [ alloc_f32!(&ir2::Expr::Op(Op::Pi, &[])) ],
+      [ pack_f32!(&ir2::Expr::Op(Op::Pi, &[])) ],
     sin[1]
       { _ => Type::Function(Type::Sample.into(), Type::Sample.into()) }
-      [ alloc_f32!(&ir2::Expr::Op(Op::Sin, &[deref_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
+      [ pack_f32!(&ir2::Expr::Op(Op::Sin, &[unpack_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
     cos[1]
       { _ => Type::Function(Type::Sample.into(), Type::Sample.into()) }
-      [ alloc_f32!(&ir2::Expr::Op(Op::Cos, &[deref_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
+      [ pack_f32!(&ir2::Expr::Op(Op::Cos, &[unpack_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
     reinterpi[1]
       { _ => Type::Function(Type::Index.into(), Type::Sample.into()) }
-      [ alloc_f32!(&ir2::Expr::Op(Op::ReinterpI2F, &[deref_i32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
+      [ pack_f32!(&ir2::Expr::Op(Op::ReinterpI2F, &[unpack_i32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
     reinterpf[1]
       { _ => Type::Function(Type::Sample.into(), Type::Index.into()) }
-      [ alloc_i32!(&ir2::Expr::Op(Op::ReinterpF2I, &[deref_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
+      [ pack_i32!(&ir2::Expr::Op(Op::ReinterpF2I, &[unpack_f32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
     cast[1]
       { _ => Type::Function(Type::Index.into(), Type::Sample.into()) }
-      [ alloc_f32!(&ir2::Expr::Op(Op::CastI2F, &[deref_i32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
+      [ pack_f32!(&ir2::Expr::Op(Op::CastI2F, &[unpack_i32!(&ir2::Expr::Var(DebruijnIndex(0)))])) ],
     since_tick[1]
       { i => Type::Forall(g(i, "c"), Kind::Clock, Type::Stream(Clock::from_var(g(i, "c")), Type::Sample.into()).into()) }
       [ &ir2::Expr::Op(Op::SinceLastTickStream, &[&ir2::Expr::Var(DebruijnIndex(0))]) ],
@@ -0,0 +1,242 @@
+use std::fmt;
+use std::ops::Range;
+
+use serde::Serialize;
+
+// an error message about some part of a source file, which can be
+// rendered rustc-style with the offending code underlined
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // stable identifier for the kind of error, for tools to match on
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub primary: Option<Label>,
+    pub secondary: Vec<Label>,
+    pub notes: Vec<String>,
+    // the types the error is about, already pretty-printed
+    pub types: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    fn name(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+// byte offsets into the source, plus the rows and (byte) columns
+// tree-sitter gave for them
+#[derive(Clone, Debug)]
+pub struct Label {
+    pub range: Range<usize>,
+    pub start: tree_sitter::Point,
+    pub end: tree_sitter::Point,
+    pub message: String,
+}
+
+impl Label {
+    pub fn new(range: &tree_sitter::Range, message: impl Into<String>) -> Label {
+        Label {
+            range: range.start_byte .. range.end_byte,
+            start: range.start_point,
+            end: range.end_point,
+            message: message.into(),
+        }
+    }
+}
+
+impl Diagnostic {
+    pub fn new(message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message: message.into(),
+            primary: None,
+            secondary: Vec::new(),
+            notes: Vec::new(),
+            types: Vec::new(),
+        }
+    }
+
+    pub fn with_code(self, code: &'static str) -> Diagnostic {
+        Diagnostic { code: Some(code), ..self }
+    }
+
+    pub fn with_type(mut self, ty: impl Into<String>) -> Diagnostic {
+        self.types.push(ty.into());
+        self
+    }
+
+    pub fn with_primary(self, label: Label) -> Diagnostic {
+        Diagnostic { primary: Some(label), ..self }
+    }
+
+    pub fn with_secondary(mut self, label: Label) -> Diagnostic {
+        self.secondary.push(label);
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Diagnostic {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn render<'a>(&'a self, file_name: &'a str, code: &'a str) -> RenderedDiagnostic<'a> {
+        RenderedDiagnostic { diagnostic: self, file_name, code }
+    }
+
+    // a single line of JSON, for editors and CI to consume instead of
+    // scraping the rendered form
+    pub fn to_json(&self, file_name: &str) -> String {
+        let json = JsonDiagnostic {
+            file: file_name,
+            severity: self.severity.name(),
+            code: self.code,
+            message: &self.message,
+            primary: self.primary.as_ref().map(JsonLabel::from),
+            secondary: self.secondary.iter().map(JsonLabel::from).collect(),
+            notes: &self.notes,
+            types: &self.types,
+        };
+        // only strings and numbers in there, so this can't fail
+        serde_json::to_string(&json).unwrap()
+    }
+}
+
+// what to_json writes out. the field names and their order are what
+// tools match on, so only add to these
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file: &'a str,
+    severity: &'static str,
+    code: Option<&'static str>,
+    message: &'a str,
+    primary: Option<JsonLabel<'a>>,
+    secondary: Vec<JsonLabel<'a>>,
+    notes: &'a [String],
+    types: &'a [String],
+}
+
+// rows are 0-based, as tree-sitter has them
+#[derive(Serialize)]
+struct JsonLabel<'a> {
+    byte_start: usize,
+    byte_end: usize,
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+    message: &'a str,
+}
+
+impl<'a> From<&'a Label> for JsonLabel<'a> {
+    fn from(label: &'a Label) -> JsonLabel<'a> {
+        JsonLabel {
+            byte_start: label.range.start,
+            byte_end: label.range.end,
+            line_start: label.start.row,
+            column_start: label.start.column,
+            line_end: label.end.row,
+            column_end: label.end.column,
+            message: &label.message,
+        }
+    }
+}
+
+pub struct RenderedDiagnostic<'a> {
+    diagnostic: &'a Diagnostic,
+    file_name: &'a str,
+    code: &'a str,
+}
+
+// 0-based line number and the byte offset of the start of that line
+fn line_of(code: &str, offset: usize) -> (usize, usize) {
+    let offset = offset.min(code.len());
+    let before = &code[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, line_start)
+}
+
+fn line_text(code: &str, line_start: usize) -> &str {
+    let rest = &code[line_start..];
+    &rest[..rest.find('\n').unwrap_or(rest.len())]
+}
+
+impl<'a> fmt::Display for RenderedDiagnostic<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let diag = self.diagnostic;
+        writeln!(f, "{}: {}", diag.severity.name(), diag.message)?;
+
+        let mut labels: Vec<(&Label, bool)> = diag.primary.iter().map(|l| (l, true))
+            .chain(diag.secondary.iter().map(|l| (l, false)))
+            .collect();
+        // each line's labels go outermost first, under that line
+        labels.sort_by_key(|&(l, _)| (line_of(self.code, l.range.start).0, l.range.start, usize::MAX - l.range.end));
+
+        let max_line = labels.iter().map(|&(l, _)| line_of(self.code, l.range.start).0 + 1).max().unwrap_or(0);
+        let gutter = max_line.to_string().len();
+
+        match diag.primary {
+            Some(ref primary) => {
+                let (line, line_start) = line_of(self.code, primary.range.start);
+                let column = self.code[line_start .. primary.range.start.min(self.code.len())].chars().count() + 1;
+                writeln!(f, "{:gutter$}--> {}:{}:{}", "", self.file_name, line + 1, column)?;
+            },
+            None if !labels.is_empty() || !diag.notes.is_empty() =>
+                writeln!(f, "{:gutter$}--> {}", "", self.file_name)?,
+            None => { },
+        }
+
+        if !labels.is_empty() {
+            writeln!(f, "{:gutter$} |", "")?;
+            let mut last_line = None;
+            for (label, is_primary) in labels {
+                let (line, line_start) = line_of(self.code, label.range.start);
+                let text = line_text(self.code, line_start);
+                if last_line != Some(line) {
+                    if matches!(last_line, Some(l) if l + 1 != line) {
+                        writeln!(f, "{:gutter$}...", "")?;
+                    }
+                    writeln!(f, "{:>gutter$} | {}", line + 1, text)?;
+                    last_line = Some(line);
+                }
+                // spans running onto later lines only get underlined
+                // on their first one
+                let start = (label.range.start - line_start).min(text.len());
+                let end = (label.range.end - line_start).min(text.len());
+                let start = text[..start].chars().count();
+                let end = text[..end].chars().count().max(start + 1);
+                let marker = if is_primary { "^" } else { "-" };
+                write!(f, "{:gutter$} | {:start$}{}", "", "", marker.repeat(end - start))?;
+                if label.message.is_empty() {
+                    writeln!(f)?;
+                } else {
+                    writeln!(f, " {}", label.message)?;
+                }
+            }
+        }
+
+        if !diag.notes.is_empty() {
+            writeln!(f, "{:gutter$} |", "")?;
+            for note in diag.notes.iter() {
+                let mut lines = note.lines();
+                writeln!(f, "{:gutter$} = note: {}", "", lines.next().unwrap_or(""))?;
+                for line in lines {
+                    writeln!(f, "{:gutter$}         {}", "", line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
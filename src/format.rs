@@ -0,0 +1,558 @@
+use num::One;
+use string_interner::DefaultStringInterner;
+
+use crate::expr::{Binop, Expr, SourceFile, Symbol, TopLevelDef, TopLevelDefBody, Value};
+use crate::typing::{Clock, Type};
+
+// lines get broken up once they would go past this
+const WIDTH: usize = 80;
+
+// a document in the style of wadler's "a prettier printer": groups
+// get printed on one line if they fit, and otherwise have each of
+// their lines broken
+enum Doc {
+    Text(String),
+    // a space, or a newline if the enclosing group doesn't fit
+    Line,
+    // always a newline, and so never fits
+    HardLine,
+    Nest(usize, Box<Doc>),
+    // nests to whatever column this starts at
+    Align(Box<Doc>),
+    Group(Box<Doc>),
+    Concat(Vec<Doc>),
+}
+
+fn text(s: impl Into<String>) -> Doc {
+    Doc::Text(s.into())
+}
+
+fn nest(indent: usize, doc: Doc) -> Doc {
+    Doc::Nest(indent, Box::new(doc))
+}
+
+fn align(doc: Doc) -> Doc {
+    Doc::Align(Box::new(doc))
+}
+
+fn group(doc: Doc) -> Doc {
+    Doc::Group(Box::new(doc))
+}
+
+fn concat(docs: Vec<Doc>) -> Doc {
+    Doc::Concat(docs)
+}
+
+// whether the doc fits in the given width when printed on one line
+fn fits(doc: &Doc, width: usize) -> bool {
+    let mut left = width;
+    let mut stack = vec![doc];
+    while let Some(doc) = stack.pop() {
+        let len = match *doc {
+            Doc::Text(ref s) if s.contains('\n') => return false,
+            Doc::Text(ref s) => s.chars().count(),
+            Doc::Line => 1,
+            Doc::HardLine => return false,
+            Doc::Nest(_, ref doc) | Doc::Align(ref doc) | Doc::Group(ref doc) => {
+                stack.push(doc);
+                0
+            },
+            Doc::Concat(ref docs) => {
+                stack.extend(docs.iter().rev());
+                0
+            },
+        };
+        left = match left.checked_sub(len) {
+            Some(left) => left,
+            None => return false,
+        };
+    }
+    true
+}
+
+fn render(doc: &Doc, out: &mut String) {
+    let mut col = 0;
+    // indentation, whether lines get broken, and what's left to print
+    let mut stack = vec![(0, true, doc)];
+    while let Some((indent, broken, doc)) = stack.pop() {
+        match *doc {
+            Doc::Text(ref s) => {
+                out.push_str(s);
+                col = match s.rfind('\n') {
+                    Some(i) => s[i + 1..].chars().count(),
+                    None => col + s.chars().count(),
+                };
+            },
+            Doc::Line if !broken => {
+                out.push(' ');
+                col += 1;
+            },
+            Doc::Line | Doc::HardLine => {
+                out.truncate(out.trim_end_matches(' ').len());
+                out.push('\n');
+                out.push_str(&" ".repeat(indent));
+                col = indent;
+            },
+            Doc::Nest(n, ref doc) =>
+                stack.push((indent + n, broken, doc)),
+            Doc::Align(ref doc) =>
+                stack.push((col, broken, doc)),
+            Doc::Group(ref doc) =>
+                stack.push((indent, broken && !fits(doc, WIDTH.saturating_sub(col)), doc)),
+            Doc::Concat(ref docs) =>
+                stack.extend(docs.iter().rev().map(|doc| (indent, broken, doc))),
+        }
+    }
+}
+
+// how tightly each kind of expression binds. anything that binds less
+// tightly than its position calls for gets parenthesized
+const OPEN: u8 = 0; // runs on as far to the right as it can, e.g. \x. e
+const POSTFIX: u8 = 1; // e @(k), e $(ty), which take everything to their left
+const GEN: u8 = 2;
+const CMP: u8 = 3;
+const ADD: u8 = 4;
+const MUL: u8 = 5;
+const APP: u8 = 6;
+const KEYWORD: u8 = 7; // box e, inl e, ...
+const PREFIX: u8 = 8; // !e, %e, `e
+const ATOM: u8 = 9;
+
+fn binop_level(op: Binop) -> u8 {
+    match op {
+        Binop::FMul | Binop::FDiv | Binop::IMul | Binop::IDiv =>
+            MUL,
+        Binop::FAdd | Binop::FSub | Binop::IAdd | Binop::ISub |
+        Binop::Shl | Binop::Shr | Binop::And | Binop::Xor | Binop::Or =>
+            ADD,
+        _ =>
+            CMP,
+    }
+}
+
+fn binop_text(op: Binop) -> &'static str {
+    match op {
+        Binop::FMul => "*",
+        Binop::FDiv => "/",
+        Binop::FAdd => "+",
+        Binop::FSub => "-",
+        Binop::FGt => ">",
+        Binop::FGe => ">=",
+        Binop::FLt => "<",
+        Binop::FLe => "<=",
+        Binop::FEq => "==",
+        Binop::FNe => "!=",
+        Binop::Shl => ".<<.",
+        Binop::Shr => ".>>.",
+        Binop::And => ".&.",
+        Binop::Xor => ".^.",
+        Binop::Or => ".|.",
+        Binop::IMul => ".*.",
+        Binop::IDiv => "./.",
+        Binop::IAdd => ".+.",
+        Binop::ISub => ".-.",
+        Binop::IGt => ".>.",
+        Binop::IGe => ".>=.",
+        Binop::ILt => ".<.",
+        Binop::ILe => ".<=.",
+        Binop::IEq => ".==.",
+        Binop::INe => ".!=.",
+    }
+}
+
+struct Formatter<'a> {
+    interner: &'a DefaultStringInterner,
+    code: &'a str,
+    // every comment in the file, in order, and how many have been
+    // printed so far
+    comments: Vec<tree_sitter::Range>,
+    printed_comments: usize,
+}
+
+impl<'a> Formatter<'a> {
+    fn name(&self, x: Symbol) -> &'a str {
+        self.interner.resolve(x).expect("encountered a symbol not corresponding to an identifier while formatting")
+    }
+
+    fn source(&self, range: &tree_sitter::Range) -> &'a str {
+        &self.code[range.start_byte..range.end_byte]
+    }
+
+    fn next_comment_before(&mut self, byte: usize) -> Option<tree_sitter::Range> {
+        let comment = *self.comments.get(self.printed_comments)?;
+        if comment.start_byte < byte {
+            self.printed_comments += 1;
+            Some(comment)
+        } else {
+            None
+        }
+    }
+
+    // the comments before this point, each on its own line
+    fn comments_before(&mut self, byte: usize) -> Vec<Doc> {
+        let mut docs = Vec::new();
+        while let Some(comment) = self.next_comment_before(byte) {
+            docs.push(text(self.source(&comment).trim_end()));
+            docs.push(Doc::HardLine);
+        }
+        docs
+    }
+
+    fn clock(&self, clock: &Clock) -> String {
+        let name = self.name(clock.var);
+        if clock.coeff.is_one() {
+            name.to_string()
+        } else if clock.coeff.is_integer() {
+            format!("{}{}", clock.coeff, name)
+        } else {
+            format!("{} {}", clock.coeff, name)
+        }
+    }
+
+    // types never get broken over lines, so they just get written out
+    fn type_(&self, ty: &Type, prec: u8) -> String {
+        // function types are 0, sums 1, products 2, and 3 is as tight
+        // as it gets
+        let (level, s) = match *ty {
+            Type::Unit =>
+                (3, "unit".to_string()),
+            Type::Sample =>
+                (3, "sample".to_string()),
+            Type::Index =>
+                (3, "index".to_string()),
+            Type::TypeVar(x) =>
+                (3, self.name(x).to_string()),
+            Type::Stream(ref clock, ref ty) =>
+                (3, format!("~^({}) {}", self.clock(clock), self.type_(ty, 3))),
+            Type::Later(ref clock, ref ty) =>
+                (3, format!("|>^({}) {}", self.clock(clock), self.type_(ty, 3))),
+            Type::Box(ref ty) => {
+                let inner = self.type_(ty, 3);
+                (3, if inner.starts_with('(') { format!("[]{}", inner) } else { format!("[] {}", inner) })
+            },
+            Type::Array(ref ty, ref size) =>
+                (3, format!("[{}; {}]", self.type_(ty, 0), size)),
+            Type::Product(ref ty1, ref ty2) =>
+                (2, format!("{} * {}", self.type_(ty1, 3), self.type_(ty2, 2))),
+            Type::Sum(ref ty1, ref ty2) =>
+                (1, format!("{} + {}", self.type_(ty1, 2), self.type_(ty2, 1))),
+            Type::Function(ref ty1, ref ty2) =>
+                (0, format!("{} -> {}", self.type_(ty1, 1), self.type_(ty2, 0))),
+            Type::Forall(x, k, ref ty) =>
+                (0, format!("for {} : {}. {}", self.name(x), k, self.type_(ty, 0))),
+            Type::Exists(x, ref ty) =>
+                (0, format!("?{}. {}", self.name(x), self.type_(ty, 0))),
+        };
+        if level < prec { format!("({})", s) } else { s }
+    }
+
+    // `left` is whether nothing to the left could take part of this
+    // expression for itself, which is what lets @(k) and $(ty) go
+    // without parentheses
+    fn level(&self, expr: &Expr<'_, tree_sitter::Range>, left: bool) -> u8 {
+        match *expr {
+            Expr::Val(ref r, Value::Sample(_)) if self.source(r).starts_with(['-', '+']) =>
+                APP,
+            Expr::Var(..) | Expr::Val(..) | Expr::Hole(..) | Expr::Annotate(..) |
+            Expr::Pair(..) | Expr::Array(..) =>
+                ATOM,
+            Expr::Adv(..) | Expr::UnGen(..) | Expr::Delay(..) =>
+                PREFIX,
+            Expr::Box(..) | Expr::Unbox(..) | Expr::InL(..) | Expr::InR(..) =>
+                KEYWORD,
+            Expr::App(..) =>
+                APP,
+            Expr::Binop(_, op, _, _) =>
+                binop_level(op),
+            Expr::Gen(..) =>
+                GEN,
+            Expr::ClockApp(..) | Expr::TypeApp(..) =>
+                if left { ATOM } else { POSTFIX },
+            Expr::Lam(..) | Expr::Lob(..) | Expr::LetIn(..) | Expr::UnPair(..) |
+            Expr::Case(..) | Expr::ExIntro(..) | Expr::ExElim(..) =>
+                OPEN,
+            Expr::ClockLam(_, _, e) =>
+                self.level(e, left),
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr<'_, tree_sitter::Range>, prec: u8, left: bool) -> Doc {
+        let mut docs = self.comments_before(expr.range().start_byte);
+        if self.level(expr, left) < prec {
+            // lets only get a line to themselves when they aren't
+            // tucked away in parentheses
+            let inner = match *expr {
+                Expr::LetIn(..) | Expr::UnPair(..) | Expr::ExElim(..) =>
+                    group(self.let_(expr, Doc::Line)),
+                _ =>
+                    self.expr_unparenthesized(expr, true),
+            };
+            docs.push(text("("));
+            docs.push(align(inner));
+            docs.push(text(")"));
+        } else {
+            docs.push(self.expr_unparenthesized(expr, left));
+        }
+        concat(docs)
+    }
+
+    // any of the kinds of let, with `after_in` between the `in` and
+    // the body
+    fn let_(&mut self, expr: &Expr<'_, tree_sitter::Range>, after_in: Doc) -> Doc {
+        let (header, bound, body) = match *expr {
+            Expr::LetIn(_, x, Some(ref ty), e1, e2) =>
+                (format!("let {}: {} =", self.name(x), self.type_(ty, 0)), e1, e2),
+            Expr::LetIn(_, x, None, e1, e2) =>
+                (format!("let {} =", self.name(x)), e1, e2),
+            Expr::UnPair(_, x1, x2, e1, e2) =>
+                (format!("let ({}, {}) =", self.name(x1), self.name(x2)), e1, e2),
+            Expr::ExElim(_, c, x, e1, e2) =>
+                (format!("let clock {} and {} =", self.name(c), self.name(x)), e1, e2),
+            _ =>
+                unreachable!("only lets get formatted as lets"),
+        };
+        concat(vec![
+            group(concat(vec![
+                text(header),
+                nest(2, concat(vec![Doc::Line, self.expr(bound, OPEN, true)])),
+                Doc::Line,
+                text("in"),
+            ])),
+            after_in,
+            self.expr(body, OPEN, true),
+        ])
+    }
+
+    fn prefix(&mut self, op: &str, expr: &Expr<'_, tree_sitter::Range>) -> Doc {
+        concat(vec![text(op), self.expr(expr, ATOM, false)])
+    }
+
+    fn expr_unparenthesized(&mut self, expr: &Expr<'_, tree_sitter::Range>, left: bool) -> Doc {
+        match *expr {
+            Expr::Var(_, x) =>
+                text(self.name(x)),
+            Expr::Val(_, Value::Unit) =>
+                text("()"),
+            // keep literals the way they were written, e.g. in hex
+            Expr::Val(ref r, _) =>
+                text(self.source(r)),
+            Expr::Hole(_, Some(x)) =>
+                text(format!("_{}", self.name(x))),
+            Expr::Hole(_, None) =>
+                text("_"),
+            Expr::Annotate(_, e, ref ty) =>
+                concat(vec![
+                    text("("),
+                    align(concat(vec![self.expr(e, CMP, true), text(format!(" : {}", self.type_(ty, 0)))])),
+                    text(")"),
+                ]),
+            Expr::App(..) => {
+                let mut args = Vec::new();
+                let mut head = expr;
+                while let Expr::App(_, e1, e2) = *head {
+                    args.push(e2);
+                    head = e1;
+                }
+                let head_doc = self.expr(head, APP, left);
+                let mut arg_docs = Vec::new();
+                for arg in args.into_iter().rev() {
+                    arg_docs.push(Doc::Line);
+                    arg_docs.push(self.expr(arg, PREFIX, false));
+                }
+                group(concat(vec![head_doc, nest(2, concat(arg_docs))]))
+            },
+            Expr::Lam(..) | Expr::Lob(..) => {
+                let mut binders = Vec::new();
+                let mut body = expr;
+                loop {
+                    body = match *body {
+                        Expr::Lam(_, x, e) => {
+                            binders.push(format!("\\{}.", self.name(x)));
+                            e
+                        },
+                        Expr::Lob(_, ref clock, x, e) => {
+                            binders.push(format!("&^({}) {}.", self.clock(clock), self.name(x)));
+                            e
+                        },
+                        _ =>
+                            break,
+                    };
+                }
+                group(concat(vec![
+                    text(binders.join(" ")),
+                    nest(2, concat(vec![Doc::Line, self.expr(body, OPEN, true)])),
+                ]))
+            },
+            Expr::Gen(_, e1, e2) =>
+                group(concat(vec![
+                    self.expr(e1, CMP, left),
+                    text(" ::"),
+                    nest(2, concat(vec![Doc::Line, self.expr(e2, GEN, true)])),
+                ])),
+            Expr::LetIn(..) | Expr::UnPair(..) | Expr::ExElim(..) =>
+                self.let_(expr, Doc::HardLine),
+            Expr::Pair(_, e1, e2) =>
+                group(concat(vec![
+                    text("("),
+                    align(concat(vec![self.expr(e1, OPEN, true), text(","), Doc::Line, self.expr(e2, OPEN, true)])),
+                    text(")"),
+                ])),
+            Expr::Case(_, e0, x1, e1, x2, e2) =>
+                concat(vec![
+                    text("case "),
+                    self.expr(e0, CMP, true),
+                    text(" {"),
+                    nest(2, concat(vec![
+                        Doc::HardLine,
+                        text(format!("inl {} => ", self.name(x1))),
+                        align(self.expr(e1, OPEN, true)),
+                    ])),
+                    Doc::HardLine,
+                    text(format!("| inr {} => ", self.name(x2))),
+                    align(self.expr(e2, OPEN, true)),
+                    Doc::HardLine,
+                    text("}"),
+                ]),
+            Expr::Array(_, ref es) => {
+                let mut docs = Vec::new();
+                for (i, e) in es.iter().enumerate() {
+                    if i > 0 {
+                        docs.push(text(","));
+                        docs.push(Doc::Line);
+                    }
+                    docs.push(self.expr(e, OPEN, true));
+                }
+                group(concat(vec![text("["), align(concat(docs)), text("]")]))
+            },
+            Expr::Adv(_, e) =>
+                self.prefix("!", e),
+            Expr::UnGen(_, e) =>
+                self.prefix("%", e),
+            Expr::Delay(_, e) =>
+                self.prefix("`", e),
+            Expr::Box(_, e) =>
+                self.prefix("box ", e),
+            Expr::Unbox(_, e) =>
+                self.prefix("unbox ", e),
+            Expr::InL(_, e) =>
+                self.prefix("inl ", e),
+            Expr::InR(_, e) =>
+                self.prefix("inr ", e),
+            Expr::ClockApp(_, e, ref clock) =>
+                concat(vec![self.expr(e, KEYWORD, left), text(format!(" @({})", self.clock(clock)))]),
+            Expr::TypeApp(_, e, ref ty) =>
+                concat(vec![self.expr(e, KEYWORD, left), text(format!(" $({})", self.type_(ty, 0)))]),
+            Expr::Binop(_, op, e1, e2) => {
+                let level = binop_level(op);
+                group(concat(vec![
+                    self.expr(e1, level, left),
+                    nest(2, concat(vec![Doc::Line, text(format!("{} ", binop_text(op))), self.expr(e2, level + 1, false)])),
+                ]))
+            },
+            Expr::ExIntro(_, ref clock, e) =>
+                group(concat(vec![
+                    text(format!("clock {} and", self.clock(clock))),
+                    nest(2, concat(vec![Doc::Line, self.expr(e, OPEN, true)])),
+                ])),
+            // clock lambdas only come out of elaboration, and are
+            // implicit in the surface syntax
+            Expr::ClockLam(_, _, e) =>
+                self.expr_unparenthesized(e, left),
+        }
+    }
+
+    fn def(&mut self, def: &TopLevelDef<'_, tree_sitter::Range>) -> Doc {
+        let name = self.name(def.name);
+        match def.body {
+            TopLevelDefBody::Def { kind, ref type_, expr } => {
+                // long signatures get broken after their quantifiers
+                let mut quantifiers = String::new();
+                let mut ty = type_;
+                while let Type::Forall(x, k, ref inner) = *ty {
+                    quantifiers.push_str(&format!("for {} : {}. ", self.name(x), k));
+                    ty = inner;
+                }
+                let header = group(concat(vec![
+                    text(format!("{} {}: {}", kind, name, quantifiers.trim_end())),
+                    nest(2, concat(vec![if quantifiers.is_empty() { text("") } else { Doc::Line }, text(self.type_(ty, 0))])),
+                    text(" ="),
+                ]));
+                let body = self.expr(expr, OPEN, true);
+                let mut docs = vec![group(concat(vec![header, nest(2, concat(vec![Doc::Line, body]))]))];
+                // anything after the body
+                while let Some(comment) = self.next_comment_before(def.range.end_byte) {
+                    docs.push(text(format!(" {}", self.source(&comment).trim_end())));
+                    docs.push(Doc::HardLine);
+                }
+                docs.push(text(";;"));
+                concat(docs)
+            },
+            TopLevelDefBody::Clock { freq } =>
+                text(format!("clock {} of frequency {} Hz;;", name, freq)),
+        }
+    }
+}
+
+fn collect_comments(node: tree_sitter::Node<'_>, comments: &mut Vec<tree_sitter::Range>) {
+    if node.kind() == "comment" {
+        comments.push(node.range());
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_comments(child, comments);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Item {
+    Def,
+    // a comment on the same line as the end of what came before it
+    TrailingComment,
+    Comment,
+}
+
+// prints the file back out as source code, with the comments from
+// the tree it was parsed from. blank lines are only kept around
+// top-level comments; definitions always get one between them
+pub fn format_file(interner: &DefaultStringInterner, code: &str, tree: &tree_sitter::Tree, file: &SourceFile<'_, tree_sitter::Range>) -> String {
+    let mut comments = Vec::new();
+    collect_comments(tree.root_node(), &mut comments);
+    let mut formatter = Formatter { interner, code, comments, printed_comments: 0 };
+
+    let mut out = String::new();
+    // the last item printed, and which row of the source it ended on
+    let mut last: Option<(Item, usize)> = None;
+    let mut defs = file.defs.iter().peekable();
+    loop {
+        let next_def_start = defs.peek().map_or(code.len(), |def| def.range.start_byte);
+        let (item, range, doc) = match formatter.next_comment_before(next_def_start) {
+            Some(comment) => {
+                let item = match last {
+                    Some((_, row)) if row == comment.start_point.row => Item::TrailingComment,
+                    _ => Item::Comment,
+                };
+                (item, comment, text(formatter.source(&comment).trim_end()))
+            },
+            None => match defs.next() {
+                Some(def) => (Item::Def, def.range, formatter.def(def)),
+                None => break,
+            },
+        };
+        match last {
+            None => { },
+            Some(_) if item == Item::TrailingComment =>
+                out.push(' '),
+            Some((Item::Def | Item::TrailingComment, _)) if item == Item::Def =>
+                out.push_str("\n\n"),
+            Some((_, row)) if range.start_point.row > row + 1 =>
+                out.push_str("\n\n"),
+            Some(_) =>
+                out.push('\n'),
+        }
+        render(&doc, &mut out);
+        last = Some((item, range.end_point.row));
+    }
+    out.push('\n');
+    out
+}
@@ -0,0 +1,623 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use num::rational::Ratio;
+
+use crate::builtin::BuiltinsMap;
+use crate::expr::{Expr, SourceFile, Symbol, TopLevelDefBody, TopLevelDefKind, Value as HValue};
+use crate::ir1::{DebruijnIndex, Op};
+use crate::ir2;
+use crate::typing::Clock;
+
+// a tree-walking interpreter for elaborated programs, as a reference
+// for what the compiled code should do. it goes by the same
+// operational semantics: delays are thunks that get run the first time
+// they're advanced, boxes get rerun each time they're unboxed, and a
+// lob unboxes to itself, later. the scheduler is a simulation of the
+// runtime's, stepped a sample at a time.
+
+// a clock at runtime, with the coefficient it was applied with. like
+// the runtime's scheduler, ours only goes by which clock it is
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClockValue {
+    pub id: usize,
+    pub coeff: Ratio<u32>,
+}
+
+#[derive(Clone)]
+pub enum Value<'a, R> {
+    Unit,
+    Sample(f32),
+    Index(usize),
+    Pair(Rc<Value<'a, R>>, Rc<Value<'a, R>>),
+    InL(Rc<Value<'a, R>>),
+    InR(Rc<Value<'a, R>>),
+    Array(Rc<[Value<'a, R>]>),
+    // a head and a later tail
+    Stream(Rc<Value<'a, R>>, Rc<Value<'a, R>>),
+    Closure(Rc<Env<'a, R>>, Symbol, &'a Expr<'a, R>),
+    ClockClosure(Rc<Env<'a, R>>, Symbol, &'a Expr<'a, R>),
+    // a builtin's arity and body, and the arguments it's got so far
+    Builtin(usize, &'static ir2::Expr<'static>, Rc<[Value<'a, R>]>),
+    Box(Rc<Env<'a, R>>, &'a Expr<'a, R>),
+    // what a lob binds: unboxing it gives the lob itself, later
+    Fix(Rc<Env<'a, R>>, &'a Expr<'a, R>),
+    // a thunk in the interpreter's heap. keeping them there, rather
+    // than behind Rcs, means a long stream isn't a long chain of
+    // pointers to drop. the ones nothing can reach any more get
+    // collected between samples
+    Later(usize),
+    Clock(ClockValue),
+    ClockEx(ClockValue, Rc<Value<'a, R>>),
+}
+
+impl<'a, R> Value<'a, R> {
+    // comparisons give a unit + unit, which is inl for true, as in
+    // the compiled code
+    fn bool(b: bool) -> Value<'a, R> {
+        if b {
+            Value::InL(Rc::new(Value::Unit))
+        } else {
+            Value::InR(Rc::new(Value::Unit))
+        }
+    }
+
+    fn sample(&self) -> f32 {
+        match *self {
+            Value::Sample(x) => x,
+            _ => panic!("expected a sample"),
+        }
+    }
+
+    // indices are 32 bits in the compiled code, so they wrap like it
+    fn index(&self) -> u32 {
+        match *self {
+            Value::Index(i) => i as u32,
+            _ => panic!("expected an index"),
+        }
+    }
+
+    fn clock(&self) -> ClockValue {
+        match *self {
+            Value::Clock(c) => c,
+            _ => panic!("expected a clock"),
+        }
+    }
+}
+
+pub enum Env<'a, R> {
+    Empty,
+    TermVar(Symbol, Value<'a, R>, Rc<Env<'a, R>>),
+    ClockVar(Symbol, ClockValue, Rc<Env<'a, R>>),
+}
+
+impl<'a, R> Env<'a, R> {
+    fn lookup_term_var(&self, x: Symbol) -> Option<&Value<'a, R>> {
+        match *self {
+            Env::Empty => None,
+            Env::TermVar(y, ref v, _) if x == y => Some(v),
+            Env::TermVar(_, _, ref next) |
+            Env::ClockVar(_, _, ref next) => next.lookup_term_var(x),
+        }
+    }
+
+    fn lookup_clock_var(&self, x: Symbol) -> Option<ClockValue> {
+        match *self {
+            Env::Empty => None,
+            Env::ClockVar(y, c, _) if x == y => Some(c),
+            Env::TermVar(_, _, ref next) |
+            Env::ClockVar(_, _, ref next) => next.lookup_clock_var(x),
+        }
+    }
+}
+
+fn with_term_var<'a, R>(env: &Rc<Env<'a, R>>, x: Symbol, v: Value<'a, R>) -> Rc<Env<'a, R>> {
+    Rc::new(Env::TermVar(x, v, env.clone()))
+}
+
+fn with_clock_var<'a, R>(env: &Rc<Env<'a, R>>, x: Symbol, c: ClockValue) -> Rc<Env<'a, R>> {
+    Rc::new(Env::ClockVar(x, c, env.clone()))
+}
+
+enum Thunk<'a, R> {
+    // a delay, which gets run the first time it's advanced
+    Delayed(Rc<Env<'a, R>>, &'a Expr<'a, R>),
+    Forced(Value<'a, R>),
+    // the rest of a since_tick stream
+    SinceTick(ClockValue),
+    // what sched gives: what its task found, if it ran
+    Scheduled(Option<Value<'a, R>>),
+    // being advanced right now
+    Forcing,
+    // collected, and on the free list
+    Free,
+}
+
+enum ClockState {
+    Audio,
+    Periodic { period: f32, remaining: f32 },
+}
+
+impl ClockState {
+    // same as the runtime's
+    fn pass_time(&mut self, dur: f32) -> bool {
+        match *self {
+            ClockState::Audio =>
+                true,
+            ClockState::Periodic { period, ref mut remaining } =>
+                if dur > *remaining {
+                    *remaining = period - (dur - *remaining) % period;
+                    true
+                } else {
+                    *remaining -= dur;
+                    false
+                },
+        }
+    }
+
+    fn since_last_tick(&self) -> f32 {
+        match *self {
+            ClockState::Audio => 1. / 48e3,
+            ClockState::Periodic { period, .. } => period,
+        }
+    }
+}
+
+// runs the later value when the triggering clock ticks, filling in
+// the scheduled thunk, unless the cancelling clock ticks first
+struct Task<'a, R> {
+    triggering: usize,
+    cancelling: usize,
+    later: Value<'a, R>,
+    thunk: usize,
+}
+
+// the heap doesn't get collected until it's at least this big, so
+// small programs don't collect every sample
+const MIN_COLLECT: usize = 1024;
+
+// finds the thunks reachable from some values. envs are shared by
+// everything closed over them, so each only gets gone through once
+struct Marker<'a, R> {
+    marked: Vec<bool>,
+    envs: HashSet<*const Env<'a, R>>,
+    // marked thunks that haven't been gone through yet
+    pending: Vec<usize>,
+}
+
+impl<'a, R> Marker<'a, R> {
+    fn value(&mut self, v: &Value<'a, R>) {
+        match *v {
+            Value::Unit | Value::Sample(_) | Value::Index(_) | Value::Clock(_) => {},
+            Value::Pair(ref v1, ref v2) | Value::Stream(ref v1, ref v2) => {
+                self.value(v1);
+                self.value(v2);
+            },
+            Value::InL(ref v) | Value::InR(ref v) | Value::ClockEx(_, ref v) =>
+                self.value(v),
+            Value::Array(ref vs) | Value::Builtin(_, _, ref vs) =>
+                for v in vs.iter() {
+                    self.value(v);
+                },
+            Value::Closure(ref env, _, _) | Value::ClockClosure(ref env, _, _) |
+            Value::Box(ref env, _) | Value::Fix(ref env, _) =>
+                self.env(env),
+            Value::Later(i) =>
+                if !self.marked[i] {
+                    self.marked[i] = true;
+                    self.pending.push(i);
+                },
+        }
+    }
+
+    fn env(&mut self, mut env: &Rc<Env<'a, R>>) {
+        while self.envs.insert(Rc::as_ptr(env)) {
+            match **env {
+                Env::Empty => break,
+                Env::TermVar(_, ref v, ref next) => {
+                    self.value(v);
+                    env = next;
+                },
+                Env::ClockVar(_, _, ref next) =>
+                    env = next,
+            }
+        }
+    }
+}
+
+pub struct Interpreter<'a, 'b, R> {
+    builtins: &'b BuiltinsMap,
+    globals: HashMap<Symbol, Value<'a, R>>,
+    // the globals that only the definitions after them can use, since
+    // they're lets that aren't stable
+    time_zero_globals: Vec<Symbol>,
+    global_clocks: HashMap<Symbol, ClockValue>,
+    heap: Vec<Thunk<'a, R>>,
+    // the slots in the heap that have been collected
+    free: Vec<usize>,
+    // how many thunks were live after the last collection
+    live: usize,
+    clocks: Vec<ClockState>,
+    tasks: Vec<Task<'a, R>>,
+}
+
+impl<'a, 'b, R: Clone> Interpreter<'a, 'b, R> {
+    pub fn new(builtins: &'b BuiltinsMap, builtin_clocks: &[Symbol]) -> Interpreter<'a, 'b, R> {
+        let mut interp = Interpreter {
+            builtins,
+            globals: HashMap::new(),
+            time_zero_globals: Vec::new(),
+            global_clocks: HashMap::new(),
+            heap: Vec::new(),
+            free: Vec::new(),
+            live: 0,
+            clocks: Vec::new(),
+            tasks: Vec::new(),
+        };
+        // the only builtin clock is audio
+        for &name in builtin_clocks.iter() {
+            let clock = interp.add_clock(ClockState::Audio);
+            interp.global_clocks.insert(name, clock);
+        }
+        interp
+    }
+
+    // runs the definitions of a checked file, in order
+    pub fn load(&mut self, file: &SourceFile<'a, R>) {
+        let env = Rc::new(Env::Empty);
+        for def in file.defs.iter() {
+            match def.body {
+                TopLevelDefBody::Def { kind, ref type_, expr } => {
+                    let v = self.eval(&env, expr);
+                    self.globals.insert(def.name, v);
+                    if kind == TopLevelDefKind::Let && !type_.is_stable() {
+                        self.time_zero_globals.push(def.name);
+                    }
+                },
+                TopLevelDefBody::Clock { freq } => {
+                    let period = 1. / freq;
+                    let clock = self.add_clock(ClockState::Periodic { period, remaining: period });
+                    self.global_clocks.insert(def.name, clock);
+                },
+            }
+        }
+    }
+
+    pub fn global(&self, name: Symbol) -> Option<&Value<'a, R>> {
+        self.globals.get(&name)
+    }
+
+    // the heads of an audio-rate stream of samples, moving time on by
+    // a sample after each, like the runtime's sample_scheduler
+    pub fn sample(&mut self, mut stream: Value<'a, R>, num_samples: usize) -> Vec<f32> {
+        // nothing can get at these any more, and keeping them would
+        // keep everything the stream's been, if it's one of them
+        for name in self.time_zero_globals.drain(..) {
+            self.globals.remove(&name);
+        }
+        let mut samples = Vec::with_capacity(num_samples);
+        for _ in 0..num_samples {
+            stream = match stream {
+                Value::Stream(head, tail) => {
+                    samples.push(head.sample());
+                    self.advance(&tail)
+                },
+                _ => panic!("expected a stream"),
+            };
+            self.step(1. / 48e3);
+            // nothing's being evaluated between samples, so everything
+            // live is reachable from the stream, the globals and the
+            // tasks. collecting once the heap's doubled keeps it to
+            // amortized constant time per thunk
+            if self.heap.len() - self.free.len() >= 2 * self.live + MIN_COLLECT {
+                self.collect(&stream);
+            }
+        }
+        samples
+    }
+
+    // how many thunks the heap has room for, in use or free
+    pub fn heap_size(&self) -> usize {
+        self.heap.len()
+    }
+
+    fn collect(&mut self, stream: &Value<'a, R>) {
+        let mut marker = Marker { marked: vec![false; self.heap.len()], envs: HashSet::new(), pending: Vec::new() };
+        marker.value(stream);
+        for v in self.globals.values() {
+            marker.value(v);
+        }
+        for task in self.tasks.iter() {
+            marker.value(&task.later);
+            marker.value(&Value::Later(task.thunk));
+        }
+        while let Some(i) = marker.pending.pop() {
+            match self.heap[i] {
+                Thunk::Delayed(ref env, _) => marker.env(env),
+                Thunk::Forced(ref v) | Thunk::Scheduled(Some(ref v)) => marker.value(v),
+                Thunk::SinceTick(_) | Thunk::Scheduled(None) => {},
+                Thunk::Forcing | Thunk::Free => unreachable!("only thunks in use get marked"),
+            }
+        }
+        self.live = 0;
+        for (i, thunk) in self.heap.iter_mut().enumerate() {
+            if marker.marked[i] {
+                self.live += 1;
+            } else if !matches!(*thunk, Thunk::Free) {
+                *thunk = Thunk::Free;
+                self.free.push(i);
+            }
+        }
+    }
+
+    // moves time on by dur seconds, running the tasks whose clocks
+    // ticked
+    pub fn step(&mut self, dur: f32) {
+        let ticked: Vec<bool> = self.clocks.iter_mut().map(|clock| clock.pass_time(dur)).collect();
+        for task in std::mem::take(&mut self.tasks) {
+            if ticked[task.triggering] {
+                let v = self.advance(&task.later);
+                if let Thunk::Scheduled(ref mut found) = self.heap[task.thunk] {
+                    *found = Some(v);
+                }
+            } else if !ticked[task.cancelling] {
+                self.tasks.push(task);
+            }
+        }
+    }
+
+    fn add_clock(&mut self, state: ClockState) -> ClockValue {
+        self.clocks.push(state);
+        ClockValue { id: self.clocks.len() - 1, coeff: Ratio::from_integer(1) }
+    }
+
+    fn alloc(&mut self, thunk: Thunk<'a, R>) -> Value<'a, R> {
+        if let Some(i) = self.free.pop() {
+            self.heap[i] = thunk;
+            Value::Later(i)
+        } else {
+            self.heap.push(thunk);
+            Value::Later(self.heap.len() - 1)
+        }
+    }
+
+    fn lookup_clock(&self, env: &Env<'a, R>, c: Clock) -> ClockValue {
+        let base = env.lookup_clock_var(c.var)
+            .or_else(|| self.global_clocks.get(&c.var).copied())
+            .expect("couldn't find clock var??");
+        ClockValue { id: base.id, coeff: base.coeff * c.coeff }
+    }
+
+    pub fn eval(&mut self, env: &Rc<Env<'a, R>>, expr: &'a Expr<'a, R>) -> Value<'a, R> {
+        match *expr {
+            Expr::Var(_, x) =>
+                if let Some(v) = env.lookup_term_var(x) {
+                    v.clone()
+                } else if let Some(v) = self.globals.get(&x) {
+                    v.clone()
+                } else if let Some(builtin) = self.builtins.get(&x) {
+                    if builtin.n_args == 0 {
+                        self.builtin(builtin.ir2_expr, &[])
+                    } else {
+                        Value::Builtin(builtin.n_args, builtin.ir2_expr, Rc::new([]))
+                    }
+                } else {
+                    panic!("couldn't find a variable??")
+                },
+            Expr::Val(_, ref v) =>
+                match *v {
+                    HValue::Unit => Value::Unit,
+                    HValue::Sample(x) => Value::Sample(x),
+                    HValue::Index(i) => Value::Index(i),
+                },
+            Expr::Annotate(_, e, _) |
+            Expr::TypeApp(_, e, _) =>
+                self.eval(env, e),
+            Expr::Lam(_, x, e) =>
+                Value::Closure(env.clone(), x, e),
+            Expr::App(_, e1, e2) => {
+                let f = self.eval(env, e1);
+                let arg = self.eval(env, e2);
+                self.apply(f, arg)
+            },
+            Expr::Adv(_, e) => {
+                let later = self.eval(env, e);
+                self.advance(&later)
+            },
+            Expr::Lob(_, _, x, e) => {
+                let new_env = with_term_var(env, x, Value::Fix(env.clone(), expr));
+                self.eval(&new_env, e)
+            },
+            Expr::Gen(_, e1, e2) => {
+                let head = self.eval(env, e1);
+                let tail = self.eval(env, e2);
+                Value::Stream(Rc::new(head), Rc::new(tail))
+            },
+            Expr::LetIn(_, x, _, e1, e2) => {
+                let v = self.eval(env, e1);
+                self.eval(&with_term_var(env, x, v), e2)
+            },
+            Expr::Pair(_, e1, e2) => {
+                let v1 = self.eval(env, e1);
+                let v2 = self.eval(env, e2);
+                Value::Pair(Rc::new(v1), Rc::new(v2))
+            },
+            Expr::UnPair(_, x1, x2, e1, e2) =>
+                match self.eval(env, e1) {
+                    Value::Pair(v1, v2) => {
+                        let new_env = with_term_var(&with_term_var(env, x1, (*v1).clone()), x2, (*v2).clone());
+                        self.eval(&new_env, e2)
+                    },
+                    _ => panic!("expected a pair"),
+                },
+            Expr::InL(_, e) =>
+                Value::InL(Rc::new(self.eval(env, e))),
+            Expr::InR(_, e) =>
+                Value::InR(Rc::new(self.eval(env, e))),
+            Expr::Case(_, e0, x1, e1, x2, e2) =>
+                match self.eval(env, e0) {
+                    Value::InL(v) => self.eval(&with_term_var(env, x1, (*v).clone()), e1),
+                    Value::InR(v) => self.eval(&with_term_var(env, x2, (*v).clone()), e2),
+                    _ => panic!("expected a sum"),
+                },
+            Expr::Array(_, ref es) =>
+                Value::Array(es.iter().map(|&e| self.eval(env, e)).collect()),
+            Expr::UnGen(_, e) =>
+                match self.eval(env, e) {
+                    Value::Stream(head, tail) => Value::Pair(head, tail),
+                    _ => panic!("expected a stream"),
+                },
+            Expr::Delay(_, e) =>
+                self.alloc(Thunk::Delayed(env.clone(), e)),
+            Expr::Box(_, e) =>
+                Value::Box(env.clone(), e),
+            Expr::Unbox(_, e) =>
+                match self.eval(env, e) {
+                    Value::Box(box_env, e) => self.eval(&box_env, e),
+                    Value::Fix(lob_env, lob) => self.alloc(Thunk::Delayed(lob_env, lob)),
+                    _ => panic!("expected a box"),
+                },
+            Expr::ClockApp(_, e, c) => {
+                let f = self.eval(env, e);
+                let clock = self.lookup_clock(env, c);
+                self.apply(f, Value::Clock(clock))
+            },
+            Expr::Binop(_, op, e1, e2) => {
+                let v1 = self.eval(env, e1);
+                let v2 = self.eval(env, e2);
+                self.op(Op::from_binop(op), &[v1, v2])
+            },
+            Expr::ExIntro(_, c, e) => {
+                let clock = self.lookup_clock(env, c);
+                Value::ClockEx(clock, Rc::new(self.eval(env, e)))
+            },
+            Expr::ExElim(_, c, x, e1, e2) =>
+                match self.eval(env, e1) {
+                    Value::ClockEx(clock, v) => {
+                        let new_env = with_term_var(&with_clock_var(env, c, clock), x, (*v).clone());
+                        self.eval(&new_env, e2)
+                    },
+                    _ => panic!("expected a clock existential"),
+                },
+            Expr::Hole(_, _) =>
+                unreachable!("holes never typecheck"),
+            Expr::ClockLam(_, x, e) =>
+                Value::ClockClosure(env.clone(), x, e),
+        }
+    }
+
+    fn apply(&mut self, f: Value<'a, R>, arg: Value<'a, R>) -> Value<'a, R> {
+        match f {
+            Value::Closure(env, x, body) =>
+                self.eval(&with_term_var(&env, x, arg), body),
+            Value::ClockClosure(env, x, body) =>
+                self.eval(&with_clock_var(&env, x, arg.clock()), body),
+            Value::Builtin(n_args, body, ref args) => {
+                let args: Rc<[_]> = args.iter().cloned().chain([arg]).collect();
+                if args.len() == n_args {
+                    self.builtin(body, &args)
+                } else {
+                    Value::Builtin(n_args, body, args)
+                }
+            },
+            _ => panic!("expected a function"),
+        }
+    }
+
+    pub fn advance(&mut self, later: &Value<'a, R>) -> Value<'a, R> {
+        let i = match *later {
+            Value::Later(i) => i,
+            _ => panic!("expected a later value"),
+        };
+        let v = match std::mem::replace(&mut self.heap[i], Thunk::Forcing) {
+            Thunk::Delayed(env, e) =>
+                self.eval(&env, e),
+            Thunk::Forced(v) =>
+                v,
+            Thunk::SinceTick(clock) =>
+                self.since_tick(clock),
+            Thunk::Scheduled(Some(v)) =>
+                Value::InR(Rc::new(v)),
+            Thunk::Scheduled(None) =>
+                Value::InL(Rc::new(Value::Unit)),
+            Thunk::Forcing =>
+                panic!("a later value needed itself"),
+            Thunk::Free =>
+                panic!("a later value got collected while still in use"),
+        };
+        self.heap[i] = Thunk::Forced(v.clone());
+        v
+    }
+
+    fn since_tick(&mut self, clock: ClockValue) -> Value<'a, R> {
+        let head = Value::Sample(self.clocks[clock.id].since_last_tick());
+        let tail = self.alloc(Thunk::SinceTick(clock));
+        Value::Stream(Rc::new(head), Rc::new(tail))
+    }
+
+    // builtins are ops on their arguments, which are the variables,
+    // the last one innermost
+    fn builtin(&mut self, body: &ir2::Expr<'_>, args: &[Value<'a, R>]) -> Value<'a, R> {
+        match *body {
+            ir2::Expr::Var(DebruijnIndex(i)) =>
+                args[args.len() - 1 - i as usize].clone(),
+            ir2::Expr::Op(op, es) => {
+                let vs: Vec<_> = es.iter().map(|e| self.builtin(e, args)).collect();
+                self.op(op, &vs)
+            },
+            _ =>
+                panic!("builtins should only be ops"),
+        }
+    }
+
+    fn op(&mut self, op: Op, args: &[Value<'a, R>]) -> Value<'a, R> {
+        match (op, args) {
+            // values aren't boxed here
+            (Op::PackF32 | Op::PackI32 | Op::UnpackF32 | Op::UnpackI32, [v]) =>
+                v.clone(),
+            (Op::FAdd, [x, y]) => Value::Sample(x.sample() + y.sample()),
+            (Op::FSub, [x, y]) => Value::Sample(x.sample() - y.sample()),
+            (Op::FMul, [x, y]) => Value::Sample(x.sample() * y.sample()),
+            (Op::FDiv, [x, y]) => Value::Sample(x.sample() / y.sample()),
+            (Op::FGt, [x, y]) => Value::bool(x.sample() > y.sample()),
+            (Op::FGe, [x, y]) => Value::bool(x.sample() >= y.sample()),
+            (Op::FLt, [x, y]) => Value::bool(x.sample() < y.sample()),
+            (Op::FLe, [x, y]) => Value::bool(x.sample() <= y.sample()),
+            (Op::FEq, [x, y]) => Value::bool(x.sample() == y.sample()),
+            (Op::FNe, [x, y]) => Value::bool(x.sample() != y.sample()),
+            (Op::Sin, [x]) => Value::Sample(x.sample().sin()),
+            (Op::Cos, [x]) => Value::Sample(x.sample().cos()),
+            (Op::Pi, []) => Value::Sample(std::f32::consts::PI),
+            (Op::IAdd, [i, j]) => Value::Index(i.index().wrapping_add(j.index()) as usize),
+            (Op::ISub, [i, j]) => Value::Index(i.index().wrapping_sub(j.index()) as usize),
+            (Op::IMul, [i, j]) => Value::Index(i.index().wrapping_mul(j.index()) as usize),
+            (Op::IDiv, [i, j]) => Value::Index((i.index() / j.index()) as usize),
+            (Op::Shl, [i, j]) => Value::Index(i.index().wrapping_shl(j.index()) as usize),
+            (Op::Shr, [i, j]) => Value::Index(i.index().wrapping_shr(j.index()) as usize),
+            (Op::And, [i, j]) => Value::Index((i.index() & j.index()) as usize),
+            (Op::Xor, [i, j]) => Value::Index((i.index() ^ j.index()) as usize),
+            (Op::Or, [i, j]) => Value::Index((i.index() | j.index()) as usize),
+            (Op::IGt, [i, j]) => Value::bool(i.index() > j.index()),
+            (Op::IGe, [i, j]) => Value::bool(i.index() >= j.index()),
+            (Op::ILt, [i, j]) => Value::bool(i.index() < j.index()),
+            (Op::ILe, [i, j]) => Value::bool(i.index() <= j.index()),
+            (Op::IEq, [i, j]) => Value::bool(i.index() == j.index()),
+            (Op::INe, [i, j]) => Value::bool(i.index() != j.index()),
+            (Op::ReinterpF2I, [x]) => Value::Index(x.sample().to_bits() as usize),
+            (Op::ReinterpI2F, [i]) => Value::Sample(f32::from_bits(i.index())),
+            (Op::CastI2F, [i]) => Value::Sample(i.index() as f32),
+            (Op::SinceLastTickStream, [clock]) =>
+                self.since_tick(clock.clock()),
+            (Op::Wait, [_]) =>
+                self.alloc(Thunk::Forced(Value::Unit)),
+            (Op::Schedule, [source, target, later]) => {
+                let thunk = self.heap.len();
+                self.heap.push(Thunk::Scheduled(None));
+                self.tasks.push(Task { triggering: source.clock().id, cancelling: target.clock().id, later: later.clone(), thunk });
+                Value::Later(thunk)
+            },
+            _ =>
+                panic!("the interpreter can't run {:?} on {} arguments", op, args.len()),
+        }
+    }
+}
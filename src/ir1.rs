@@ -43,6 +43,12 @@ impl DebruijnIndex {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Global(pub u32);
 
+impl fmt::Display for Global {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "@{}", self.0)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 pub enum Value {
     Unit,
@@ -124,12 +130,16 @@ pub enum Op {
     UnGen,
     // TODO: coalesce (some of?) these
     AllocAndFill,
-    AllocF32,
-    AllocI32,
+    // every value is one word, and samples and indices are stored in
+    // it directly rather than behind a pointer. these go between that
+    // word and the f32 or i32 the arithmetic ops work on, so they
+    // cost a reinterpret at most
+    PackF32,
+    PackI32,
     BuildClosure(Global),
     LoadGlobal(Global),
-    DerefF32,
-    DerefI32,
+    UnpackF32,
+    UnpackI32,
     ApplyCoeff(Ratio<u32>),
     SinceLastTickStream,
     Advance,
@@ -140,7 +150,7 @@ pub enum Op {
 }
 
 impl Op {
-    fn from_binop(op: HBinop) -> Op {
+    pub fn from_binop(op: HBinop) -> Op {
         match op {
             HBinop::FMul => Op::FMul,
             HBinop::FDiv => Op::FDiv,
@@ -210,11 +220,11 @@ impl Op {
             Op::Proj(_) => Some(1),
             Op::UnGen => Some(1),
             Op::AllocAndFill => None,
-            Op::AllocI32 => Some(1),
-            Op::AllocF32 => Some(1),
+            Op::PackI32 => Some(1),
+            Op::PackF32 => Some(1),
             Op::BuildClosure(_) => None,
-            Op::DerefI32 => Some(1),
-            Op::DerefF32 => Some(1),
+            Op::UnpackI32 => Some(1),
+            Op::UnpackF32 => Some(1),
             Op::LoadGlobal(_) => Some(0),
             Op::ApplyCoeff(_) => Some(1),
             Op::SinceLastTickStream => Some(1),
@@ -227,6 +237,61 @@ impl Op {
     }
 }
 
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Op::Const(v) => write!(f, "{}", v),
+            Op::FAdd => write!(f, "fadd"),
+            Op::FSub => write!(f, "fsub"),
+            Op::FMul => write!(f, "fmul"),
+            Op::FDiv => write!(f, "fdiv"),
+            Op::FGt => write!(f, "fgt"),
+            Op::FGe => write!(f, "fge"),
+            Op::FLt => write!(f, "flt"),
+            Op::FLe => write!(f, "fle"),
+            Op::FEq => write!(f, "feq"),
+            Op::FNe => write!(f, "fne"),
+            Op::Sin => write!(f, "sin"),
+            Op::Cos => write!(f, "cos"),
+            Op::Pi => write!(f, "pi"),
+            Op::IAdd => write!(f, "iadd"),
+            Op::ISub => write!(f, "isub"),
+            Op::IMul => write!(f, "imul"),
+            Op::IDiv => write!(f, "idiv"),
+            Op::Shl => write!(f, "shl"),
+            Op::Shr => write!(f, "shr"),
+            Op::And => write!(f, "and"),
+            Op::Xor => write!(f, "xor"),
+            Op::Or => write!(f, "or"),
+            Op::IGt => write!(f, "igt"),
+            Op::IGe => write!(f, "ige"),
+            Op::ILt => write!(f, "ilt"),
+            Op::ILe => write!(f, "ile"),
+            Op::IEq => write!(f, "ieq"),
+            Op::INe => write!(f, "ine"),
+            Op::ReinterpF2I => write!(f, "reinterp_f2i"),
+            Op::ReinterpI2F => write!(f, "reinterp_i2f"),
+            Op::CastI2F => write!(f, "cast_i2f"),
+            Op::Proj(i) => write!(f, "proj[{}]", i),
+            Op::UnGen => write!(f, "ungen"),
+            Op::AllocAndFill => write!(f, "alloc_and_fill"),
+            Op::PackF32 => write!(f, "pack_f32"),
+            Op::PackI32 => write!(f, "pack_i32"),
+            Op::BuildClosure(g) => write!(f, "build_closure[{}]", g),
+            Op::LoadGlobal(g) => write!(f, "load_global[{}]", g),
+            Op::UnpackF32 => write!(f, "unpack_f32"),
+            Op::UnpackI32 => write!(f, "unpack_i32"),
+            Op::ApplyCoeff(c) => write!(f, "apply_coeff[{}]", c),
+            Op::SinceLastTickStream => write!(f, "since_last_tick_stream"),
+            Op::Advance => write!(f, "advance"),
+            Op::Wait => write!(f, "wait"),
+            Op::Schedule => write!(f, "schedule"),
+            Op::MakeClock(freq) => write!(f, "make_clock[{}]", freq),
+            Op::GetClock(i) => write!(f, "get_clock[{}]", i),
+        }
+    }
+}
+
 // TODO: change this out for some sort of bit set -- particularly one
 // that has an optimization for small sizes, bc i imagine that will be
 // the *very* common case
@@ -319,6 +384,86 @@ impl<'a> Expr<'a> {
             Adv(e) => Adv(arena.alloc(e.shifted_by_signed(by, depth, arena))),
         }
     }
+
+    pub fn pretty<'b>(&'b self) -> PrettyExpr<'b, 'a> {
+        PrettyExpr { expr: self, indent: 0 }
+    }
+}
+
+// prints variables as #i for de bruijn index i and globals as @g.
+// lets and ifs go over several lines, indented by how deep they are
+pub struct PrettyExpr<'a, 'b> {
+    expr: &'a Expr<'b>,
+    indent: usize,
+}
+
+impl<'a, 'b> PrettyExpr<'a, 'b> {
+    fn for_expr(&self, other_expr: &'a Expr<'b>) -> PrettyExpr<'a, 'b> {
+        PrettyExpr { expr: other_expr, indent: self.indent }
+    }
+
+    fn indented(&self, other_expr: &'a Expr<'b>) -> PrettyExpr<'a, 'b> {
+        PrettyExpr { expr: other_expr, indent: self.indent + 2 }
+    }
+
+    fn args(&self, f: &mut fmt::Formatter<'_>, es: &'a [&'a Expr<'b>]) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, e) in es.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", self.indented(e))?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<'a, 'b> fmt::Display for PrettyExpr<'a, 'b> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indent = self.indent;
+        match *self.expr {
+            Expr::Var(i) =>
+                write!(f, "#{}", i.0),
+            Expr::Val(v) =>
+                write!(f, "{}", v),
+            Expr::Glob(g) =>
+                write!(f, "{}", g),
+            Expr::Lam(_, arity, e) =>
+                write!(f, "\\{}. {}", arity, self.indented(e)),
+            Expr::App(e0, es) => {
+                if matches!(*e0, Expr::Var(_) | Expr::Glob(_)) {
+                    write!(f, "{}", self.for_expr(e0))?;
+                } else {
+                    write!(f, "({})", self.for_expr(e0))?;
+                }
+                self.args(f, es)
+            },
+            Expr::Unbox(e) =>
+                write!(f, "unbox({})", self.for_expr(e)),
+            Expr::Box(_, e) =>
+                write!(f, "box({})", self.indented(e)),
+            Expr::Lob(_, e) =>
+                write!(f, "lob. {}", self.indented(e)),
+            Expr::LetIn(e1, e2) =>
+                write!(f, "let {} in\n{:indent$}{}", self.indented(e1), "", self.for_expr(e2)),
+            Expr::If(e0, e1, e2) =>
+                write!(f, "if {}\n{:indent$}then {}\n{:indent$}else {}", self.for_expr(e0), "", self.indented(e1), "", self.indented(e2)),
+            Expr::Con(con, es) => {
+                write!(f, "{:?}", con)?;
+                self.args(f, es)
+            },
+            Expr::Op(op, []) =>
+                write!(f, "{}", op),
+            Expr::Op(op, es) => {
+                write!(f, "{}", op)?;
+                self.args(f, es)
+            },
+            Expr::Delay(_, e) =>
+                write!(f, "delay({})", self.indented(e)),
+            Expr::Adv(e) =>
+                write!(f, "adv({})", self.for_expr(e)),
+        }
+    }
 }
             
 
@@ -368,20 +513,23 @@ impl Ctx {
     }
 }
 
-fn binop_types(b: HBinop) -> (Op, Op, Op) {
+// the ops that unpack the arguments and pack the result. comparisons
+// give None, since their result is a boolean, which is a sum and so
+// has to be built like one
+fn binop_types(b: HBinop) -> (Op, Op, Option<Op>) {
     match b {
         HBinop::FMul |
         HBinop::FDiv |
         HBinop::FAdd |
         HBinop::FSub =>
-            (Op::DerefF32, Op::DerefF32, Op::AllocF32),
+            (Op::UnpackF32, Op::UnpackF32, Some(Op::PackF32)),
         HBinop::FGt |
         HBinop::FGe |
         HBinop::FLt |
         HBinop::FLe |
         HBinop::FEq |
         HBinop::FNe =>
-            (Op::DerefF32, Op::DerefF32, Op::AllocI32),
+            (Op::UnpackF32, Op::UnpackF32, None),
         HBinop::Shl |
         HBinop::Shr |
         HBinop::And |
@@ -390,14 +538,15 @@ fn binop_types(b: HBinop) -> (Op, Op, Op) {
         HBinop::IMul |
         HBinop::IDiv |
         HBinop::IAdd |
-        HBinop::ISub |
+        HBinop::ISub =>
+            (Op::UnpackI32, Op::UnpackI32, Some(Op::PackI32)),
         HBinop::IGt |
         HBinop::IGe |
         HBinop::ILt |
         HBinop::ILe |
         HBinop::IEq |
         HBinop::INe =>
-            (Op::DerefI32, Op::DerefI32, Op::AllocI32),
+            (Op::UnpackI32, Op::UnpackI32, None),
     }
 }
 
@@ -410,12 +559,12 @@ impl<'a> Translator<'a> {
         self.arena.alloc_slice(it)
     }
 
-    fn make_alloc_f32(&self, e: &'a Expr<'a>) -> Expr<'a> {
-        Expr::Op(Op::AllocF32, self.alloc_slice([e]))
+    fn make_pack_f32(&self, e: &'a Expr<'a>) -> Expr<'a> {
+        Expr::Op(Op::PackF32, self.alloc_slice([e]))
     }
 
-    fn make_alloc_i32(&self, e: &'a Expr<'a>) -> Expr<'a> {
-        Expr::Op(Op::AllocI32, self.alloc_slice([e]))
+    fn make_pack_i32(&self, e: &'a Expr<'a>) -> Expr<'a> {
+        Expr::Op(Op::PackI32, self.alloc_slice([e]))
     }
 
     pub fn translate<'b, R>(&self, ctx: Rc<Ctx>, expr: &'b HExpr<'b, R>) -> Expr<'a> {
@@ -429,11 +578,11 @@ impl<'a> Translator<'a> {
                     panic!("couldn't find a variable??")
                 },
             HExpr::Val(_, HValue::Unit) =>
-                self.make_alloc_i32(self.alloc(Expr::Val(Value::Unit))),
+                self.make_pack_i32(self.alloc(Expr::Val(Value::Unit))),
             HExpr::Val(_, HValue::Sample(x)) =>
-                self.make_alloc_f32(self.alloc(Expr::Val(Value::Sample(x)))),
+                self.make_pack_f32(self.alloc(Expr::Val(Value::Sample(x)))),
             HExpr::Val(_, HValue::Index(i)) =>
-                self.make_alloc_i32(self.alloc(Expr::Val(Value::Index(i)))),
+                self.make_pack_i32(self.alloc(Expr::Val(Value::Index(i)))),
             HExpr::Annotate(_, next, _) =>
                 self.translate(ctx, next),
             HExpr::Lam(_, x, next) => {
@@ -519,7 +668,7 @@ impl<'a> Translator<'a> {
             },
             HExpr::Array(_, ref es) => {
                 let est = es.iter().map(|&e| self.alloc(self.translate(ctx.clone(), e)));
-                Expr::Con(Con::Array, self.alloc_slice(est))
+                Expr::Con(Con::Array, self.arena.alloc_slice_r(est))
             },
             HExpr::UnGen(_, e) => {
                 let et = self.translate(ctx, e);
@@ -559,9 +708,19 @@ impl<'a> Translator<'a> {
                 let (a1o, a2o, ro) = binop_types(op);
                 let e1p = Expr::Op(a1o, self.alloc_slice([self.alloc(self.translate(ctx.clone(), e1))]));
                 let e2p = Expr::Op(a2o, self.alloc_slice([self.alloc(self.translate(ctx, e2))]));
-                Expr::Op(ro, self.alloc_slice([self.alloc(
+                let result = self.alloc(
                     Expr::Op(Op::from_binop(op), self.alloc_slice([self.alloc(e1p), self.alloc(e2p)]))
-                )]))
+                );
+                match ro {
+                    Some(ro) =>
+                        Expr::Op(ro, self.alloc_slice([result])),
+                    // laid out like inl/inr, with the comparison as the tag
+                    None =>
+                        Expr::Op(Op::AllocAndFill, self.alloc_slice([
+                            result,
+                            self.alloc(self.make_pack_i32(self.alloc(Expr::Val(Value::Unit))))
+                        ])),
+                }
             },
             HExpr::ExIntro(_, c, e) => {
                 // TODO: factor out this lookup logic
@@ -589,6 +748,8 @@ impl<'a> Translator<'a> {
                     Expr::LetIn(piece(0), self.alloc(
                         Expr::LetIn(piece(1), e2t)))))
             },
+            HExpr::Hole(_, _) =>
+                unreachable!("holes never typecheck"),
             HExpr::ClockLam(_, x, e) => {
                 let new_ctx = Rc::new(Ctx::ClockVar(x, ctx));
                 let et = self.translate(new_ctx, e);
@@ -1003,3 +1164,470 @@ impl<'a> Translator<'a> {
 }
 
 
+
+// what a closed expression evaluates to, if that can be worked out at
+// compile time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstValue {
+    Raw(Value),
+    // the value packed into a word, like PackF32 and PackI32 give
+    Packed(Value),
+}
+
+// works out the value of a definition's body, where consts has the
+// values of the constant globals so far, which are packed. this only
+// goes as far as scalars, lets and ifs, which covers things like
+// `def persamp: sample = 2. * 3.14159 * 440. / 48000.`. it gives up
+// on anything whose result might not be exactly what the compiled code
+// would get: sin and cos, which the runtime does with its own libm,
+// and nans, whose bits can differ
+pub fn const_eval(consts: &HashMap<Global, Value>, expr: &Expr<'_>) -> Option<ConstValue> {
+    const_eval_in(consts, &mut Vec::new(), expr)
+}
+
+fn const_eval_in(consts: &HashMap<Global, Value>, env: &mut Vec<ConstValue>, expr: &Expr<'_>) -> Option<ConstValue> {
+    match *expr {
+        Expr::Var(DebruijnIndex(i)) =>
+            env.iter().rev().nth(i as usize).copied(),
+        Expr::Val(v) =>
+            Some(ConstValue::Raw(v)),
+        Expr::Glob(g) =>
+            consts.get(&g).map(|&v| ConstValue::Packed(v)),
+        Expr::LetIn(e1, e2) => {
+            let v1 = const_eval_in(consts, env, e1)?;
+            env.push(v1);
+            let v2 = const_eval_in(consts, env, e2);
+            env.pop();
+            v2
+        },
+        Expr::If(e0, e1, e2) =>
+            match const_eval_in(consts, env, e0)? {
+                ConstValue::Raw(Value::Index(0)) => const_eval_in(consts, env, e2),
+                ConstValue::Raw(Value::Index(_)) => const_eval_in(consts, env, e1),
+                _ => None,
+            },
+        Expr::Op(op, es) => {
+            let args = es.iter().map(|e| const_eval_in(consts, env, e)).collect::<Option<Vec<_>>>()?;
+            const_eval_op(op, &args)
+        },
+        _ => None,
+    }
+}
+
+fn const_eval_op(op: Op, args: &[ConstValue]) -> Option<ConstValue> {
+    use ConstValue::{Raw, Packed};
+    let raw = match (op, args) {
+        (Op::Const(v), &[]) => v,
+        (Op::PackF32 | Op::PackI32, &[Raw(v)]) => return Some(Packed(v)),
+        (Op::UnpackF32 | Op::UnpackI32, &[Packed(v)]) => v,
+        (Op::Pi, &[]) => Value::Sample(std::f32::consts::PI),
+        (_, &[Raw(Value::Sample(x)), Raw(Value::Sample(y))]) =>
+            match op {
+                Op::FAdd => Value::Sample(x + y),
+                Op::FSub => Value::Sample(x - y),
+                Op::FMul => Value::Sample(x * y),
+                Op::FDiv => Value::Sample(x / y),
+                Op::FGt => Value::Index((x > y) as usize),
+                Op::FGe => Value::Index((x >= y) as usize),
+                Op::FLt => Value::Index((x < y) as usize),
+                Op::FLe => Value::Index((x <= y) as usize),
+                Op::FEq => Value::Index((x == y) as usize),
+                Op::FNe => Value::Index((x != y) as usize),
+                _ => return None,
+            },
+        // these are all u32s at runtime
+        (_, &[Raw(Value::Index(i)), Raw(Value::Index(j))]) => {
+            let (i, j) = (i as u32, j as u32);
+            Value::Index(match op {
+                Op::IAdd => i.wrapping_add(j),
+                Op::ISub => i.wrapping_sub(j),
+                Op::IMul => i.wrapping_mul(j),
+                // traps at runtime
+                Op::IDiv if j == 0 => return None,
+                Op::IDiv => i / j,
+                Op::Shl => i.wrapping_shl(j),
+                Op::Shr => i.wrapping_shr(j),
+                Op::And => i & j,
+                Op::Xor => i ^ j,
+                Op::Or => i | j,
+                Op::IGt => (i > j) as u32,
+                Op::IGe => (i >= j) as u32,
+                Op::ILt => (i < j) as u32,
+                Op::ILe => (i <= j) as u32,
+                Op::IEq => (i == j) as u32,
+                Op::INe => (i != j) as u32,
+                _ => return None,
+            } as usize)
+        },
+        (Op::ReinterpF2I, &[Raw(Value::Sample(x))]) => Value::Index(x.to_bits() as usize),
+        (Op::ReinterpI2F, &[Raw(Value::Index(i))]) => Value::Sample(f32::from_bits(i as u32)),
+        (Op::CastI2F, &[Raw(Value::Index(i))]) => Value::Sample(i as u32 as f32),
+        _ => return None,
+    };
+    match raw {
+        Value::Sample(x) if x.is_nan() => None,
+        _ => Some(Raw(raw)),
+    }
+}
+
+impl<'a> Translator<'a> {
+    // replaces loads from the constant globals with their values, so
+    // that using one doesn't have to go through a wasm global
+    pub fn inline_consts(&self, consts: &HashMap<Global, Value>, expr: &'a Expr<'a>) -> &'a Expr<'a> {
+        use Expr::*;
+        match *expr {
+            Var(_) | Val(_) | Glob(_) => expr,
+            Op(self::Op::UnpackF32 | self::Op::UnpackI32, &[&Glob(g)]) if consts.contains_key(&g) =>
+                self.alloc(Op(self::Op::Const(consts[&g]), &[])),
+            Lam(ref vars, arity, e) => self.alloc(Lam(vars.clone(), arity, self.inline_consts(consts, e))),
+            App(e, es) => self.alloc(App(self.inline_consts(consts, e), self.slice_inline_consts(consts, es))),
+            Unbox(e) => self.alloc(Unbox(self.inline_consts(consts, e))),
+            Box(ref vars, e) => self.alloc(Box(vars.clone(), self.inline_consts(consts, e))),
+            Lob(ref vars, e) => self.alloc(Lob(vars.clone(), self.inline_consts(consts, e))),
+            LetIn(e1, e2) => self.alloc(LetIn(self.inline_consts(consts, e1), self.inline_consts(consts, e2))),
+            If(e0, e1, e2) => self.alloc(If(self.inline_consts(consts, e0), self.inline_consts(consts, e1), self.inline_consts(consts, e2))),
+            Con(con, es) => self.alloc(Con(con, self.slice_inline_consts(consts, es))),
+            Op(op, es) => self.alloc(Op(op, self.slice_inline_consts(consts, es))),
+            Delay(ref vars, e) => self.alloc(Delay(vars.clone(), self.inline_consts(consts, e))),
+            Adv(e) => self.alloc(Adv(self.inline_consts(consts, e))),
+        }
+    }
+
+    fn slice_inline_consts(&self, consts: &HashMap<Global, Value>, exprs: &[&'a Expr<'a>]) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.inline_consts(consts, e)))
+    }
+}
+
+// whether variable i is only ever used unpacked as a sample, and
+// never from inside a closure
+fn only_unpacked(expr: &Expr<'_>, i: u32, in_closure: bool) -> bool {
+    use Expr::*;
+    match *expr {
+        Var(DebruijnIndex(j)) => j != i,
+        Val(_) | Glob(_) => true,
+        Op(self::Op::UnpackF32, &[&Var(DebruijnIndex(j))]) if j == i => !in_closure,
+        Lam(_, arity, e) => only_unpacked(e, i + arity, true),
+        App(e, es) => only_unpacked(e, i, in_closure) && es.iter().all(|e| only_unpacked(e, i, in_closure)),
+        Unbox(e) | Adv(e) => only_unpacked(e, i, in_closure),
+        Box(_, e) | Delay(_, e) => only_unpacked(e, i, true),
+        Lob(_, e) => only_unpacked(e, i + 1, true),
+        LetIn(e1, e2) => only_unpacked(e1, i, in_closure) && only_unpacked(e2, i + 1, in_closure),
+        If(e0, e1, e2) => [e0, e1, e2].iter().all(|e| only_unpacked(e, i, in_closure)),
+        Con(_, es) | Op(_, es) => es.iter().all(|e| only_unpacked(e, i, in_closure)),
+    }
+}
+
+impl<'a> Translator<'a> {
+    // let-binds a sample as the f32 itself when the variable is only
+    // ever unpacked, so the backend can keep it in an f32 local. if a
+    // closure captures it, it has to stay packed, since closures only
+    // hold words. parameters are the same, except that they come in
+    // packed, since every function takes words, so they get unpacked
+    // once on the way in
+    pub fn unpack_lets(&self, expr: &'a Expr<'a>) -> &'a Expr<'a> {
+        use Expr::*;
+        match *expr {
+            Var(_) | Val(_) | Glob(_) => expr,
+            LetIn(e1, e2) => {
+                let e1p = self.unpack_lets(e1);
+                let e2p = self.unpack_lets(e2);
+                match *e1p {
+                    Op(self::Op::PackF32, &[inner]) if only_unpacked(e2p, 0, false) =>
+                        self.alloc(LetIn(inner, self.drop_unpacks(e2p, 0))),
+                    _ =>
+                        self.alloc(LetIn(e1p, e2p)),
+                }
+            },
+            Lam(ref vars, 1, e) => {
+                let ep = self.unpack_lets(e);
+                let body = if only_unpacked(ep, 0, false) {
+                    // under the let, #0 is the unpacked parameter and
+                    // the parameter itself goes unused
+                    let shifted = self.alloc(ep.shifted_by(1, 1, self.arena));
+                    match self.drop_unpacks(shifted, 0) {
+                        dropped if dropped == shifted => ep,
+                        dropped => {
+                            let param = self.alloc(Var(DebruijnIndex(0)));
+                            self.alloc(LetIn(self.alloc(Op(self::Op::UnpackF32, self.alloc_slice([param]))), dropped))
+                        },
+                    }
+                } else {
+                    ep
+                };
+                self.alloc(Lam(vars.clone(), 1, body))
+            },
+            Lam(ref vars, arity, e) => self.alloc(Lam(vars.clone(), arity, self.unpack_lets(e))),
+            App(e, es) => self.alloc(App(self.unpack_lets(e), self.slice_unpack_lets(es))),
+            Unbox(e) => self.alloc(Unbox(self.unpack_lets(e))),
+            Box(ref vars, e) => self.alloc(Box(vars.clone(), self.unpack_lets(e))),
+            Lob(ref vars, e) => self.alloc(Lob(vars.clone(), self.unpack_lets(e))),
+            If(e0, e1, e2) => self.alloc(If(self.unpack_lets(e0), self.unpack_lets(e1), self.unpack_lets(e2))),
+            Con(con, es) => self.alloc(Con(con, self.slice_unpack_lets(es))),
+            Op(op, es) => self.alloc(Op(op, self.slice_unpack_lets(es))),
+            Delay(ref vars, e) => self.alloc(Delay(vars.clone(), self.unpack_lets(e))),
+            Adv(e) => self.alloc(Adv(self.unpack_lets(e))),
+        }
+    }
+
+    fn slice_unpack_lets(&self, exprs: &[&'a Expr<'a>]) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.unpack_lets(e)))
+    }
+
+    // turns the unpacks of variable i into uses of it directly
+    fn drop_unpacks(&self, expr: &'a Expr<'a>, i: u32) -> &'a Expr<'a> {
+        use Expr::*;
+        match *expr {
+            Var(_) | Val(_) | Glob(_) => expr,
+            Op(self::Op::UnpackF32, &[&Var(DebruijnIndex(j))]) if j == i =>
+                self.alloc(Var(DebruijnIndex(j))),
+            Lam(ref vars, arity, e) => self.alloc(Lam(vars.clone(), arity, self.drop_unpacks(e, i + arity))),
+            App(e, es) => self.alloc(App(self.drop_unpacks(e, i), self.slice_drop_unpacks(es, i))),
+            Unbox(e) => self.alloc(Unbox(self.drop_unpacks(e, i))),
+            Box(ref vars, e) => self.alloc(Box(vars.clone(), self.drop_unpacks(e, i))),
+            Lob(ref vars, e) => self.alloc(Lob(vars.clone(), self.drop_unpacks(e, i + 1))),
+            LetIn(e1, e2) => self.alloc(LetIn(self.drop_unpacks(e1, i), self.drop_unpacks(e2, i + 1))),
+            If(e0, e1, e2) => self.alloc(If(self.drop_unpacks(e0, i), self.drop_unpacks(e1, i), self.drop_unpacks(e2, i))),
+            Con(con, es) => self.alloc(Con(con, self.slice_drop_unpacks(es, i))),
+            Op(op, es) => self.alloc(Op(op, self.slice_drop_unpacks(es, i))),
+            Delay(ref vars, e) => self.alloc(Delay(vars.clone(), self.drop_unpacks(e, i))),
+            Adv(e) => self.alloc(Adv(self.drop_unpacks(e, i))),
+        }
+    }
+
+    fn slice_drop_unpacks(&self, exprs: &[&'a Expr<'a>], i: u32) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.drop_unpacks(e, i)))
+    }
+}
+
+// the shape of a stream map, or of a zip of several streams, like
+// mapsig in tests/accept/map.cky:
+//
+//   \f. &^(k) mapped. \s. let (x, s') = %s in (unbox f) x :: `(!(unbox mapped) !s')
+//
+// it unpacks each of its streams, then builds the stream of its
+// function applied to their heads, followed by itself applied to
+// their tails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamMap {
+    // how many clocks it takes before its function
+    pub clocks: u32,
+    // how many streams it takes after its function
+    pub streams: u32,
+}
+
+// what a variable in the body of a stream map is bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MapPart {
+    Func,
+    Rec,
+    Stream(u32),
+    Unpacked(u32),
+    Head(u32),
+    Tail(u32),
+}
+
+// parts has what each variable in scope is bound to, outermost first
+fn map_part(parts: &[MapPart], expr: &Expr<'_>) -> Option<MapPart> {
+    match *expr {
+        Expr::Var(DebruijnIndex(i)) =>
+            parts.len().checked_sub(i as usize + 1).map(|j| parts[j]),
+        Expr::Op(Op::UnGen, &[e]) => match map_part(parts, e)? {
+            MapPart::Stream(i) => Some(MapPart::Unpacked(i)),
+            _ => None,
+        },
+        Expr::Op(Op::Proj(0), &[e]) => match map_part(parts, e)? {
+            MapPart::Unpacked(i) => Some(MapPart::Head(i)),
+            _ => None,
+        },
+        Expr::Op(Op::Proj(1), &[e]) => match map_part(parts, e)? {
+            MapPart::Unpacked(i) => Some(MapPart::Tail(i)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// the function and arguments of a chain of applications
+fn spine<'b>(mut expr: &'b Expr<'b>) -> (&'b Expr<'b>, Vec<&'b Expr<'b>>) {
+    let mut args = Vec::new();
+    while let Expr::App(e, es) = *expr {
+        args.splice(0..0, es.iter().copied());
+        expr = e;
+    }
+    (expr, args)
+}
+
+// an application of one of the stream maps among the globals
+struct MapApp<'a> {
+    shape: StreamMap,
+    // the map applied to its clocks
+    map: &'a Expr<'a>,
+    func: &'a Expr<'a>,
+    streams: Vec<&'a Expr<'a>>,
+}
+
+impl<'a> Translator<'a> {
+    // whether the definition is a stream map, and of what shape.
+    // nothing but the shape is checked, which is enough since the
+    // typechecker has already been over it
+    pub fn stream_map(&self, mut expr: &'a Expr<'a>) -> Option<StreamMap> {
+        let mut lams = 0;
+        while let Expr::Lam(_, 1, body) = *expr {
+            lams += 1;
+            expr = body;
+        }
+        let Expr::Lob(_, mut body) = *expr else { return None };
+        if lams == 0 {
+            return None;
+        }
+        let mut parts = vec![MapPart::Func, MapPart::Rec];
+        while let Expr::Lam(_, 1, e) = *body {
+            parts.push(MapPart::Stream(parts.len() as u32 - 2));
+            body = e;
+        }
+        let streams = parts.len() as u32 - 2;
+        if streams == 0 {
+            return None;
+        }
+        while let Expr::LetIn(bound, e) = *body {
+            match map_part(&parts, bound)? {
+                part @ (MapPart::Unpacked(_) | MapPart::Head(_) | MapPart::Tail(_)) => parts.push(part),
+                _ => return None,
+            }
+            body = e;
+        }
+        let Expr::Con(Con::Stream, &[head, &Expr::Delay(_, tail)]) = *body else { return None };
+        let (f, heads) = spine(head);
+        let (rec, tails) = spine(tail);
+        let is = |e: &Expr<'_>, part| map_part(&parts, e) == Some(part);
+        let func_ok = matches!(*f, Expr::Unbox(f) if is(f, MapPart::Func));
+        let rec_ok = matches!(*rec, Expr::Adv(&Expr::Unbox(r)) if is(r, MapPart::Rec));
+        let heads_ok = heads.len() == streams as usize &&
+            heads.iter().zip(0..).all(|(h, i)| is(h, MapPart::Head(i)));
+        let tails_ok = tails.len() == streams as usize &&
+            tails.iter().zip(0..).all(|(t, i)| matches!(**t, Expr::Adv(t) if is(t, MapPart::Tail(i))));
+        (func_ok && rec_ok && heads_ok && tails_ok).then_some(StreamMap { clocks: lams - 1, streams })
+    }
+
+    // maps is the stream maps among the globals, with their shapes
+    fn stream_map_app(&self, maps: &HashMap<Global, StreamMap>, expr: &'a Expr<'a>) -> Option<MapApp<'a>> {
+        let mut args = Vec::new();
+        let mut e = expr;
+        while let Expr::App(f, &[arg]) = *e {
+            args.push(arg);
+            e = f;
+        }
+        let Expr::Glob(g) = *e else { return None };
+        let shape = *maps.get(&g)?;
+        if args.len() != (shape.clocks + 1 + shape.streams) as usize {
+            return None;
+        }
+        args.reverse();
+        let mut map = expr;
+        for _ in 0..=shape.streams {
+            let Expr::App(e, _) = *map else { unreachable!() };
+            map = e;
+        }
+        let func = args[shape.clocks as usize];
+        let streams = args[shape.clocks as usize + 1..].to_vec();
+        Some(MapApp { shape, map, func, streams })
+    }
+
+    // fuses a stream map applied to another into one map of the two
+    // functions composed, so each sample goes through one stream cell
+    // and one delayed tail rather than one per map. one of the two has
+    // to take a single stream: a map into one stream of a zip, or a
+    // zip into a map. the functions get moved under the composition's
+    // box, so they have to be things that are fine to evaluate there
+    // every sample
+    pub fn fuse_stream_maps(&self, maps: &HashMap<Global, StreamMap>, expr: &'a Expr<'a>) -> &'a Expr<'a> {
+        use Expr::*;
+        let mut expr = match *expr {
+            Var(_) | Val(_) | Glob(_) => expr,
+            Lam(ref vars, arity, e) => self.alloc(Lam(vars.clone(), arity, self.fuse_stream_maps(maps, e))),
+            App(e, es) => self.alloc(App(self.fuse_stream_maps(maps, e), self.slice_fuse_stream_maps(maps, es))),
+            Unbox(e) => self.alloc(Unbox(self.fuse_stream_maps(maps, e))),
+            Box(ref vars, e) => self.alloc(Box(vars.clone(), self.fuse_stream_maps(maps, e))),
+            Lob(ref vars, e) => self.alloc(Lob(vars.clone(), self.fuse_stream_maps(maps, e))),
+            LetIn(e1, e2) => self.alloc(LetIn(self.fuse_stream_maps(maps, e1), self.fuse_stream_maps(maps, e2))),
+            If(e0, e1, e2) => self.alloc(If(self.fuse_stream_maps(maps, e0), self.fuse_stream_maps(maps, e1), self.fuse_stream_maps(maps, e2))),
+            Con(con, es) => self.alloc(Con(con, self.slice_fuse_stream_maps(maps, es))),
+            Op(op, es) => self.alloc(Op(op, self.slice_fuse_stream_maps(maps, es))),
+            Delay(ref vars, e) => self.alloc(Delay(vars.clone(), self.fuse_stream_maps(maps, e))),
+            Adv(e) => self.alloc(Adv(self.fuse_stream_maps(maps, e))),
+        };
+        // each fusion takes out a map, so this stops
+        while let Some(fused) = self.fuse_stream_map_app(maps, expr) {
+            expr = fused;
+        }
+        expr
+    }
+
+    fn fuse_stream_map_app(&self, maps: &HashMap<Global, StreamMap>, expr: &'a Expr<'a>) -> Option<&'a Expr<'a>> {
+        let movable = |f: &Expr<'_>| matches!(*f, Expr::Var(_) | Expr::Glob(_) | Expr::Box(_, &Expr::Lam(_, 1, _)));
+        let outer = self.stream_map_app(maps, expr).filter(|app| movable(app.func))?;
+        let n = outer.shape.streams;
+        let var = |i| self.alloc(Expr::Var(DebruijnIndex(i)));
+        for (p, &s) in outer.streams.iter().enumerate() {
+            let Some(inner) = self.stream_map_app(maps, s).filter(|app| movable(app.func)) else { continue };
+            let m = inner.shape.streams;
+            let (map, streams, body) = if m == 1 {
+                // f applied to the streams, with g applied to the pth
+                let args: Vec<_> = (0..n).map(|j|
+                    if j as usize == p {
+                        self.apply_under_lams(inner.func, &[var(n - 1 - j)], n)
+                    } else {
+                        var(n - 1 - j)
+                    }
+                ).collect();
+                let mut streams = outer.streams.clone();
+                streams[p] = inner.streams[0];
+                (outer.map, streams, self.apply_under_lams(outer.func, &args, n))
+            } else if n == 1 {
+                // f applied to g applied to the streams
+                let args: Vec<_> = (0..m).map(|j| var(m - 1 - j)).collect();
+                let inner_app = self.apply_under_lams(inner.func, &args, m);
+                (inner.map, inner.streams.clone(), self.apply_under_lams(outer.func, &[inner_app], m))
+            } else {
+                continue;
+            };
+            let lams = (0..streams.len()).fold(body, |e, _| self.alloc(Expr::Lam(None, 1, e)));
+            let composed = self.alloc(Expr::Box(None, lams));
+            let applied = streams.iter().fold(
+                self.alloc(Expr::App(map, self.alloc_slice([composed]))),
+                |e, &s| self.alloc(Expr::App(e, self.alloc_slice([s])))
+            );
+            return Some(applied);
+        }
+        None
+    }
+
+    fn slice_fuse_stream_maps(&self, maps: &HashMap<Global, StreamMap>, exprs: &[&'a Expr<'a>]) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.fuse_stream_maps(maps, e)))
+    }
+
+    // applies the boxed function h, from outside depth lambdas, to
+    // args, from inside them. a boxed lambda gets its body put in
+    // place, with the arguments let-bound, rather than being built and
+    // called
+    fn apply_under_lams(&self, h: &'a Expr<'a>, args: &[&'a Expr<'a>], depth: u32) -> &'a Expr<'a> {
+        if let Expr::Box(_, mut body) = *h {
+            let mut params = 0;
+            while params < args.len() {
+                let Expr::Lam(_, 1, e) = *body else { break };
+                body = e;
+                params += 1;
+            }
+            if params == args.len() {
+                let bindings: Vec<_> = args.iter().zip(0..).map(|(arg, i)|
+                    self.alloc(arg.shifted_by(i, 0, self.arena))
+                ).collect();
+                return self.build_lets(&bindings, self.alloc(body.shifted_by(depth, params as u32, self.arena)));
+            }
+        }
+        args.iter().fold(
+            self.alloc(Expr::Unbox(self.alloc(h.shifted_by(depth, 0, self.arena)))),
+            |e, &arg| self.alloc(Expr::App(e, self.alloc_slice([arg])))
+        )
+    }
+}
@@ -185,12 +185,12 @@ impl ToEgglogConverter {
             },
             Op::UnGen => self.app("UnGen".into(), vec![]),
             Op::AllocAndFill => self.app("AllocAndFill".into(), vec![]),
-            Op::AllocF32 => self.app("AllocF32".into(), vec![]),
-            Op::AllocI32 => self.app("AllocI32".into(), vec![]),
+            Op::PackF32 => self.app("PackF32".into(), vec![]),
+            Op::PackI32 => self.app("PackI32".into(), vec![]),
             Op::BuildClosure(_) => todo!(),
             Op::LoadGlobal(_) => todo!(),
-            Op::DerefF32 => self.app("DerefF32".into(), vec![]),
-            Op::DerefI32 => self.app("DerefI32".into(), vec![]),
+            Op::UnpackF32 => self.app("UnpackF32".into(), vec![]),
+            Op::UnpackI32 => self.app("UnpackI32".into(), vec![]),
             Op::ApplyCoeff(c) => {
                 let n = self.lit_int(*c.numer() as i64);
                 let d = self.lit_int(*c.denom() as i64);
@@ -348,6 +348,7 @@ impl<'a> FromEgglogConverter<'a> {
 
     fn term_to_op(&self, t: Term) -> Op {
         match_term_app!(t; {
+            ("Const", &[v]) => Op::Const(self.val_term_to_value(self.termdag.get(v))),
             ("FAdd", &[]) => Op::FAdd,
             ("FSub", &[]) => Op::FSub,
             ("FMul", &[]) => Op::FMul,
@@ -381,10 +382,10 @@ impl<'a> FromEgglogConverter<'a> {
             ("CastI2F", &[]) => Op::CastI2F,
             ("UnGen", &[]) => Op::UnGen,
             ("AllocAndFill", &[]) => Op::AllocAndFill,
-            ("AllocF32", &[]) => Op::AllocF32,
-            ("AllocI32", &[]) => Op::AllocI32,
-            ("DerefF32", &[]) => Op::DerefF32,
-            ("DerefI32", &[]) => Op::DerefI32,
+            ("PackF32", &[]) => Op::PackF32,
+            ("PackI32", &[]) => Op::PackI32,
+            ("UnpackF32", &[]) => Op::UnpackF32,
+            ("UnpackI32", &[]) => Op::UnpackI32,
             ("SinceLastTickStream", &[]) => Op::SinceLastTickStream,
             ("Advance", &[]) => Op::Advance,
             ("Wait", &[]) => Op::Wait,
@@ -1,3 +1,5 @@
+use std::{collections::BTreeSet, fmt};
+
 use crate::ir1::{DebruijnIndex, Expr as HExpr, Op, Global, Con, Value};
 use crate::util::ArenaPlus;
 
@@ -7,7 +9,8 @@ pub enum Expr<'a> {
     If(&'a Expr<'a>, &'a Expr<'a>, &'a Expr<'a>),
     Let(&'a [&'a Expr<'a>], &'a Expr<'a>),
     Op(Op, &'a [&'a Expr<'a>]),
-    #[allow(unused)]
+    // a saturated call to a function with no environment, which
+    // doesn't need its closure
     CallDirect(Global, &'a [&'a Expr<'a>]),
     CallIndirect(&'a Expr<'a>, &'a [&'a Expr<'a>]),
 }
@@ -36,11 +39,100 @@ impl<'a> GlobalDef<'a> {
     }
 }
 
+impl<'a> fmt::Display for GlobalDef<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            GlobalDef::Func { rec, arity, env_size, body } =>
+                write!(f, "func(arity {}, env {}{}) = {}", arity, env_size, if rec { ", rec" } else { "" }, body.pretty()),
+            GlobalDef::ClosedExpr { body } =>
+                write!(f, "{}", body.pretty()),
+        }
+    }
+}
+
+impl<'a> Expr<'a> {
+    pub fn pretty<'b>(&'b self) -> PrettyExpr<'b, 'a> {
+        PrettyExpr { expr: self, indent: 0 }
+    }
+}
+
+// the same notation as ir1::PrettyExpr
+pub struct PrettyExpr<'a, 'b> {
+    expr: &'a Expr<'b>,
+    indent: usize,
+}
+
+impl<'a, 'b> PrettyExpr<'a, 'b> {
+    fn for_expr(&self, other_expr: &'a Expr<'b>) -> PrettyExpr<'a, 'b> {
+        PrettyExpr { expr: other_expr, indent: self.indent }
+    }
+
+    fn indented(&self, other_expr: &'a Expr<'b>) -> PrettyExpr<'a, 'b> {
+        PrettyExpr { expr: other_expr, indent: self.indent + 2 }
+    }
+
+    fn list(&self, f: &mut fmt::Formatter<'_>, es: &'a [&'a Expr<'b>]) -> fmt::Result {
+        for (i, e) in es.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", self.indented(e))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, 'b> fmt::Display for PrettyExpr<'a, 'b> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indent = self.indent;
+        match *self.expr {
+            Expr::Var(i) =>
+                write!(f, "#{}", i.0),
+            Expr::If(e0, e1, e2) =>
+                write!(f, "if {}\n{:indent$}then {}\n{:indent$}else {}", self.for_expr(e0), "", self.indented(e1), "", self.indented(e2)),
+            Expr::Let(es, body) => {
+                write!(f, "let ")?;
+                self.list(f, es)?;
+                write!(f, " in\n{:indent$}{}", "", self.for_expr(body))
+            },
+            Expr::Op(op, []) =>
+                write!(f, "{}", op),
+            Expr::Op(op, es) => {
+                write!(f, "{}(", op)?;
+                self.list(f, es)?;
+                write!(f, ")")
+            },
+            Expr::CallDirect(g, es) => {
+                write!(f, "{}(", g)?;
+                self.list(f, es)?;
+                write!(f, ")")
+            },
+            Expr::CallIndirect(e0, es) => {
+                write!(f, "apply({}", self.for_expr(e0))?;
+                if !es.is_empty() {
+                    write!(f, ", ")?;
+                    self.list(f, es)?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
+
 pub struct Translator<'a> {
     pub arena: &'a ArenaPlus<'a, Expr<'a>>,
     pub globals: Vec<GlobalDef<'a>>,
 }
 
+// a global whose function we know without looking at its closure
+enum KnownFunc<'a> {
+    // builtins are the only funcs that are globals themselves, rather
+    // than being built into a closure. they're small enough to inline
+    Builtin { arity: u32, body: &'a Expr<'a> },
+    // a def that's a lambda with nothing captured
+    Func { func: Global, arity: u32 },
+}
+
 impl<'a> Translator<'a> {
     pub fn translate<'b>(&mut self, expr: &'b HExpr<'b>) -> &'a Expr<'a> {
         let arena = self.arena;
@@ -139,16 +231,305 @@ impl<'a> Translator<'a> {
             Con::Pair |
             Con::ClockEx =>
                 arena.alloc_slice_r(args.iter().map(|e| self.translate(e))),
+            // case takes the first branch when the tag is nonzero,
+            // which is also what comparisons give for true and what
+            // the runtime puts in a sched cell before it's filled
             Con::InL => {
                 assert!(args.len() == 1);
-                let one = arena.alloc(Expr::Op(Op::Const(Value::Index(0)), &[]));
-                arena.alloc_slice([one, self.translate(args[0])])
+                let tag = arena.alloc(Expr::Op(Op::Const(Value::Index(1)), &[]));
+                arena.alloc_slice([tag, self.translate(args[0])])
             },
             Con::InR => {
                 assert!(args.len() == 1);
-                let one = arena.alloc(Expr::Op(Op::Const(Value::Index(1)), &[]));
-                arena.alloc_slice([one, self.translate(args[0])])
+                let tag = arena.alloc(Expr::Op(Op::Const(Value::Index(0)), &[]));
+                arena.alloc_slice([tag, self.translate(args[0])])
+            },
+        }
+    }
+}
+
+impl<'a> Translator<'a> {
+    fn known_func(&self, Global(g): Global) -> Option<KnownFunc<'a>> {
+        match self.globals[g as usize] {
+            GlobalDef::Func { rec: false, arity, env_size: 0, body } =>
+                Some(KnownFunc::Builtin { arity, body }),
+            GlobalDef::ClosedExpr { body: &Expr::Op(Op::BuildClosure(func), &[]) } =>
+                match self.globals[func.0 as usize] {
+                    GlobalDef::Func { rec: false, arity, env_size: 0, .. } =>
+                        Some(KnownFunc::Func { func, arity }),
+                    _ => None,
+                },
+            _ => None,
+        }
+    }
+
+    // turns saturated calls to known functions into direct calls, and
+    // inlines builtins. this has to wait until every def is
+    // translated, so we can see which ones are lambdas
+    pub fn direct_calls(&mut self) {
+        for i in 0..self.globals.len() {
+            let new_body = match self.globals[i] {
+                GlobalDef::Func { body, .. } |
+                GlobalDef::ClosedExpr { body } =>
+                    self.direct_calls_in(body),
+            };
+            match self.globals[i] {
+                GlobalDef::Func { ref mut body, .. } |
+                GlobalDef::ClosedExpr { ref mut body } =>
+                    *body = new_body,
+            }
+        }
+    }
+
+    fn direct_calls_in(&self, expr: &'a Expr<'a>) -> &'a Expr<'a> {
+        let arena = self.arena;
+        match *expr {
+            Expr::Var(_) =>
+                expr,
+            Expr::If(e0, e1, e2) =>
+                arena.alloc(Expr::If(self.direct_calls_in(e0), self.direct_calls_in(e1), self.direct_calls_in(e2))),
+            Expr::Let(es, ec) =>
+                arena.alloc(Expr::Let(self.slice_direct_calls_in(es), self.direct_calls_in(ec))),
+            // pi is a builtin with no arguments, so using it is
+            // already a saturated call
+            Expr::Op(Op::LoadGlobal(g), &[]) =>
+                match self.known_func(g) {
+                    Some(KnownFunc::Builtin { arity: 0, body }) => body,
+                    _ => expr,
+                },
+            Expr::Op(op, es) =>
+                arena.alloc(Expr::Op(op, self.slice_direct_calls_in(es))),
+            Expr::CallDirect(g, es) =>
+                arena.alloc(Expr::CallDirect(g, self.slice_direct_calls_in(es))),
+            Expr::CallIndirect(target, args) => {
+                // ir1 applies one argument at a time, so gather up the
+                // whole chain of applications
+                let mut head = expr;
+                let mut arg_groups = Vec::new();
+                while let Expr::CallIndirect(target, args) = *head {
+                    arg_groups.push(args);
+                    head = target;
+                }
+                arg_groups.reverse();
+                let known = match *head {
+                    Expr::Op(Op::LoadGlobal(g), &[]) => self.known_func(g),
+                    _ => None,
+                };
+                let arity = match known {
+                    Some(KnownFunc::Builtin { arity, .. } | KnownFunc::Func { arity, .. }) => arity,
+                    None => return arena.alloc(Expr::CallIndirect(self.direct_calls_in(target), self.slice_direct_calls_in(args))),
+                };
+                // how many of the groups it takes to saturate it, if
+                // they do so exactly
+                let mut n_args = 0;
+                let mut n_groups = 0;
+                let saturating = loop {
+                    if n_groups == arg_groups.len() {
+                        break None;
+                    }
+                    n_args += arg_groups[n_groups].len() as u32;
+                    n_groups += 1;
+                    if n_args >= arity {
+                        break (n_args == arity).then_some(n_groups);
+                    }
+                };
+                let Some(n_groups) = saturating else {
+                    return arena.alloc(Expr::CallIndirect(self.direct_calls_in(target), self.slice_direct_calls_in(args)));
+                };
+                let call_args: Vec<_> = arg_groups[..n_groups].iter()
+                    .flat_map(|args| args.iter())
+                    .map(|e| self.direct_calls_in(e))
+                    .collect();
+                let call_args = arena.alloc_slice_r(call_args);
+                let call = match known.unwrap() {
+                    KnownFunc::Builtin { body, .. } =>
+                        // the builtin's arguments are its innermost
+                        // variables, last one first, which is just how
+                        // let binds them
+                        arena.alloc(Expr::Let(call_args, body)),
+                    KnownFunc::Func { func, .. } =>
+                        arena.alloc(Expr::CallDirect(func, call_args)),
+                };
+                arg_groups[n_groups..].iter().fold(call, |call, args| {
+                    arena.alloc(Expr::CallIndirect(call, self.slice_direct_calls_in(args)))
+                })
             },
         }
     }
+
+    fn slice_direct_calls_in(&self, exprs: &[&'a Expr<'a>]) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.direct_calls_in(e)))
+    }
+}
+
+fn globals_used(expr: &Expr<'_>, used: &mut Vec<Global>) {
+    match *expr {
+        Expr::Var(_) => {},
+        Expr::If(e0, e1, e2) => {
+            globals_used(e0, used);
+            globals_used(e1, used);
+            globals_used(e2, used);
+        },
+        Expr::Let(es, ec) => {
+            for e in es.iter() {
+                globals_used(e, used);
+            }
+            globals_used(ec, used);
+        },
+        Expr::Op(op, es) => {
+            if let Op::LoadGlobal(g) | Op::BuildClosure(g) = op {
+                used.push(g);
+            }
+            for e in es.iter() {
+                globals_used(e, used);
+            }
+        },
+        Expr::CallDirect(g, es) => {
+            used.push(g);
+            for e in es.iter() {
+                globals_used(e, used);
+            }
+        },
+        Expr::CallIndirect(e, es) => {
+            globals_used(e, used);
+            for e in es.iter() {
+                globals_used(e, used);
+            }
+        },
+    }
+}
+
+impl<'a> Translator<'a> {
+    // drops the globals that can't be reached from the roots, keeping
+    // the rest in the same order, since that's the order closed
+    // expressions get initialized in. gives where each global went
+    pub fn prune_unreachable(&mut self, roots: &[usize]) -> Vec<Option<u32>> {
+        let mut reachable = vec![false; self.globals.len()];
+        let mut to_visit = roots.to_vec();
+        while let Some(i) = to_visit.pop() {
+            if reachable[i] {
+                continue;
+            }
+            reachable[i] = true;
+            let mut used = Vec::new();
+            match self.globals[i] {
+                GlobalDef::Func { body, .. } |
+                GlobalDef::ClosedExpr { body } =>
+                    globals_used(body, &mut used),
+            }
+            to_visit.extend(used.into_iter().map(|Global(g)| g as usize));
+        }
+
+        let mut next = 0;
+        let new_indices: Vec<Option<u32>> = reachable.iter().map(|&r| {
+            r.then(|| {
+                next += 1;
+                next - 1
+            })
+        }).collect();
+
+        let globals = std::mem::take(&mut self.globals);
+        self.globals = globals.into_iter().zip(reachable).filter(|&(_, r)| r).map(|(def, _)| {
+            match def {
+                GlobalDef::Func { rec, arity, env_size, body } =>
+                    GlobalDef::Func { rec, arity, env_size, body: self.renumber_globals(&new_indices, body) },
+                GlobalDef::ClosedExpr { body } =>
+                    GlobalDef::ClosedExpr { body: self.renumber_globals(&new_indices, body) },
+            }
+        }).collect();
+        new_indices
+    }
+
+    fn renumber_globals(&self, new_indices: &[Option<u32>], expr: &'a Expr<'a>) -> &'a Expr<'a> {
+        let arena = self.arena;
+        let renumber = |Global(g): Global| Global(new_indices[g as usize].unwrap());
+        match *expr {
+            Expr::Var(_) =>
+                expr,
+            Expr::If(e0, e1, e2) =>
+                arena.alloc(Expr::If(
+                    self.renumber_globals(new_indices, e0),
+                    self.renumber_globals(new_indices, e1),
+                    self.renumber_globals(new_indices, e2)
+                )),
+            Expr::Let(es, ec) =>
+                arena.alloc(Expr::Let(self.slice_renumber_globals(new_indices, es), self.renumber_globals(new_indices, ec))),
+            Expr::Op(op, es) => {
+                let op = match op {
+                    Op::LoadGlobal(g) => Op::LoadGlobal(renumber(g)),
+                    Op::BuildClosure(g) => Op::BuildClosure(renumber(g)),
+                    _ => op,
+                };
+                arena.alloc(Expr::Op(op, self.slice_renumber_globals(new_indices, es)))
+            },
+            Expr::CallDirect(g, es) =>
+                arena.alloc(Expr::CallDirect(renumber(g), self.slice_renumber_globals(new_indices, es))),
+            Expr::CallIndirect(e, es) =>
+                arena.alloc(Expr::CallIndirect(self.renumber_globals(new_indices, e), self.slice_renumber_globals(new_indices, es))),
+        }
+    }
+
+    fn slice_renumber_globals(&self, new_indices: &[Option<u32>], exprs: &[&'a Expr<'a>]) -> &'a [&'a Expr<'a>] {
+        self.arena.alloc_slice_r(exprs.iter().map(|e| self.renumber_globals(new_indices, e)))
+    }
+}
+
+fn call_arg_counts(expr: &Expr<'_>, counts: &mut BTreeSet<u32>) {
+    match *expr {
+        Expr::Var(_) => {},
+        Expr::If(e0, e1, e2) => {
+            call_arg_counts(e0, counts);
+            call_arg_counts(e1, counts);
+            call_arg_counts(e2, counts);
+        },
+        Expr::Let(es, ec) => {
+            for e in es.iter() {
+                call_arg_counts(e, counts);
+            }
+            call_arg_counts(ec, counts);
+        },
+        Expr::Op(_, es) |
+        Expr::CallDirect(_, es) => {
+            for e in es.iter() {
+                call_arg_counts(e, counts);
+            }
+        },
+        Expr::CallIndirect(e, es) => {
+            counts.insert(es.len() as u32);
+            call_arg_counts(e, counts);
+            for e in es.iter() {
+                call_arg_counts(e, counts);
+            }
+        },
+    }
+}
+
+// the partial applications that might happen, as (arity, number of
+// arguments) pairs: every indirect call might be to any function with
+// more arguments than it gives, and the closure that builds has the
+// rest as its arity, so it might get partially applied again
+pub fn partial_apps_needed(globals: &[GlobalDef<'_>]) -> BTreeSet<(u32, u32)> {
+    let mut arg_counts = BTreeSet::new();
+    for def in globals.iter() {
+        match *def {
+            GlobalDef::Func { body, .. } |
+            GlobalDef::ClosedExpr { body } =>
+                call_arg_counts(body, &mut arg_counts),
+        }
+    }
+    arg_counts.remove(&0);
+
+    let mut needed = BTreeSet::new();
+    let mut arities: Vec<u32> = globals.iter().filter_map(|def| def.arity()).collect();
+    let mut seen_arities = BTreeSet::new();
+    while let Some(arity) = arities.pop() {
+        if !seen_arities.insert(arity) {
+            continue;
+        }
+        for &n_args in arg_counts.range(..arity) {
+            needed.insert((arity, n_args));
+            arities.push(arity - n_args);
+        }
+    }
+    needed
 }
@@ -1,14 +1,22 @@
 pub mod expr;
+pub mod diagnostic;
 pub mod builtin;
 pub mod parse;
+pub mod format;
 pub mod typing;
 pub mod ir1;
 pub mod ir1_egglog;
 pub mod ir2;
+pub mod interp;
 pub mod util;
 pub mod wasm;
 pub mod runtime;
 pub mod toplevel;
+pub mod session;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod lsp;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod repl;
 
 #[cfg(target_arch = "wasm32")]
 pub mod bindings;
@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::io;
+use std::ops::Range;
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types as lsp;
+use lsp_types::Url;
+use string_interner::DefaultStringInterner;
+use typed_arena::Arena;
+
+use crate::diagnostic::{Diagnostic, Severity};
+use crate::expr::{Expr, TopLevelDefBody};
+use crate::toplevel::{check_parsed, TopLevel};
+
+// what we know about one version of a file. this owns everything it
+// needs so that it doesn't have to hang on to the arena that the
+// file was checked in
+pub struct Analysis {
+    pub diagnostics: Vec<Diagnostic>,
+    // byte range and type of each subexpression that typechecked,
+    // innermost first
+    types: Vec<(Range<usize>, String)>,
+    // top-level definitions, in order
+    defs: Vec<(String, Range<usize>)>,
+    // local binders, with the byte range they're in scope over
+    locals: Vec<(String, Range<usize>)>,
+}
+
+fn byte_range(range: &tree_sitter::Range) -> Range<usize> {
+    range.start_byte .. range.end_byte
+}
+
+impl Analysis {
+    pub fn new(code: &str) -> Analysis {
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        toplevel.type_log = Some(Vec::new());
+
+        let parsed = toplevel.make_parser().parse_file(code);
+        let source = match parsed {
+            Ok(ref file) => file.clone(),
+            Err(ref err) => err.partial.clone(),
+        };
+        let diagnostics = match check_parsed(&mut toplevel, code.to_string(), parsed) {
+            Ok(_) => Vec::new(),
+            Err(err) => err.diagnostics(&toplevel.interner),
+        };
+
+        let type_log = toplevel.type_log.take().unwrap_or_default();
+        let interner = &toplevel.interner;
+        let types = type_log.iter()
+            .map(|(range, ty)| (byte_range(range), ty.pretty(interner).to_string()))
+            .collect();
+        let defs = source.defs.iter()
+            .map(|def| (interner.resolve(def.name).unwrap().to_string(), byte_range(&def.range)))
+            .collect();
+        let mut locals = Vec::new();
+        for def in source.defs.iter() {
+            if let TopLevelDefBody::Def { expr, .. } = def.body {
+                collect_locals(interner, expr, &mut locals);
+            }
+        }
+
+        Analysis { diagnostics, types, defs, locals }
+    }
+
+    // the type of the innermost subexpression around the given byte
+    // offset, along with where that subexpression is
+    pub fn type_at(&self, offset: usize) -> Option<(Range<usize>, &str)> {
+        let mut best: Option<&(Range<usize>, String)> = None;
+        for entry in self.types.iter() {
+            if !entry.0.contains(&offset) {
+                continue;
+            }
+            match best {
+                Some(b) if b.0.len() <= entry.0.len() => { },
+                _ => best = Some(entry),
+            }
+        }
+        best.map(|(range, ty)| (range.clone(), &**ty))
+    }
+
+    // where the top-level definition named by the identifier at the
+    // given byte offset is, unless a local binding shadows it there
+    pub fn definition_at(&self, code: &str, offset: usize) -> Option<Range<usize>> {
+        let word = &code[identifier_at(code, offset)?];
+        if self.locals.iter().any(|(name, scope)| name == word && scope.contains(&offset)) {
+            return None;
+        }
+        self.defs.iter().find(|(name, _)| name == word).map(|(_, range)| range.clone())
+    }
+
+    pub fn def_names(&self) -> impl Iterator<Item=&str> {
+        self.defs.iter().map(|(name, _)| &**name)
+    }
+}
+
+fn is_identifier_byte(b: u8) -> bool {
+    b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'
+}
+
+// the byte range of the identifier touching the given offset
+fn identifier_at(code: &str, offset: usize) -> Option<Range<usize>> {
+    let bytes = code.as_bytes();
+    let offset = offset.min(bytes.len());
+    let mut start = offset;
+    while start > 0 && is_identifier_byte(bytes[start - 1]) {
+        start -= 1;
+    }
+    let mut end = offset;
+    while end < bytes.len() && is_identifier_byte(bytes[end]) {
+        end += 1;
+    }
+    if start < end && !bytes[start].is_ascii_digit() {
+        Some(start .. end)
+    } else {
+        None
+    }
+}
+
+fn collect_locals(interner: &DefaultStringInterner, expr: &Expr<'_, tree_sitter::Range>, locals: &mut Vec<(String, Range<usize>)>) {
+    let bind = |x, scope: &Expr<'_, tree_sitter::Range>| (interner.resolve(x).unwrap().to_string(), byte_range(scope.range()));
+    match *expr {
+        Expr::Var(..) |
+        Expr::Val(..) |
+        Expr::Hole(..) => { },
+        Expr::Lam(_, x, e) |
+        Expr::Lob(_, _, x, e) |
+        Expr::ClockLam(_, x, e) => {
+            locals.push(bind(x, e));
+            collect_locals(interner, e, locals);
+        },
+        Expr::LetIn(_, x, _, e1, e2) => {
+            locals.push(bind(x, e2));
+            collect_locals(interner, e1, locals);
+            collect_locals(interner, e2, locals);
+        },
+        Expr::UnPair(_, x1, x2, e0, e) => {
+            locals.push(bind(x1, e));
+            locals.push(bind(x2, e));
+            collect_locals(interner, e0, locals);
+            collect_locals(interner, e, locals);
+        },
+        Expr::ExElim(_, c, x, e0, e) => {
+            locals.push(bind(c, e));
+            locals.push(bind(x, e));
+            collect_locals(interner, e0, locals);
+            collect_locals(interner, e, locals);
+        },
+        Expr::Case(_, e0, x1, e1, x2, e2) => {
+            locals.push(bind(x1, e1));
+            locals.push(bind(x2, e2));
+            collect_locals(interner, e0, locals);
+            collect_locals(interner, e1, locals);
+            collect_locals(interner, e2, locals);
+        },
+        Expr::Annotate(_, e, _) |
+        Expr::Adv(_, e) |
+        Expr::InL(_, e) |
+        Expr::InR(_, e) |
+        Expr::UnGen(_, e) |
+        Expr::Delay(_, e) |
+        Expr::Box(_, e) |
+        Expr::Unbox(_, e) |
+        Expr::ClockApp(_, e, _) |
+        Expr::TypeApp(_, e, _) |
+        Expr::ExIntro(_, _, e) =>
+            collect_locals(interner, e, locals),
+        Expr::App(_, e1, e2) |
+        Expr::Gen(_, e1, e2) |
+        Expr::Pair(_, e1, e2) |
+        Expr::Binop(_, _, e1, e2) => {
+            collect_locals(interner, e1, locals);
+            collect_locals(interner, e2, locals);
+        },
+        Expr::Array(_, ref es) =>
+            for e in es.iter() {
+                collect_locals(interner, e, locals);
+            },
+    }
+}
+
+// LSP positions count UTF-16 code units within a line
+fn offset_to_position(code: &str, offset: usize) -> lsp::Position {
+    let offset = offset.min(code.len());
+    let before = &code[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    lsp::Position::new(line as u32, before[line_start..].encode_utf16().count() as u32)
+}
+
+fn position_to_offset(code: &str, position: lsp::Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match code[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return code.len(),
+        }
+    }
+    let line = &code[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let mut units = 0;
+    for (i, c) in line.char_indices() {
+        if units >= position.character as usize {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+fn to_lsp_range(code: &str, range: &Range<usize>) -> lsp::Range {
+    lsp::Range::new(offset_to_position(code, range.start), offset_to_position(code, range.end))
+}
+
+fn to_lsp_diagnostic(uri: &Url, code: &str, diag: &Diagnostic) -> lsp::Diagnostic {
+    let mut message = diag.message.clone();
+    if let Some(ref label) = diag.primary {
+        if !label.message.is_empty() {
+            message.push_str(": ");
+            message.push_str(&label.message);
+        }
+    }
+    for note in diag.notes.iter() {
+        message.push_str("\nnote: ");
+        message.push_str(note);
+    }
+    let related: Vec<_> = diag.secondary.iter()
+        .map(|label| lsp::DiagnosticRelatedInformation {
+            location: lsp::Location::new(uri.clone(), to_lsp_range(code, &label.range)),
+            message: label.message.clone(),
+        })
+        .collect();
+    lsp::Diagnostic {
+        range: diag.primary.as_ref().map_or_else(lsp::Range::default, |label| to_lsp_range(code, &label.range)),
+        severity: Some(match diag.severity {
+            Severity::Error => lsp::DiagnosticSeverity::ERROR,
+            Severity::Warning => lsp::DiagnosticSeverity::WARNING,
+        }),
+        code: diag.code.map(|code| lsp::NumberOrString::String(code.to_string())),
+        source: Some("clocky".to_string()),
+        message,
+        related_information: if related.is_empty() { None } else { Some(related) },
+        ..lsp::Diagnostic::default()
+    }
+}
+
+struct Document {
+    text: String,
+    analysis: Analysis,
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+    // names and types of the builtins, for completion
+    builtins: Vec<(String, String)>,
+}
+
+fn connection_error(err: impl std::error::Error + Send + Sync + 'static) -> io::Error {
+    io::Error::other(err)
+}
+
+// speaks LSP over stdin/stdout until the client shuts us down
+pub fn run() -> io::Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    let capabilities = lsp::ServerCapabilities {
+        text_document_sync: Some(lsp::TextDocumentSyncCapability::Kind(lsp::TextDocumentSyncKind::FULL)),
+        hover_provider: Some(lsp::HoverProviderCapability::Simple(true)),
+        definition_provider: Some(lsp::OneOf::Left(true)),
+        completion_provider: Some(lsp::CompletionOptions::default()),
+        ..lsp::ServerCapabilities::default()
+    };
+    connection.initialize(serde_json::to_value(capabilities).unwrap()).map_err(connection_error)?;
+
+    let arena = Arena::new();
+    let toplevel = TopLevel::new(&arena);
+    let mut builtins: Vec<_> = toplevel.builtins.iter()
+        .map(|(&name, builtin)| (toplevel.interner.resolve(name).unwrap().to_string(), builtin.type_.pretty(&toplevel.interner).to_string()))
+        .collect();
+    builtins.sort();
+
+    let mut server = Server { connection, documents: HashMap::new(), builtins };
+    server.main_loop()?;
+    // the writer thread only finishes once the connection is gone
+    drop(server);
+    io_threads.join()
+}
+
+impl Server {
+    fn main_loop(&mut self) -> io::Result<()> {
+        while let Ok(msg) = self.connection.receiver.recv() {
+            match msg {
+                Message::Request(req) => {
+                    if self.connection.handle_shutdown(&req).map_err(connection_error)? {
+                        return Ok(());
+                    }
+                    let resp = self.handle_request(req);
+                    self.send(Message::Response(resp))?;
+                },
+                Message::Notification(not) =>
+                    self.handle_notification(not)?,
+                Message::Response(_) => { },
+            }
+        }
+        Ok(())
+    }
+
+    fn send(&self, msg: Message) -> io::Result<()> {
+        self.connection.sender.send(msg).map_err(connection_error)
+    }
+
+    fn handle_request(&self, req: Request) -> Response {
+        let Request { id, method, params } = req;
+        let res = match &*method {
+            "textDocument/hover" =>
+                self.parse_params(&id, params).map(|params| serde_json::to_value(self.hover(params))),
+            "textDocument/definition" =>
+                self.parse_params(&id, params).map(|params| serde_json::to_value(self.definition(params))),
+            "textDocument/completion" =>
+                self.parse_params(&id, params).map(|params| serde_json::to_value(self.completion(params))),
+            _ =>
+                return Response::new_err(id, ErrorCode::MethodNotFound as i32, format!("unsupported request \"{}\"", method)),
+        };
+        match res {
+            Ok(result) => Response::new_ok(id, result.unwrap()),
+            Err(resp) => *resp,
+        }
+    }
+
+    // the error response is boxed, since it's much bigger than the
+    // params usually are
+    fn parse_params<P: serde::de::DeserializeOwned>(&self, id: &RequestId, params: serde_json::Value) -> Result<P, Box<Response>> {
+        serde_json::from_value(params)
+            .map_err(|err| Box::new(Response::new_err(id.clone(), ErrorCode::InvalidParams as i32, err.to_string())))
+    }
+
+    fn handle_notification(&mut self, not: Notification) -> io::Result<()> {
+        match &*not.method {
+            "textDocument/didOpen" => {
+                let Ok(params) = serde_json::from_value::<lsp::DidOpenTextDocumentParams>(not.params) else { return Ok(()) };
+                let doc = params.text_document;
+                self.update(doc.uri, doc.version, doc.text)
+            },
+            "textDocument/didChange" => {
+                let Ok(mut params) = serde_json::from_value::<lsp::DidChangeTextDocumentParams>(not.params) else { return Ok(()) };
+                // we only ask for whole-document syncing, so the last
+                // change has all the text
+                let Some(change) = params.content_changes.pop() else { return Ok(()) };
+                self.update(params.text_document.uri, params.text_document.version, change.text)
+            },
+            "textDocument/didClose" => {
+                let Ok(params) = serde_json::from_value::<lsp::DidCloseTextDocumentParams>(not.params) else { return Ok(()) };
+                self.documents.remove(&params.text_document.uri);
+                self.publish_diagnostics(params.text_document.uri, Vec::new(), None)
+            },
+            _ =>
+                Ok(()),
+        }
+    }
+
+    fn update(&mut self, uri: Url, version: i32, text: String) -> io::Result<()> {
+        let analysis = Analysis::new(&text);
+        let diagnostics = analysis.diagnostics.iter()
+            .map(|diag| to_lsp_diagnostic(&uri, &text, diag))
+            .collect();
+        self.documents.insert(uri.clone(), Document { text, analysis });
+        self.publish_diagnostics(uri, diagnostics, Some(version))
+    }
+
+    fn publish_diagnostics(&self, uri: Url, diagnostics: Vec<lsp::Diagnostic>, version: Option<i32>) -> io::Result<()> {
+        let params = lsp::PublishDiagnosticsParams::new(uri, diagnostics, version);
+        self.send(Message::Notification(Notification::new("textDocument/publishDiagnostics".to_string(), params)))
+    }
+
+    fn document_at(&self, pos: &lsp::TextDocumentPositionParams) -> Option<(&Document, usize)> {
+        let doc = self.documents.get(&pos.text_document.uri)?;
+        Some((doc, position_to_offset(&doc.text, pos.position)))
+    }
+
+    fn hover(&self, params: lsp::HoverParams) -> Option<lsp::Hover> {
+        let (doc, offset) = self.document_at(&params.text_document_position_params)?;
+        let (range, ty) = doc.analysis.type_at(offset)?;
+        Some(lsp::Hover {
+            contents: lsp::HoverContents::Scalar(lsp::MarkedString::LanguageString(lsp::LanguageString {
+                language: "clocky".to_string(),
+                value: ty.to_string(),
+            })),
+            range: Some(to_lsp_range(&doc.text, &range)),
+        })
+    }
+
+    fn definition(&self, params: lsp::GotoDefinitionParams) -> Option<lsp::GotoDefinitionResponse> {
+        let pos = &params.text_document_position_params;
+        let (doc, offset) = self.document_at(pos)?;
+        let range = doc.analysis.definition_at(&doc.text, offset)?;
+        Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location::new(pos.text_document.uri.clone(), to_lsp_range(&doc.text, &range))))
+    }
+
+    fn completion(&self, params: lsp::CompletionParams) -> Option<lsp::CompletionResponse> {
+        let (doc, _) = self.document_at(&params.text_document_position)?;
+        let builtins = self.builtins.iter().map(|(name, ty)| lsp::CompletionItem {
+            label: name.clone(),
+            kind: Some(lsp::CompletionItemKind::FUNCTION),
+            detail: Some(ty.clone()),
+            ..lsp::CompletionItem::default()
+        });
+        let defs = doc.analysis.def_names().map(|name| lsp::CompletionItem {
+            label: name.to_string(),
+            kind: Some(lsp::CompletionItemKind::VARIABLE),
+            ..lsp::CompletionItem::default()
+        });
+        Some(lsp::CompletionResponse::Array(builtins.chain(defs).collect()))
+    }
+}
@@ -7,8 +7,7 @@ use typed_arena::Arena;
 
 use clap::Parser as CliParser;
 
-use clocky::parse;
-use clocky::toplevel::{compile, TopLevel, TopLevelError, TopLevelResult};
+use clocky::toplevel::{compile, parse_and_check, Stage, TopLevel, TopLevelError, TopLevelResult};
 
 #[derive(CliParser, Debug)]
 struct Args {
@@ -30,6 +29,10 @@ enum Command {
     Typecheck {
         /// Code file to use
         file: Option<PathBuf>,
+
+        /// How to report errors
+        #[arg(long, value_enum, default_value_t)]
+        message_format: MessageFormat,
     },
     Compile {
         /// Code file to use
@@ -38,12 +41,33 @@ enum Command {
         /// Path to wasm module file to be written
         #[arg(short='o')]
         out: Option<PathBuf>,
+
+        /// Print how many e-classes the optimizer made and what it saved, to stderr
+        #[arg(long)]
+        egraph_stats: bool,
+
+        /// Stages of compilation to show, each written next to the wasm module with the stage as its extension, or to stderr without -o
+        #[arg(long, value_enum, value_delimiter=',')]
+        emit: Vec<Stage>,
     },
     Egglog {
         /// Code file to use
         file: Option<PathBuf>,
     },
-    #[cfg(feature="run")]
+    /// Format the given program, in place, or to stdout if reading from stdin
+    Fmt {
+        /// Code file to use
+        file: Option<PathBuf>,
+
+        /// Don't write anything, just fail if the program isn't formatted
+        #[arg(long)]
+        check: bool,
+    },
+    /// Run a language server over stdin/stdout.
+    Lsp,
+    /// Interactively declare things, show their types and run streams
+    Repl,
+    /// Write the samples the given program plays to a wav file
     Sample {
         /// Code file to use
         file: PathBuf,
@@ -54,9 +78,46 @@ enum Command {
         /// Length of time to sample for
         #[arg(short='l', default_value_t=10.0)]
         length: f32,
+
+        /// Run the program with the reference interpreter instead of compiling it
+        #[arg(long)]
+        interpret: bool,
     },
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+enum MessageFormat {
+    /// Rendered diagnostics on stderr
+    #[default]
+    Human,
+    /// One JSON object per diagnostic per line on stdout
+    Json,
+}
+
+impl Command {
+    fn message_format(&self) -> MessageFormat {
+        match *self {
+            Command::Typecheck { message_format, .. } => message_format,
+            _ => MessageFormat::Human,
+        }
+    }
+
+    fn file(&self) -> Option<&Path> {
+        match *self {
+            Command::Parse { ref file, .. } |
+            Command::Typecheck { ref file, .. } |
+            Command::Compile { ref file, .. } |
+            Command::Egglog { ref file } |
+            Command::Fmt { ref file, .. } =>
+                file.as_deref(),
+            Command::Lsp | Command::Repl =>
+                None,
+            Command::Sample { ref file, .. } =>
+                Some(file),
+        }
+    }
+}
+
 fn read_file(name: Option<&Path>) -> std::io::Result<String> {
     let mut s = String::new();
     match name {
@@ -81,13 +142,13 @@ fn cmd_parse<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>, dump_to: Op
         Ok(parsed_file) => {
             println!("{}", parsed_file.pretty(&toplevel.interner));
         },
-        Err(parse::FullParseError { tree, error }) => {
-            eprintln!("{:?}", error);
+        Err(e) => {
             if let Some(dump_path) = dump_to {
                 let dump_file = File::create(dump_path)?;
                 #[cfg(not(target_arch = "wasm32"))]
-                tree.print_dot_graph(&dump_file);
+                e.tree.print_dot_graph(&dump_file);
             }
+            return Err(TopLevelError::ParseError(code, Box::new(e), None));
         },
     }
     Ok(())
@@ -95,17 +156,24 @@ fn cmd_parse<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>, dump_to: Op
 
 fn cmd_typecheck<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>) -> TopLevelResult<'a, ()> {
     let code = read_file(file.as_deref())?;
-    let parsed_file = match toplevel.make_parser().parse_file(&code) {
-        Ok(parsed_file) => parsed_file,
-        Err(e) => { return Err(TopLevelError::ParseError(code, e)); }
-    };
-    toplevel.make_typechecker().check_file(&parsed_file).map_err(|e| TopLevelError::TypeError(code, e))?;
+    parse_and_check(toplevel, code)?;
     Ok(())
 }
 
 fn cmd_compile<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>, out: Option<PathBuf>) -> TopLevelResult<'a, ()> {
     let code = read_file(file.as_deref())?;
-    let wasm_bytes = compile(toplevel, code)?;
+    let compiled = compile(toplevel, code);
+
+    // whatever stages got done are still worth seeing if a later one
+    // failed
+    for (stage, text) in toplevel.emitted.iter() {
+        match out {
+            Some(ref path) => File::create(path.with_extension(stage.extension()))?.write_all(text.as_bytes())?,
+            None => eprint!(";; {}\n{}\n", stage.extension(), text),
+        }
+    }
+
+    let wasm_bytes = compiled?;
 
     write_file(out.as_deref(), &wasm_bytes)?;
 
@@ -119,22 +187,42 @@ fn cmd_egglog<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>) -> TopLeve
     Ok(())
 }
 
-#[cfg(feature="run")]
-fn cmd_sample<'a>(toplevel: &mut TopLevel<'a>, file: PathBuf, out: PathBuf, length: f32) -> TopLevelResult<'a, ()> {
-    let wasm_bytes = match file.extension() {
-        Some(ext) if ext == "wasm" => {
-            let mut buf = Vec::new();
-            File::open(file)?.read_to_end(&mut buf)?;
-            buf
-        },
-        _ => {
-            let code = read_file(Some(&file))?;
-            compile(toplevel, code)?
-        },
-    };
+// whether the file was already formatted
+fn cmd_fmt<'a>(toplevel: &mut TopLevel<'a>, file: Option<PathBuf>, check: bool) -> TopLevelResult<'a, bool> {
+    let code = read_file(file.as_deref())?;
+    let formatted = clocky::toplevel::format(toplevel, code.clone())?;
+
+    if !check {
+        match file {
+            Some(path) if formatted != code => File::create(path)?.write_all(formatted.as_bytes())?,
+            Some(_) => { },
+            None => print!("{}", formatted),
+        }
+    }
+
+    Ok(formatted == code)
+}
+
+fn cmd_lsp<'a>() -> TopLevelResult<'a, ()> {
+    clocky::lsp::run()?;
+
+    Ok(())
+}
+
+fn cmd_repl<'a>() -> TopLevelResult<'a, ()> {
+    clocky::repl::run()?;
+
+    Ok(())
+}
 
+fn cmd_sample<'a>(toplevel: &mut TopLevel<'a>, file: PathBuf, out: PathBuf, length: f32, interpret: bool) -> TopLevelResult<'a, ()> {
     let num_samples = (length * 48000.0) as usize;
-    let samples = clocky::toplevel::run(&wasm_bytes, num_samples);
+    let samples = if interpret {
+        let code = read_file(Some(&file))?;
+        clocky::toplevel::interpret(toplevel, code, num_samples)?
+    } else {
+        run_compiled(toplevel, file, num_samples)?
+    };
 
     let wav_spec = hound::WavSpec {
         channels: 1,
@@ -151,29 +239,74 @@ fn cmd_sample<'a>(toplevel: &mut TopLevel<'a>, file: PathBuf, out: PathBuf, leng
     Ok(())
 }
 
-fn main() -> Result<(), ExitCode> {
+#[cfg(feature="run")]
+fn run_compiled<'a>(toplevel: &mut TopLevel<'a>, file: PathBuf, num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    let wasm_bytes = match file.extension() {
+        Some(ext) if ext == "wasm" => {
+            let mut buf = Vec::new();
+            File::open(file)?.read_to_end(&mut buf)?;
+            buf
+        },
+        _ => {
+            let code = read_file(Some(&file))?;
+            compile(toplevel, code)?
+        },
+    };
+
+    Ok(clocky::toplevel::run(&wasm_bytes, num_samples))
+}
+
+#[cfg(not(feature="run"))]
+fn run_compiled<'a>(_toplevel: &mut TopLevel<'a>, _file: PathBuf, _num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    Err(TopLevelError::InterpError("running compiled code needs the run feature; try --interpret".to_string()))
+}
+
+fn main() -> ExitCode {
     let args = Args::parse();
 
     let arena = Arena::new();
     let mut toplevel = TopLevel::new(&arena);
+    let file_name = args.cmd.file().map_or("<stdin>".to_string(), |path| path.display().to_string());
+    let message_format = args.cmd.message_format();
+    let mut unformatted = false;
 
     let res = match args.cmd {
         Command::Parse { file, dump_to } => cmd_parse(&mut toplevel, file, dump_to),
-        Command::Typecheck { file } => cmd_typecheck(&mut toplevel, file),
-        Command::Compile { file, out } => cmd_compile(&mut toplevel, file, out),
+        Command::Typecheck { file, .. } => cmd_typecheck(&mut toplevel, file),
+        Command::Compile { file, out, egraph_stats, emit } => {
+            toplevel.egraph_stats = egraph_stats;
+            toplevel.emit = emit;
+            cmd_compile(&mut toplevel, file, out)
+        },
         Command::Egglog { file } => cmd_egglog(&mut toplevel, file),
-        #[cfg(feature = "run")]
-        Command::Sample { file, out, length } => cmd_sample(&mut toplevel, file, out, length),
+        Command::Fmt { file, check } => cmd_fmt(&mut toplevel, file, check).map(|formatted| {
+            if check && !formatted {
+                eprintln!("{} is not formatted", file_name);
+                unformatted = true;
+            }
+        }),
+        Command::Lsp => cmd_lsp(),
+        Command::Repl => cmd_repl(),
+        Command::Sample { file, out, length, interpret } => cmd_sample(&mut toplevel, file, out, length, interpret),
     };
 
     match res {
         Ok(()) => { },
-        Err(TopLevelError::TypeError(code, e)) => {
-            println!("{}", e.pretty(&toplevel.interner, &code));
-            return Err(1.into());
+        Err(err) => {
+            match message_format {
+                MessageFormat::Human => eprint!("{}", err.pretty(&toplevel.interner, &file_name)),
+                MessageFormat::Json => print!("{}", err.json(&toplevel.interner, &file_name)),
+            }
+            return match err {
+                TopLevelError::ParseError(..) | TopLevelError::TypeError(..) => 1.into(),
+                _ => 2.into(),
+            };
         },
-        Err(err) => { println!("{:?}", err); return Err(2.into()); },
     }
 
-    Ok(())
+    if unformatted {
+        return 1.into();
+    }
+
+    ExitCode::SUCCESS
 }
@@ -2,6 +2,7 @@ use string_interner::DefaultStringInterner;
 use typed_arena::Arena;
 use num::rational::Ratio;
 
+use crate::diagnostic::{Diagnostic, Label};
 use crate::{expr::{Binop, Expr, SourceFile, Symbol, TopLevelDef, TopLevelDefBody, TopLevelDefKind, Value}, typing::{Type, ArraySize, Clock, Kind}};
 
 macro_rules! make_node_enum {
@@ -146,45 +147,100 @@ make_field_enum!(Field {
     BinderExpr: binderexpr
 } with matcher ConcreteFieldMatcher);
 
-pub struct Parser<'a, 'b> {
+// the parts of a parser that don't depend on what's being parsed,
+// which are worth keeping around between parses
+pub struct Grammar {
     parser: tree_sitter::Parser,
     node_matcher: ConcreteNodeMatcher,
     field_matcher: ConcreteFieldMatcher,
-    pub interner: &'a mut DefaultStringInterner,
-    pub arena: &'b Arena<Expr<'b, tree_sitter::Range>>,
 }
 
-impl<'a, 'b> Parser<'a, 'b> {
-    pub fn new(interner: &'a mut DefaultStringInterner, arena: &'b Arena<Expr<'b, tree_sitter::Range>>) -> Parser<'a, 'b> {
+impl Grammar {
+    pub fn new() -> Grammar {
         let mut parser = tree_sitter::Parser::new();
         let lang = tree_sitter_clocky::language();
         let node_matcher = ConcreteNodeMatcher::new(&lang);
         let field_matcher = ConcreteFieldMatcher::new(&lang);
         parser.set_language(lang).expect("Error loading clocky grammar");
 
-        Parser {
+        Grammar {
             parser,
             node_matcher,
             field_matcher,
+        }
+    }
+}
+
+impl Default for Grammar {
+    fn default() -> Grammar {
+        Grammar::new()
+    }
+}
+
+pub struct Parser<'a, 'b> {
+    grammar: &'a mut Grammar,
+    pub interner: &'a mut DefaultStringInterner,
+    pub arena: &'b Arena<Expr<'b, tree_sitter::Range>>,
+}
+
+impl<'a, 'b> Parser<'a, 'b> {
+    pub fn new(grammar: &'a mut Grammar, interner: &'a mut DefaultStringInterner, arena: &'b Arena<Expr<'b, tree_sitter::Range>>) -> Parser<'a, 'b> {
+        Parser {
+            grammar,
             interner,
             arena,
         }
     }
 
-    pub fn parse_file(&mut self, text: &str) -> Result<SourceFile<'b, tree_sitter::Range>, FullParseError> {
+    // parses as much of the file as it can, only failing once it's
+    // gone through all of it
+    pub fn parse_file(&mut self, text: &str) -> Result<SourceFile<'b, tree_sitter::Range>, FullParseError<'b>> {
+        self.reparse_file(text, None).1
+    }
+
+    // like parse_file, but reuses what it can of old_tree, which must
+    // have had Tree::edit called on it for each change since it was
+    // parsed. also returns the new tree, for next time
+    pub fn reparse_file(&mut self, text: &str, old_tree: Option<&tree_sitter::Tree>) -> (tree_sitter::Tree, Result<SourceFile<'b, tree_sitter::Range>, FullParseError<'b>>) {
         // this unwrap should be safe because we make sure to set the language and don't set a timeout or cancellation flag
-        let tree = self.parser.parse(text, None).unwrap();
+        let tree = self.grammar.parser.parse(text, old_tree).unwrap();
         let root_node = tree.root_node();
-        AbstractionContext { parser: self, original_text: text }
-            .parse_file(root_node)
-            .map_err(|error| FullParseError { tree, error })
+        let mut errors = Vec::new();
+        syntax_errors(root_node, &mut errors);
+        let mut abs = AbstractionContext { parser: self, original_text: text, errors, dropped: Vec::new() };
+        let partial = abs.parse_file(root_node);
+        if abs.errors.is_empty() {
+            (tree, Ok(partial))
+        } else {
+            let AbstractionContext { errors, dropped, .. } = abs;
+            (tree.clone(), Err(FullParseError { tree, errors, partial, dropped }))
+        }
+    }
+}
+
+// the ERROR and MISSING nodes that tree-sitter put in while
+// recovering, in order
+fn syntax_errors(node: tree_sitter::Node<'_>, errors: &mut Vec<ParseError>) {
+    if node.is_error() {
+        errors.push(ParseError::SyntaxError(node.range()));
+    } else if node.is_missing() {
+        errors.push(ParseError::Missing(node.range(), node.kind().to_string()));
+    } else if node.has_error() {
+        let mut cur = node.walk();
+        for child in node.children(&mut cur) {
+            syntax_errors(child, errors);
+        }
     }
 }
 
 #[derive(Debug)]
-pub struct FullParseError {
+pub struct FullParseError<'b> {
     pub tree: tree_sitter::Tree,
-    pub error: ParseError,
+    pub errors: Vec<ParseError>,
+    // the definitions that did parse
+    pub partial: SourceFile<'b, tree_sitter::Range>,
+    // the names of the ones that didn't, where we could tell
+    pub dropped: Vec<Symbol>,
 }
 
 #[derive(Debug)]
@@ -195,11 +251,97 @@ pub enum ParseError {
     UnknownNodeType(tree_sitter::Range, String),
     BadCoefficient(tree_sitter::Range),
     UhhhhhhWhat(tree_sitter::Range, String),
+    SyntaxError(tree_sitter::Range),
+    Missing(tree_sitter::Range, String),
+    MissingField(tree_sitter::Range, String),
+    UnknownOperator(tree_sitter::Range, String),
+    UnknownBaseType(tree_sitter::Range, String),
+    UnknownKind(tree_sitter::Range, String),
+    // a name starting with an underscore, which can only be a hole,
+    // used as a binder or definition name
+    HoleBinder(tree_sitter::Range),
+}
+
+impl ParseError {
+    pub fn diagnostic(&self, code: &str) -> Diagnostic {
+        let range = self.range();
+        let text = &code[range.start_byte .. range.end_byte];
+        let (message, label) = match *self {
+            ParseError::BadLiteral(_) =>
+                (format!("invalid literal \"{}\"", text), "can't be represented".to_string()),
+            ParseError::ExpectedExpression(_) =>
+                ("expected an expression".to_string(), "this is not an expression".to_string()),
+            ParseError::ExpectedType(_) =>
+                ("expected a type".to_string(), "this is not a type".to_string()),
+            ParseError::UnknownNodeType(_, ref kind) =>
+                (format!("unexpected \"{}\"", kind), String::new()),
+            ParseError::BadCoefficient(_) =>
+                (format!("invalid clock coefficient \"{}\"", text), "coefficients are positive whole numbers or fractions".to_string()),
+            ParseError::UhhhhhhWhat(_, ref what) =>
+                (what.clone(), String::new()),
+            ParseError::SyntaxError(_) =>
+                ("syntax error".to_string(), "couldn't make sense of this".to_string()),
+            ParseError::Missing(_, ref kind) =>
+                (format!("missing \"{}\"", kind), format!("expected \"{}\" here", kind)),
+            ParseError::MissingField(_, ref field) =>
+                (format!("missing {}", field), String::new()),
+            ParseError::UnknownOperator(_, ref op) =>
+                (format!("unknown operator \"{}\"", op), String::new()),
+            ParseError::UnknownBaseType(_, ref base) =>
+                (format!("unknown type \"{}\"", base), String::new()),
+            ParseError::UnknownKind(_, ref kind) =>
+                (format!("unknown kind \"{}\"", kind), "expected \"clock\" or \"type\"".to_string()),
+            ParseError::HoleBinder(_) =>
+                (format!("can't bind \"{}\"", text), "names starting with \"_\" are holes, so this could never be used".to_string()),
+        };
+        Diagnostic::new(message).with_code(self.code()).with_primary(Label::new(&range, label))
+    }
+
+    // stable identifiers for tools to match on; never renumber these,
+    // only add new ones
+    pub fn code(&self) -> &'static str {
+        match *self {
+            ParseError::BadLiteral(_) => "E0101",
+            ParseError::ExpectedExpression(_) => "E0102",
+            ParseError::ExpectedType(_) => "E0103",
+            ParseError::UnknownNodeType(..) => "E0104",
+            ParseError::BadCoefficient(_) => "E0105",
+            ParseError::UhhhhhhWhat(..) => "E0106",
+            ParseError::SyntaxError(_) => "E0107",
+            ParseError::Missing(..) => "E0108",
+            ParseError::MissingField(..) => "E0109",
+            ParseError::UnknownOperator(..) => "E0110",
+            ParseError::UnknownBaseType(..) => "E0111",
+            ParseError::UnknownKind(..) => "E0112",
+            ParseError::HoleBinder(_) => "E0113",
+        }
+    }
+
+    pub fn range(&self) -> tree_sitter::Range {
+        match *self {
+            ParseError::BadLiteral(r) |
+            ParseError::ExpectedExpression(r) |
+            ParseError::ExpectedType(r) |
+            ParseError::UnknownNodeType(r, _) |
+            ParseError::BadCoefficient(r) |
+            ParseError::UhhhhhhWhat(r, _) |
+            ParseError::SyntaxError(r) |
+            ParseError::Missing(r, _) |
+            ParseError::MissingField(r, _) |
+            ParseError::UnknownOperator(r, _) |
+            ParseError::UnknownBaseType(r, _) |
+            ParseError::UnknownKind(r, _) |
+            ParseError::HoleBinder(r) =>
+                r,
+        }
+    }
 }
 
 struct AbstractionContext<'a, 'b, 'c> {
     parser: &'c mut Parser<'a, 'b>,
     original_text: &'c str,
+    errors: Vec<ParseError>,
+    dropped: Vec<Symbol>,
 }
 
 impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
@@ -216,41 +358,66 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
         self.parser.interner.get_or_intern(self.node_text(node))
     }
 
+    // an identifier that a term gets bound to. these can't look like
+    // holes, or every use of them would be taken for one
+    fn binder<'d>(&mut self, node: tree_sitter::Node<'d>) -> Result<Symbol, ParseError> {
+        if self.node_text(node).starts_with('_') {
+            return Err(ParseError::HoleBinder(node.range()));
+        }
+        Ok(self.identifier(node))
+    }
+
     fn field_opt<'d>(&self, node: tree_sitter::Node<'d>, field: Field) -> Option<tree_sitter::Node<'d>> {
-        node.child_by_field_id(self.parser.field_matcher.lookup(field))
+        node.child_by_field_id(self.parser.grammar.field_matcher.lookup(field))
     }
 
-    fn field<'d>(&self, node: tree_sitter::Node<'d>, field: Field) -> tree_sitter::Node<'d> {
+    fn field<'d>(&self, node: tree_sitter::Node<'d>, field: Field) -> Result<tree_sitter::Node<'d>, ParseError> {
         self.field_opt(node, field)
-            .unwrap_or_else(|| panic!("node {:?} did not have field {:?}", node, field))
+            .ok_or_else(|| ParseError::MissingField(node.range(), format!("{:?}", field).to_lowercase()))
     }
 
-    fn parse_file<'d>(&mut self, node: tree_sitter::Node<'d>) -> Result<SourceFile<'b, tree_sitter::Range>, ParseError> {
-        let Some(ConcreteNode::SourceFile) = self.parser.node_matcher.lookup(node.kind_id()) else {
-            return Err(ParseError::UhhhhhhWhat(node.range(), "you didn't pass me a file".to_string()));
+    fn parse_file<'d>(&mut self, node: tree_sitter::Node<'d>) -> SourceFile<'b, tree_sitter::Range> {
+        let mut defs = Vec::new();
+        let Some(ConcreteNode::SourceFile) = self.parser.grammar.node_matcher.lookup(node.kind_id()) else {
+            self.errors.push(ParseError::UhhhhhhWhat(node.range(), "you didn't pass me a file".to_string()));
+            return SourceFile { defs };
         };
 
-        let mut defs = Vec::new();
         let mut cur = node.walk();
-        cur.goto_first_child();
-        loop {
-            let node = cur.node();
-            if !node.is_extra() {
-                defs.push(self.parse_top_level_decl(node)?);
+        for node in node.children(&mut cur) {
+            if node.is_extra() {
+                continue;
             }
-            if !cur.goto_next_sibling() {
-                break;
+            // anything with an error node in it has already been
+            // reported, and there's no telling what it was meant to be
+            let parsed = if node.has_error() {
+                None
+            } else {
+                self.parse_top_level_decl(node)
+                    .map_err(|err| self.errors.push(err))
+                    .ok()
+            };
+            match parsed {
+                Some(def) => defs.push(def),
+                None => {
+                    if let Some(ident) = self.field_opt(node, Field::Ident) {
+                        if !ident.has_error() {
+                            let name = self.identifier(ident);
+                            self.dropped.push(name);
+                        }
+                    }
+                },
             }
         }
 
-        Ok(SourceFile { defs })
+        SourceFile { defs }
     }
 
     fn parse_top_level_decl<'d>(&mut self, node: tree_sitter::Node<'d>) -> Result<TopLevelDef<'b, tree_sitter::Range>, ParseError> {
-        let body = match self.parser.node_matcher.lookup(node.kind_id()) {
+        let body = match self.parser.grammar.node_matcher.lookup(node.kind_id()) {
             Some(ConcreteNode::TopLevelDef) => {
-                let type_ = self.parse_type(self.field(node, Field::Type))?;
-                let expr = self.parse_expr(self.field(node, Field::Body))?;
+                let type_ = self.parse_type(self.field(node, Field::Type)?)?;
+                let expr = self.parse_expr(self.field(node, Field::Body)?)?;
                 TopLevelDefBody::Def {
                     kind: TopLevelDefKind::Def,
                     type_,
@@ -258,8 +425,8 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                 }
             },
             Some(ConcreteNode::TopLevelLet) => {
-                let type_ = self.parse_type(self.field(node, Field::Type))?;
-                let expr = self.parse_expr(self.field(node, Field::Body))?;
+                let type_ = self.parse_type(self.field(node, Field::Type)?)?;
+                let expr = self.parse_expr(self.field(node, Field::Body)?)?;
                 TopLevelDefBody::Def {
                     kind: TopLevelDefKind::Let,
                     type_,
@@ -267,13 +434,13 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                 }
             },
             Some(ConcreteNode::TopLevelClock) => TopLevelDefBody::Clock {
-                freq: self.parse_freq(self.field(node, Field::Frequency))?,
+                freq: self.parse_freq(self.field(node, Field::Frequency)?)?,
             },
             _ => return Err(ParseError::UhhhhhhWhat(node.range(), "expected a top-level let here".to_string()))
         };
 
         Ok(TopLevelDef {
-            name: self.identifier(self.field(node, Field::Ident)),
+            name: self.binder(self.field(node, Field::Ident)?)?,
             range: node.range(),
             body,
         })
@@ -284,17 +451,32 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
         freq_text.parse().map_err(|_| ParseError::BadLiteral(node.range()))
     }
 
-    fn parse_expr<'d>(&mut self, node: tree_sitter::Node<'d>) -> Result<Expr<'b, tree_sitter::Range>, ParseError> {
+    fn parse_expr<'d>(&mut self, mut node: tree_sitter::Node<'d>) -> Result<Expr<'b, tree_sitter::Range>, ParseError> {
+        // go through the wrapper nodes without recursing, as deeply
+        // nested expressions otherwise take up a lot of stack
+        loop {
+            node = match self.parser.grammar.node_matcher.lookup(node.kind_id()) {
+                Some(ConcreteNode::Expression) =>
+                    node.child(0).ok_or(ParseError::ExpectedExpression(node.range()))?,
+                Some(ConcreteNode::WrapExpression) =>
+                    // the literals are included in the children indices
+                    self.field(node, Field::Expr)?,
+                _ =>
+                    break,
+            };
+        }
+
         // TODO: use a TreeCursor instead
-        match self.parser.node_matcher.lookup(node.kind_id()) {
-            Some(ConcreteNode::Expression) =>
-                self.parse_expr(node.child(0).unwrap()),
-            Some(ConcreteNode::WrapExpression) =>
-                // the literals are included in the children indices
-                self.parse_expr(self.field(node, Field::Expr)),
+        match self.parser.grammar.node_matcher.lookup(node.kind_id()) {
             Some(ConcreteNode::Identifier) => {
-                let interned_ident = self.parser.interner.get_or_intern(self.node_text(node));
-                Ok(Expr::Var(node.range(), interned_ident))
+                let text = self.node_text(node);
+                if let Some(hole_name) = text.strip_prefix('_') {
+                    let name = (!hole_name.is_empty()).then(|| self.parser.interner.get_or_intern(hole_name));
+                    Ok(Expr::Hole(node.range(), name))
+                } else {
+                    let interned_ident = self.parser.interner.get_or_intern(text);
+                    Ok(Expr::Var(node.range(), interned_ident))
+                }
             },
             Some(ConcreteNode::Literal) => {
                 let text = self.node_text(node);
@@ -311,68 +493,68 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                 Ok(Expr::Val(node.range(), Value::Sample(sample)))
             },
             Some(ConcreteNode::ApplicationExpression) => {
-                let e1 = self.parse_expr(self.field(node, Field::Func))?;
-                let e2 = self.parse_expr(self.field(node, Field::Arg))?;
+                let e1 = self.parse_expr(self.field(node, Field::Func)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::Arg)?)?;
                 Ok(Expr::App(node.range(), self.parser.arena.alloc(e1), self.parser.arena.alloc(e2)))
             },
             Some(ConcreteNode::LambdaExpression) => {
-                let x = self.parser.interner.get_or_intern(self.node_text(self.field(node, Field::Binder)));
-                let e = self.parse_expr(self.field(node, Field::Body))?;
+                let x = self.binder(self.field(node, Field::Binder)?)?;
+                let e = self.parse_expr(self.field(node, Field::Body)?)?;
                 Ok(Expr::Lam(node.range(), x, self.parser.arena.alloc(e)))
             },
             Some(ConcreteNode::LobExpression) => {
-                let clock = self.parse_clock(self.field(node, Field::Clock))?;
-                let x = self.parser.interner.get_or_intern(self.node_text(self.field(node, Field::Binder)));
-                let e = self.parse_expr(self.field(node, Field::Body))?;
+                let clock = self.parse_clock(self.field(node, Field::Clock)?)?;
+                let x = self.binder(self.field(node, Field::Binder)?)?;
+                let e = self.parse_expr(self.field(node, Field::Body)?)?;
                 Ok(Expr::Lob(node.range(), clock, x, self.parser.arena.alloc(e)))
             },
             Some(ConcreteNode::ForceExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::Adv(node.range(), self.parser.arena.alloc(e)))
             },
             Some(ConcreteNode::GenExpression) => {
-                let e1 = self.parse_expr(self.field(node, Field::Head))?;
-                let e2 = self.parse_expr(self.field(node, Field::Tail))?;
+                let e1 = self.parse_expr(self.field(node, Field::Head)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::Tail)?)?;
                 Ok(Expr::Gen(node.range(), self.parser.arena.alloc(e1), self.parser.arena.alloc(e2)))
             },
             Some(ConcreteNode::LetExpression) => {
-                let x = self.parser.interner.get_or_intern(self.node_text(self.field(node, Field::Binder)));
+                let x = self.binder(self.field(node, Field::Binder)?)?;
                 let ty = self.field_opt(node, Field::Type).map(|t| self.parse_type(t)).transpose()?;
-                let e1 = self.parse_expr(self.field(node, Field::Bound))?;
-                let e2 = self.parse_expr(self.field(node, Field::Body))?;
+                let e1 = self.parse_expr(self.field(node, Field::Bound)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::Body)?)?;
                 Ok(Expr::LetIn(node.range(), x, ty, self.parser.arena.alloc(e1), self.parser.arena.alloc(e2)))
             },
             Some(ConcreteNode::AnnotateExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Expr::Annotate(node.range(), self.parser.arena.alloc(e), ty))
             },
             Some(ConcreteNode::PairExpression) => {
-                let e1 = self.parse_expr(self.field(node, Field::Left))?;
-                let e2 = self.parse_expr(self.field(node, Field::Right))?;
+                let e1 = self.parse_expr(self.field(node, Field::Left)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::Right)?)?;
                 Ok(Expr::Pair(node.range(), self.alloc(e1), self.alloc(e2)))
             },
             Some(ConcreteNode::UnPairExpression) => {
-                let x1 = self.identifier(self.field(node, Field::BinderLeft));
-                let x2 = self.identifier(self.field(node, Field::BinderRight));
-                let e0 = self.parse_expr(self.field(node, Field::Bound))?;
-                let e = self.parse_expr(self.field(node, Field::Body))?;
+                let x1 = self.binder(self.field(node, Field::BinderLeft)?)?;
+                let x2 = self.binder(self.field(node, Field::BinderRight)?)?;
+                let e0 = self.parse_expr(self.field(node, Field::Bound)?)?;
+                let e = self.parse_expr(self.field(node, Field::Body)?)?;
                 Ok(Expr::UnPair(node.range(), x1, x2, self.alloc(e0), self.alloc(e)))
             },
             Some(ConcreteNode::InLExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::InL(node.range(), self.alloc(e)))
             },
             Some(ConcreteNode::InRExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::InR(node.range(), self.alloc(e)))
             },
             Some(ConcreteNode::CaseExpression) => {
-                let e0 = self.parse_expr(self.field(node, Field::Scrutinee))?;
-                let x1 = self.identifier(self.field(node, Field::BinderLeft));
-                let e1 = self.parse_expr(self.field(node, Field::BodyLeft))?;
-                let x2 = self.identifier(self.field(node, Field::BinderRight));
-                let e2 = self.parse_expr(self.field(node, Field::BodyRight))?;
+                let e0 = self.parse_expr(self.field(node, Field::Scrutinee)?)?;
+                let x1 = self.binder(self.field(node, Field::BinderLeft)?)?;
+                let e1 = self.parse_expr(self.field(node, Field::BodyLeft)?)?;
+                let x2 = self.binder(self.field(node, Field::BinderRight)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::BodyRight)?)?;
                 Ok(Expr::Case(node.range(), self.alloc(e0), x1, self.alloc(e1), x2, self.alloc(e2)))
             },
             Some(ConcreteNode::ArrayExpression) => {
@@ -380,7 +562,7 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                     Some(array_inner) => {
                         let mut es = Vec::with_capacity((array_inner.child_count() + 1) / 2);
                         let mut cur = array_inner.walk();
-                        let expr_field = self.parser.field_matcher.lookup(Field::Expr);
+                        let expr_field = self.parser.grammar.field_matcher.lookup(Field::Expr);
                         for elem_node in array_inner.children_by_field_id(expr_field, &mut cur) {
                             let elem = self.parse_expr(elem_node)?;
                             es.push(self.alloc(elem));
@@ -392,36 +574,36 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                 }))
             },
             Some(ConcreteNode::UnGenExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::UnGen(node.range(), self.parser.arena.alloc(e)))
             },
             Some(ConcreteNode::UnitExpression) =>
                 Ok(Expr::Val(node.range(), Value::Unit)),
             Some(ConcreteNode::DelayExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::Delay(node.range(), self.alloc(e)))
             },
             Some(ConcreteNode::BoxExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::Box(node.range(), self.alloc(e)))
             },
             Some(ConcreteNode::UnboxExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::Unbox(node.range(), self.alloc(e)))
             },
             Some(ConcreteNode::ClockAppExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
-                let clock = self.parse_clock(self.field(node, Field::Clock))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
+                let clock = self.parse_clock(self.field(node, Field::Clock)?)?;
                 Ok(Expr::ClockApp(node.range(), self.alloc(e), clock))
             },
             Some(ConcreteNode::TypeAppExpression) => {
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Expr::TypeApp(node.range(), self.alloc(e), ty))
             },
             Some(ConcreteNode::BinopExpression) => {
-                let e1 = self.parse_expr(self.field(node, Field::Left))?;
-                let op = match self.node_text(self.field(node, Field::Op)) {
+                let e1 = self.parse_expr(self.field(node, Field::Left)?)?;
+                let op = match self.node_text(self.field(node, Field::Op)?) {
                     "*" => Binop::FMul,
                     "/" => Binop::FDiv,
                     "+" => Binop::FAdd,
@@ -447,21 +629,21 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                     ".<=." => Binop::ILe,
                     ".==." => Binop::IEq,
                     ".!=." => Binop::INe,
-                    op => panic!("unknown binop \"{}\"", op)
+                    op => return Err(ParseError::UnknownOperator(node.range(), op.to_string())),
                 };
-                let e2 = self.parse_expr(self.field(node, Field::Right))?;
+                let e2 = self.parse_expr(self.field(node, Field::Right)?)?;
                 Ok(Expr::Binop(node.range(), op, self.alloc(e1), self.alloc(e2)))
             },
             Some(ConcreteNode::ExIntro) => {
-                let c = self.parse_clock(self.field(node, Field::Clock))?;
-                let e = self.parse_expr(self.field(node, Field::Expr))?;
+                let c = self.parse_clock(self.field(node, Field::Clock)?)?;
+                let e = self.parse_expr(self.field(node, Field::Expr)?)?;
                 Ok(Expr::ExIntro(node.range(), c, self.alloc(e)))
             },
             Some(ConcreteNode::ExElim) => {
-                let c = self.identifier(self.field(node, Field::BinderClock));
-                let x = self.identifier(self.field(node, Field::BinderExpr));
-                let e1 = self.parse_expr(self.field(node, Field::Bound))?;
-                let e2 = self.parse_expr(self.field(node, Field::Body))?;
+                let c = self.identifier(self.field(node, Field::BinderClock)?);
+                let x = self.binder(self.field(node, Field::BinderExpr)?)?;
+                let e1 = self.parse_expr(self.field(node, Field::Bound)?)?;
+                let e2 = self.parse_expr(self.field(node, Field::Body)?)?;
                 Ok(Expr::ExElim(node.range(), c, x, self.alloc(e1), self.alloc(e2)))
             },
             Some(_) =>
@@ -473,56 +655,56 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
 
     // TODO: add range information to Type?
     fn parse_type<'d>(&mut self, node: tree_sitter::Node<'d>) -> Result<Type, ParseError> {
-        match self.parser.node_matcher.lookup(node.kind_id()) {
+        match self.parser.grammar.node_matcher.lookup(node.kind_id()) {
             Some(ConcreteNode::Type) =>
-                self.parse_type(node.child(0).unwrap()),
+                self.parse_type(node.child(0).ok_or(ParseError::ExpectedType(node.range()))?),
             Some(ConcreteNode::WrapType) =>
-                self.parse_type(self.field(node, Field::Type)),
+                self.parse_type(self.field(node, Field::Type)?),
             Some(ConcreteNode::BaseType) =>
                 Ok(match self.node_text(node) {
                     "sample" => Type::Sample,
                     "index" => Type::Index,
                     "unit" => Type::Unit,
-                    base => panic!("unknown base type {base}"),
+                    base => return Err(ParseError::UnknownBaseType(node.range(), base.to_string())),
                 }),
             Some(ConcreteNode::FunctionType) => {
-                let ty1 = self.parse_type(self.field(node, Field::Arg))?;
-                let ty2 = self.parse_type(self.field(node, Field::Ret))?;
+                let ty1 = self.parse_type(self.field(node, Field::Arg)?)?;
+                let ty2 = self.parse_type(self.field(node, Field::Ret)?)?;
                 Ok(Type::Function(Box::new(ty1), Box::new(ty2)))
             },
             Some(ConcreteNode::StreamType) => {
-                let clock = self.parse_clock(self.field(node, Field::Clock))?;
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let clock = self.parse_clock(self.field(node, Field::Clock)?)?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Type::Stream(clock, Box::new(ty)))
             },
             Some(ConcreteNode::ProductType) => {
-                let ty1 = self.parse_type(self.field(node, Field::Left))?;
-                let ty2 = self.parse_type(self.field(node, Field::Right))?;
+                let ty1 = self.parse_type(self.field(node, Field::Left)?)?;
+                let ty2 = self.parse_type(self.field(node, Field::Right)?)?;
                 Ok(Type::Product(Box::new(ty1), Box::new(ty2)))
             },
             Some(ConcreteNode::SumType) => {
-                let ty1 = self.parse_type(self.field(node, Field::Left))?;
-                let ty2 = self.parse_type(self.field(node, Field::Right))?;
+                let ty1 = self.parse_type(self.field(node, Field::Left)?)?;
+                let ty2 = self.parse_type(self.field(node, Field::Right)?)?;
                 Ok(Type::Sum(Box::new(ty1), Box::new(ty2)))
             },
             Some(ConcreteNode::ArrayType) => {
-                let ty = self.parse_type(self.field(node, Field::Type))?;
-                let size = self.parse_size(self.field(node, Field::Size))?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
+                let size = self.parse_size(self.field(node, Field::Size)?)?;
                 Ok(Type::Array(Box::new(ty), size))
             },
             Some(ConcreteNode::LaterType) => {
-                let clock = self.parse_clock(self.field(node, Field::Clock))?;
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let clock = self.parse_clock(self.field(node, Field::Clock)?)?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Type::Later(clock, Box::new(ty)))
             },
             Some(ConcreteNode::BoxType) => {
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Type::Box(Box::new(ty)))
             },
             Some(ConcreteNode::ForallType) => {
-                let x = self.identifier(self.field(node, Field::Binder));
-                let k = self.parse_kind(self.field(node, Field::Kind))?;
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let x = self.identifier(self.field(node, Field::Binder)?);
+                let k = self.parse_kind(self.field(node, Field::Kind)?)?;
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Type::Forall(x, k, Box::new(ty)))
             },
             Some(ConcreteNode::VarType) => {
@@ -530,8 +712,8 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
                 Ok(Type::TypeVar(x))
             },
             Some(ConcreteNode::ExType) => {
-                let c = self.identifier(self.field(node, Field::Binder));
-                let ty = self.parse_type(self.field(node, Field::Type))?;
+                let c = self.identifier(self.field(node, Field::Binder)?);
+                let ty = self.parse_type(self.field(node, Field::Type)?)?;
                 Ok(Type::Exists(c, Box::new(ty)))
             },
             Some(_) =>
@@ -545,7 +727,7 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
         match self.node_text(node) {
             "clock" => Ok(Kind::Clock),
             "type" => Ok(Kind::Type),
-            kind => panic!("unknown kind {}", kind),
+            kind => Err(ParseError::UnknownKind(node.range(), kind.to_string())),
         }
     }
 
@@ -563,7 +745,7 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
         } else {
             Ratio::from_integer(1)
         };
-        let var = self.identifier(self.field(node, Field::Ident));
+        let var = self.identifier(self.field(node, Field::Ident)?);
         Ok(Clock { coeff, var })
     }
 
@@ -572,8 +754,11 @@ impl<'a, 'b, 'c> AbstractionContext<'a, 'b, 'c> {
             let n = self.node_text(node).parse().map_err(|_| ParseError::BadCoefficient(node.range()))?;
             Ok(Ratio::from_integer(n))
         } else {
-            let n = self.node_text(node.child(0).unwrap()).parse().map_err(|_| ParseError::BadCoefficient(node.range()))?;
-            let d = self.node_text(node.child(2).unwrap()).parse().map_err(|_| ParseError::BadCoefficient(node.range()))?;
+            let (Some(n_node), Some(d_node)) = (node.child(0), node.child(2)) else {
+                return Err(ParseError::BadCoefficient(node.range()));
+            };
+            let n = self.node_text(n_node).parse().map_err(|_| ParseError::BadCoefficient(node.range()))?;
+            let d = self.node_text(d_node).parse().map_err(|_| ParseError::BadCoefficient(node.range()))?;
             if d > 0 {
                 Ok(Ratio::new(n, d))
             } else {
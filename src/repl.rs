@@ -0,0 +1,200 @@
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+use typed_arena::Arena;
+
+use crate::expr::{Expr, SourceFile, Symbol, TopLevelDefBody, TopLevelDefKind};
+use crate::session::Session;
+use crate::toplevel::{TopLevel, TopLevelError, TopLevelResult};
+#[cfg(feature = "run")]
+use crate::toplevel::compile_with_main;
+#[cfg(not(feature = "run"))]
+use crate::toplevel::interpret_with_main;
+use crate::typing::{Ctx, FileTypeErrors, Kind, TopLevelTypeError};
+
+const HELP: &str = "\
+declarations (def, let and clock) end with ;; and can go over several lines
+:t expr          show the type of expr
+:take n expr     print the first n samples of expr, a ~^(audio) sample
+:q               quit
+";
+
+// the declarations so far are kept as one file in a session, so that
+// each new one only costs checking itself
+pub struct Repl<'a> {
+    session: Session<'a>,
+    // what expressions get checked in: the lets and clocks declared
+    // so far
+    ctx: Ctx,
+}
+
+// the context that a let after all of these would be checked in
+fn declared_ctx(global_clocks: &[Symbol], file: Option<&SourceFile<'_, tree_sitter::Range>>) -> Ctx {
+    let mut ctx = Ctx::Empty;
+    for &clock in global_clocks.iter() {
+        ctx = Ctx::TypeVar(clock, Kind::Clock, Rc::new(ctx));
+    }
+    for def in file.iter().flat_map(|file| file.defs.iter()) {
+        ctx = match def.body {
+            TopLevelDefBody::Def { kind: TopLevelDefKind::Let, ref type_, .. } =>
+                Ctx::TermVar(def.name, type_.clone(), Rc::new(ctx)),
+            TopLevelDefBody::Def { .. } =>
+                ctx,
+            TopLevelDefBody::Clock { .. } =>
+                Ctx::TypeVar(def.name, Kind::Clock, Rc::new(ctx)),
+        };
+    }
+    ctx
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(arena: &'a Arena<Expr<'a, tree_sitter::Range>>) -> Repl<'a> {
+        let session = Session::new(arena, String::new());
+        let ctx = declared_ctx(&session.toplevel.global_clocks, None);
+        Repl { session, ctx }
+    }
+
+    // whether this is all of a command or declaration, rather than
+    // the start of a declaration that goes on to the next line
+    pub fn is_complete(input: &str) -> bool {
+        let input = input.trim();
+        input.is_empty() || input.starts_with(':') || input.ends_with(";;")
+    }
+
+    // runs a complete input, giving back what to print, or the error
+    pub fn eval(&mut self, input: &str) -> Result<String, String> {
+        let input = input.trim();
+        let (command, rest) = input.split_once(char::is_whitespace).unwrap_or((input, ""));
+        match command {
+            "" =>
+                Ok(String::new()),
+            ":t" | ":type" =>
+                self.type_of(rest.trim()),
+            ":take" => {
+                let (n, expr) = rest.trim().split_once(char::is_whitespace).ok_or("usage: :take n expr\n")?;
+                let n = n.parse().map_err(|_| format!("expected a number of samples, not \"{}\"\n", n))?;
+                self.take(n, expr.trim())
+            },
+            ":help" | ":h" =>
+                Ok(HELP.to_string()),
+            _ if command.starts_with(':') =>
+                Err(format!("unknown command {}, try :help\n", command)),
+            _ =>
+                self.declare(input),
+        }
+    }
+
+    fn declare(&mut self, decl: &str) -> Result<String, String> {
+        let start = self.session.text().len();
+        self.session.edit(start..start, &format!("{}\n", decl));
+        match self.session.check() {
+            Ok(file) => {
+                self.ctx = declared_ctx(&self.session.toplevel.global_clocks, Some(&file));
+                let interner = &self.session.toplevel.interner;
+                let mut out = String::new();
+                for def in file.defs.iter().filter(|def| def.range.start_byte >= start) {
+                    let name = interner.resolve(def.name).unwrap();
+                    match def.body {
+                        TopLevelDefBody::Def { ref type_, .. } =>
+                            writeln!(out, "{}: {}", name, type_.pretty(interner)).unwrap(),
+                        TopLevelDefBody::Clock { freq } =>
+                            writeln!(out, "clock {} of frequency {} Hz", name, freq).unwrap(),
+                    }
+                }
+                Ok(out)
+            },
+            Err(err) => {
+                let msg = err.pretty(&self.session.toplevel.interner, "<repl>").to_string();
+                let end = self.session.text().len();
+                self.session.edit(start..end, "");
+                self.recheck();
+                Err(msg)
+            },
+        }
+    }
+
+    // puts the globals back to what the declarations so far define,
+    // after checking something else in the same toplevel
+    fn recheck(&mut self) {
+        self.session.toplevel.reset_globals();
+        if !self.session.text().is_empty() {
+            // these have all been checked before, so this only
+            // re-adds them
+            self.session.check().ok();
+        }
+    }
+
+    fn type_of(&mut self, expr_text: &str) -> Result<String, String> {
+        // the grammar only has files, so parse the expression as the
+        // body of a definition and then synthesize its type
+        let code = format!("def it: unit =\n{}\n;;", expr_text);
+        let toplevel = &mut self.session.toplevel;
+        let file = match toplevel.make_parser().parse_file(&code) {
+            Ok(file) => file,
+            Err(err) =>
+                return Err(TopLevelError::ParseError(code, Box::new(err), None).pretty(&toplevel.interner, "<repl>").to_string()),
+        };
+        let def = &file.defs[0];
+        let expr = def.body.get_expr().expect("a def always has a body");
+        let res = toplevel.make_typechecker().synthesize(&self.ctx, expr);
+        match res {
+            Ok((_, ty)) =>
+                Ok(format!("{}\n", ty.pretty(&toplevel.interner))),
+            Err(err) => {
+                let errs = FileTypeErrors { errs: vec![TopLevelTypeError::TypeError(def.name, err)] };
+                Err(TopLevelError::TypeError(code, errs).pretty(&toplevel.interner, "<repl>").to_string())
+            },
+        }
+    }
+
+    fn take(&mut self, n: usize, expr_text: &str) -> Result<String, String> {
+        let code = format!("{}let repl_main: ~^(audio) sample =\n{}\n;;\n", self.session.text(), expr_text);
+        // the declarations get checked all over again as part of this
+        self.session.toplevel.reset_globals();
+        let res = run_repl_main(&mut self.session.toplevel, code, n);
+        self.recheck();
+        let samples = res.map_err(|err| err.pretty(&self.session.toplevel.interner, "<repl>").to_string())?;
+        let samples: Vec<String> = samples.iter().take(n).map(|x| x.to_string()).collect();
+        Ok(format!("{}\n", samples.join(" ")))
+    }
+}
+
+// compiled and run with wasmtime if we have it, and otherwise
+// interpreted
+#[cfg(feature = "run")]
+fn run_repl_main<'a>(toplevel: &mut TopLevel<'a>, code: String, num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    let wasm_bytes = compile_with_main(toplevel, code, "repl_main")?;
+    Ok(crate::toplevel::run(&wasm_bytes, num_samples))
+}
+
+#[cfg(not(feature = "run"))]
+fn run_repl_main<'a>(toplevel: &mut TopLevel<'a>, code: String, num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    interpret_with_main(toplevel, code, "repl_main", num_samples)
+}
+
+pub fn run() -> io::Result<()> {
+    let arena = Arena::new();
+    let mut repl = Repl::new(&arena);
+    let stdin = io::stdin();
+    let mut input = String::new();
+    loop {
+        print!("{}", if input.is_empty() { "> " } else { "| " });
+        io::stdout().flush()?;
+        if stdin.lock().read_line(&mut input)? == 0 {
+            break;
+        }
+        if !Repl::is_complete(&input) {
+            continue;
+        }
+        if matches!(input.trim(), ":q" | ":quit") {
+            break;
+        }
+        match repl.eval(&input) {
+            Ok(out) => print!("{}", out),
+            Err(err) => eprint!("{}", err),
+        }
+        input.clear();
+    }
+    Ok(())
+}
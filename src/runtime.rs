@@ -60,13 +60,8 @@ fn constexpr_to_constexpr(ce: &wasmparser::ConstExpr<'_>) -> wasm::ConstExpr {
     // why isn't this a method??
     let mut reader = ce.get_binary_reader();
     let bytes = reader.read_bytes(reader.bytes_remaining()).unwrap();
-    println!("constexpr bytes in: {bytes:?}");
-    let constexpr_out = wasm::ConstExpr::raw(bytes.iter().copied().take(bytes.len()-1));
-    let mut out_bytes = Vec::new();
-    use wasm_encoder::Encode;
-    constexpr_out.encode(&mut out_bytes);
-    println!("constexpr bytes out: {bytes:?}");
-    constexpr_out
+    // minus the end opcode, which ConstExpr adds back
+    wasm::ConstExpr::raw(bytes.iter().copied().take(bytes.len()-1))
 }
 
 fn exportkind_to_exportkind(k: wasmparser::ExternalKind) -> wasm::ExportKind {
@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+use string_interner::DefaultStringInterner;
+use typed_arena::Arena;
+
+use crate::expr::{Expr, SourceFile, Symbol, TopLevelDefBody, TopLevelDefKind};
+use crate::toplevel::{check_parsed_with, TopLevel, TopLevelResult};
+use crate::typing::TypeError;
+
+// the error's boxed, since it's much bigger than the expression
+type CheckedBody<'a> = Result<&'a Expr<'a, tree_sitter::Range>, Box<TypeError<'a, tree_sitter::Range>>>;
+
+// a file that keeps getting edited, e.g. in an editor. each check
+// only reparses what changed and only re-typechecks the definitions
+// whose text, or the signatures of what they use, changed.
+//
+// everything still gets allocated in the toplevel's arena, so a
+// session's memory use grows with the number of edits
+pub struct Session<'a> {
+    pub toplevel: TopLevel<'a>,
+    text: String,
+    // from the last parse, with every edit since applied to it
+    tree: Option<tree_sitter::Tree>,
+    // how checking each definition's body went, keyed by what that
+    // depended on (see def_keys)
+    checked: HashMap<String, CheckedDef<'a>>,
+    rechecked: Vec<Symbol>,
+}
+
+struct CheckedDef<'a> {
+    // where the definition started at the time
+    start: (usize, tree_sitter::Point),
+    body: CheckedBody<'a>,
+}
+
+fn point_at(text: &str, offset: usize) -> tree_sitter::Point {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    tree_sitter::Point { row: before.matches('\n').count(), column: offset - line_start }
+}
+
+impl<'a> Session<'a> {
+    pub fn new(arena: &'a Arena<Expr<'a, tree_sitter::Range>>, text: String) -> Session<'a> {
+        Session {
+            toplevel: TopLevel::new(arena),
+            text,
+            tree: None,
+            checked: HashMap::new(),
+            rechecked: Vec::new(),
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    // replaces the given byte range of the text
+    pub fn edit(&mut self, range: Range<usize>, new_text: &str) {
+        let start_position = point_at(&self.text, range.start);
+        let old_end_position = point_at(&self.text, range.end);
+        self.text.replace_range(range.clone(), new_text);
+        let new_end_byte = range.start + new_text.len();
+        let new_end_position = point_at(&self.text, new_end_byte);
+        if let Some(ref mut tree) = self.tree {
+            tree.edit(&tree_sitter::InputEdit {
+                start_byte: range.start,
+                old_end_byte: range.end,
+                new_end_byte,
+                start_position,
+                old_end_position,
+                new_end_position,
+            });
+        }
+    }
+
+    // the definitions the last check actually typechecked, rather
+    // than reusing what it found before
+    pub fn rechecked(&self) -> &[Symbol] {
+        &self.rechecked
+    }
+
+    pub fn check(&mut self) -> TopLevelResult<'a, SourceFile<'a, tree_sitter::Range>> {
+        let (tree, parsed) = self.toplevel.make_parser().reparse_file(&self.text, self.tree.as_ref());
+        self.tree = Some(tree);
+        // the definitions from last time are still in there
+        self.toplevel.reset_globals();
+
+        let keys = match parsed {
+            Ok(ref file) => def_keys(&self.toplevel.interner, &self.text, file),
+            Err(ref err) => def_keys(&self.toplevel.interner, &self.text, &err.partial),
+        };
+        let arena = self.toplevel.arena;
+        let mut previously = std::mem::take(&mut self.checked);
+        let checked = &mut self.checked;
+        let rechecked = &mut self.rechecked;
+        rechecked.clear();
+
+        check_parsed_with(&mut self.toplevel, self.text.clone(), parsed, &mut |tc, file| {
+            tc.check_file_with(file, &mut |tc, i, ctx, expr, ty| {
+                let def = &file.defs[i];
+                let start = (def.range.start_byte, def.range.start_point);
+                let body = match previously.remove(&keys[i]) {
+                    Some(prev) => moved(arena, prev, start),
+                    None => {
+                        rechecked.push(def.name);
+                        tc.check(ctx, expr, ty).map_err(Box::new)
+                    },
+                };
+                // the error gets consumed, so keep a copy
+                let res = match body {
+                    Ok(e) => Ok(e),
+                    Err(ref err) => Err(Box::new(err.map_ext(arena, &|r| *r))),
+                };
+                checked.insert(keys[i].clone(), CheckedDef { start, body });
+                res
+            })
+        })
+    }
+}
+
+// what was found about a definition that has since moved to start
+// somewhere else
+fn moved<'a>(arena: &'a Arena<Expr<'a, tree_sitter::Range>>, prev: CheckedDef<'a>, start: (usize, tree_sitter::Point)) -> CheckedBody<'a> {
+    if prev.start == start {
+        return prev.body;
+    }
+    let ((old_byte, old_point), (new_byte, new_point)) = (prev.start, start);
+    let shift_point = |p: tree_sitter::Point| tree_sitter::Point {
+        row: p.row - old_point.row + new_point.row,
+        column: if p.row == old_point.row { p.column - old_point.column + new_point.column } else { p.column },
+    };
+    let shift = |r: &tree_sitter::Range| tree_sitter::Range {
+        start_byte: r.start_byte - old_byte + new_byte,
+        end_byte: r.end_byte - old_byte + new_byte,
+        start_point: shift_point(r.start_point),
+        end_point: shift_point(r.end_point),
+    };
+    match prev.body {
+        Ok(e) => Ok(&*arena.alloc(e.map_ext(arena, &shift))),
+        Err(err) => Err(Box::new(err.map_ext(arena, &shift))),
+    }
+}
+
+// what checking each definition's body depends on: its own text, the
+// signatures of the earlier definitions it mentions, and for lets,
+// whose context has all the earlier lets and clocks in it, their
+// signatures too. anything with the same key checks the same way
+fn def_keys(interner: &DefaultStringInterner, text: &str, file: &SourceFile<'_, tree_sitter::Range>) -> Vec<String> {
+    let mut keys = Vec::new();
+    // name, signature, and whether it's in lets' contexts
+    let mut earlier: Vec<(&str, String, bool)> = Vec::new();
+    for def in file.defs.iter() {
+        let def_text = &text[def.range.start_byte .. def.range.end_byte];
+        let mentioned: HashSet<&str> = def_text
+            .split(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+            .collect();
+        let name = interner.resolve(def.name).unwrap();
+        let (signature, is_let) = match def.body {
+            TopLevelDefBody::Def { kind, ref type_, .. } =>
+                (format!("{} {}: {}", kind, name, type_.pretty(interner)), kind == TopLevelDefKind::Let),
+            TopLevelDefBody::Clock { freq } =>
+                (format!("clock {} of {}", name, freq), false),
+        };
+
+        let mut key = def_text.to_string();
+        for (earlier_name, earlier_signature, earlier_in_let_ctx) in earlier.iter() {
+            if mentioned.contains(earlier_name) || (is_let && *earlier_in_let_ctx) {
+                key.push('\n');
+                key.push_str(earlier_signature);
+            }
+        }
+        keys.push(key);
+        earlier.push((name, signature, is_let || matches!(def.body, TopLevelDefBody::Clock { .. })));
+    }
+    keys
+}
@@ -2,7 +2,7 @@ use std::fmt::{self, Write};
 use std::error::Error;
 use std::collections::HashMap;
 
-use crate::expr::{Expr, Symbol, TopLevelDefBody};
+use crate::expr::{Expr, SourceFile, Symbol, TopLevelDefBody};
 use crate::ir1_egglog::{FromEgglogConverter, ToEgglogConverter};
 use egglog::{EGraph, Term, TermDag};
 use string_interner::{StringInterner, DefaultStringInterner};
@@ -10,9 +10,10 @@ use string_interner::{StringInterner, DefaultStringInterner};
 use typed_arena::Arena;
 
 use crate::builtin::{make_builtin_clocks, make_builtins, BuiltinsMap};
+use crate::diagnostic::Diagnostic;
 use crate::parse::{self, Parser};
 use crate::typing::{self, Globals, Typechecker};
-use crate::{ir1, ir2, wasm, util};
+use crate::{format, interp, ir1, ir2, wasm, util};
 
 use crate::typing::Type;
 
@@ -22,21 +23,77 @@ pub struct TopLevel<'a> {
     pub builtins: BuiltinsMap,
     pub globals: Globals,
     pub global_clocks: Vec<Symbol>,
+    pub grammar: parse::Grammar,
+    // see Typechecker::type_log
+    pub type_log: Option<Vec<(tree_sitter::Range, Type)>>,
+    // whether compiling runs the egglog rewrites over ir1, rather
+    // than going straight from the translation
+    pub optimize: bool,
+    // how many rounds of rewriting the egglog pass does, or none to
+    // keep going until they stop changing anything
+    pub opt_iterations: Option<usize>,
+    // whether compiling reports, on stderr, how big the egraph got and
+    // what the egglog pass saved
+    pub egraph_stats: bool,
+    // the stages whose output compiling keeps in emitted
+    pub emit: Vec<Stage>,
+    // the output of each of those stages from the last compile, in
+    // the order they ran
+    pub emitted: Vec<(Stage, String)>,
+}
+
+// the steps of compilation that can be shown with compile --emit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Stage {
+    /// The program as parsed
+    Ast,
+    /// The program after type checking, with the clock and type applications filled in
+    Elab,
+    /// Each definition translated to IR1
+    Ir1,
+    /// Each definition as extracted after optimizing
+    Ir1Opt,
+    /// The egglog program the optimizer runs
+    Egglog,
+    /// The globals translated to IR2, as they go to the backend
+    Ir2,
+    /// The final wasm module, as text
+    Wat,
+}
+
+impl Stage {
+    // what to end the file it's written to with
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            Stage::Ast => "ast",
+            Stage::Elab => "elab",
+            Stage::Ir1 => "ir1",
+            Stage::Ir1Opt => "ir1-opt",
+            Stage::Egglog => "egg",
+            Stage::Ir2 => "ir2",
+            Stage::Wat => "wat",
+        }
+    }
 }
 
 impl<'a> TopLevel<'a> {
     pub fn new(arena: &'a Arena<Expr<'a, tree_sitter::Range>>) -> TopLevel<'a> {
         let mut interner = StringInterner::new();
         let builtins = make_builtins(&mut interner);
-        let globals = builtins.iter().map(|(&name, builtin)| (name, builtin.type_.clone())).collect();
+        let globals = builtins_globals(&builtins);
         let builtin_clocks = make_builtin_clocks(&mut interner);
 
-        TopLevel { arena, interner, builtins, globals, global_clocks: builtin_clocks }
+        TopLevel { arena, interner, builtins, globals, global_clocks: builtin_clocks, grammar: parse::Grammar::new(), type_log: None, optimize: true, opt_iterations: Some(8), egraph_stats: false, emit: Vec::new(), emitted: Vec::new() }
+    }
+
+    // forgets the definitions from any files that have been checked,
+    // so that a new version of one can be checked from scratch
+    pub fn reset_globals(&mut self) {
+        self.globals = builtins_globals(&self.builtins);
     }
 
     pub fn make_parser<'b>(&'b mut self) -> Parser<'b, 'a> {
-        // TODO: this does a bunch of processing we don't need to redo
-        Parser::new(&mut self.interner, &mut self.arena)
+        Parser::new(&mut self.grammar, &mut self.interner, self.arena)
     }
 
     pub fn make_typechecker<'b>(&'b mut self) -> Typechecker<'b, 'a, tree_sitter::Range> {
@@ -45,23 +102,146 @@ impl<'a> TopLevel<'a> {
             globals: &mut self.globals,
             global_clocks: &self.global_clocks,
             interner: &mut self.interner,
+            type_log: self.type_log.as_mut(),
         }
     }
+
+    fn emits(&self, stage: Stage) -> bool {
+        self.emit.contains(&stage)
+    }
+}
+
+fn builtins_globals(builtins: &BuiltinsMap) -> Globals {
+    builtins.iter().map(|(&name, builtin)| (name, builtin.type_.clone())).collect()
 }
 
 #[derive(Debug)]
-#[allow(dead_code)] // necessary bc we are just printing the debug repr right now
 pub enum TopLevelError<'a> {
     IoError(std::io::Error),
-    ParseError(String, parse::FullParseError),
+    // along with the type errors in whatever did parse
+    ParseError(String, Box<parse::FullParseError<'a>>, Option<typing::FileTypeErrors<'a, tree_sitter::Range>>),
     TypeError(String, typing::FileTypeErrors<'a, tree_sitter::Range>),
     InterpError(String),
+    // there's no definition of the name to compile or run
+    NoMain(String),
     CannotSample(Type),
+    // a bug in the compiler, in the definition named, if it was in one
+    BackendError(Option<String>, wasm::BackendErrorKind),
+    // a bug in the compiler too, if egglog can't run what we give it
+    OptimizerError(egglog::Error),
+}
+
+impl<'a> TopLevelError<'a> {
+    pub fn diagnostics(&self, interner: &DefaultStringInterner) -> Vec<Diagnostic> {
+        match *self {
+            TopLevelError::IoError(ref err) =>
+                vec![Diagnostic::new(err.to_string())],
+            TopLevelError::ParseError(ref code, ref err, ref type_errs) =>
+                err.errors.iter()
+                    .map(|err| err.diagnostic(code))
+                    .chain(type_errs.iter().flat_map(|errs| errs.errs.iter()).map(|err| err.diagnostic(interner, code)))
+                    .collect(),
+            TopLevelError::TypeError(ref code, ref errs) =>
+                errs.errs.iter().map(|err| err.diagnostic(interner, code)).collect(),
+            TopLevelError::InterpError(ref msg) =>
+                vec![Diagnostic::new(msg.clone())],
+            TopLevelError::NoMain(ref name) =>
+                vec![Diagnostic::new(format!("there's no {} to play", name))
+                     .with_note(format!("it should be a stream of samples, like \"let {}: ~^(audio) sample = ...\"", name))],
+            TopLevelError::CannotSample(ref ty) =>
+                vec![Diagnostic::new(format!("cannot sample a value of type \"{}\"", ty.pretty(interner)))
+                     .with_type(ty.pretty(interner).to_string())
+                     .with_note("only streams of samples can be played")],
+            TopLevelError::BackendError(ref global, ref kind) => {
+                let message = match *global {
+                    Some(ref global) => format!("couldn't compile {}: {}", global, kind),
+                    None => format!("couldn't compile: {}", kind),
+                };
+                vec![Diagnostic::new(message).with_note("this is a bug in the compiler")]
+            },
+            TopLevelError::OptimizerError(ref err) =>
+                vec![Diagnostic::new(format!("couldn't optimize: {}", err)).with_note("this is a bug in the compiler")],
+        }
+    }
+
+    fn code(&self) -> &str {
+        match *self {
+            TopLevelError::ParseError(ref code, _, _) |
+            TopLevelError::TypeError(ref code, _) =>
+                code,
+            _ =>
+                "",
+        }
+    }
+
+    pub fn pretty<'b>(&'b self, interner: &'b DefaultStringInterner, file_name: &'b str) -> PrettyTopLevelError<'b, 'a> {
+        PrettyTopLevelError { interner, file_name, error: self }
+    }
+
+    // one JSON object per line, per diagnostic
+    pub fn json<'b>(&'b self, interner: &'b DefaultStringInterner, file_name: &'b str) -> JsonTopLevelError<'b, 'a> {
+        JsonTopLevelError { interner, file_name, error: self }
+    }
+}
+
+pub struct JsonTopLevelError<'b, 'a> {
+    interner: &'b DefaultStringInterner,
+    file_name: &'b str,
+    error: &'b TopLevelError<'a>,
 }
 
+impl<'b, 'a> fmt::Display for JsonTopLevelError<'b, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diag in self.error.diagnostics(self.interner) {
+            writeln!(f, "{}", diag.to_json(self.file_name))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct PrettyTopLevelError<'b, 'a> {
+    interner: &'b DefaultStringInterner,
+    file_name: &'b str,
+    error: &'b TopLevelError<'a>,
+}
+
+impl<'b, 'a> fmt::Display for PrettyTopLevelError<'b, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, diag) in self.error.diagnostics(self.interner).iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", diag.render(self.file_name, self.error.code()))?;
+        }
+        Ok(())
+    }
+}
+
+// without the interner this can only summarize; use pretty() to show
+// the errors themselves
 impl<'a> fmt::Display for TopLevelError<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match *self {
+            TopLevelError::IoError(ref err) =>
+                write!(f, "{}", err),
+            TopLevelError::ParseError(_, ref err, ref type_errs) =>
+                write!(f, "{} parse error(s) and {} type error(s)",
+                       err.errors.len(), type_errs.as_ref().map_or(0, |errs| errs.errs.len())),
+            TopLevelError::TypeError(_, ref errs) =>
+                write!(f, "{} type error(s)", errs.errs.len()),
+            TopLevelError::InterpError(ref msg) =>
+                write!(f, "{}", msg),
+            TopLevelError::NoMain(ref name) =>
+                write!(f, "there's no {} to play", name),
+            TopLevelError::CannotSample(_) =>
+                write!(f, "cannot sample this type"),
+            TopLevelError::BackendError(Some(ref global), ref kind) =>
+                write!(f, "couldn't compile {}: {}", global, kind),
+            TopLevelError::BackendError(None, ref kind) =>
+                write!(f, "couldn't compile: {}", kind),
+            TopLevelError::OptimizerError(ref err) =>
+                write!(f, "couldn't optimize: {}", err),
+        }
     }
 }
 
@@ -70,10 +250,14 @@ impl<'a> Error for TopLevelError<'a> {
         match *self {
             TopLevelError::IoError(ref err) => Some(err),
             // TODO: make these errors implement Error
-            TopLevelError::ParseError(_, _) => None,
+            TopLevelError::ParseError(_, _, _) => None,
             TopLevelError::TypeError(_, _) => None,
             TopLevelError::InterpError(_) => None,
+            TopLevelError::NoMain(_) => None,
             TopLevelError::CannotSample(_) => None,
+            TopLevelError::BackendError(_, wasm::BackendErrorKind::Invalid(ref err)) => Some(err),
+            TopLevelError::BackendError(_, _) => None,
+            TopLevelError::OptimizerError(ref err) => Some(err),
         }
     }
 }
@@ -93,15 +277,6 @@ enum Name {
     Clock(Symbol),
 }
 
-impl Name {
-    fn symbol(&self) -> Symbol {
-        match *self {
-            Name::Term(x) => x,
-            Name::Clock(x) => x,
-        }
-    }
-}
-
 fn print_with_intermediate_helper(
     termdag: &TermDag,
     term: Term,
@@ -132,12 +307,117 @@ fn print_with_intermediate_helper(
     }
 }
 
-pub fn egglog<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'a, ()> {
-    let parsed_file = match toplevel.make_parser().parse_file(&code) {
+// typechecks as much of the file as parses, so that a parse error
+// doesn't hide type errors elsewhere
+pub fn parse_and_check<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'a, SourceFile<'a, tree_sitter::Range>> {
+    let parsed = toplevel.make_parser().parse_file(&code);
+    check_parsed(toplevel, code, parsed)
+}
+
+// the rest of parse_and_check, for callers that want to hang on to
+// what parsed
+pub fn check_parsed<'a>(toplevel: &mut TopLevel<'a>, code: String, parsed: Result<SourceFile<'a, tree_sitter::Range>, parse::FullParseError<'a>>) -> TopLevelResult<'a, SourceFile<'a, tree_sitter::Range>> {
+    check_parsed_with(toplevel, code, parsed, &mut |tc, file| tc.check_file(file))
+}
+
+type FileCheckResult<'a> = Result<SourceFile<'a, tree_sitter::Range>, typing::FileTypeErrors<'a, tree_sitter::Range>>;
+
+// like check_parsed, but with the file getting checked by
+// check_file, e.g. to use Typechecker::check_file_with
+pub fn check_parsed_with<'a>(
+    toplevel: &mut TopLevel<'a>,
+    code: String,
+    parsed: Result<SourceFile<'a, tree_sitter::Range>, parse::FullParseError<'a>>,
+    check_file: &mut dyn FnMut(&mut Typechecker<'_, 'a, tree_sitter::Range>, &SourceFile<'a, tree_sitter::Range>) -> FileCheckResult<'a>,
+) -> TopLevelResult<'a, SourceFile<'a, tree_sitter::Range>> {
+    let parsed_file = match parsed {
         Ok(parsed_file) => parsed_file,
-        Err(e) => { return Err(TopLevelError::ParseError(code, e)); }
+        Err(e) => {
+            let type_errs = check_file(&mut toplevel.make_typechecker(), &e.partial).err()
+                .map(|errs| errs.not_caused_by_missing(&e.dropped))
+                .filter(|errs| !errs.errs.is_empty());
+            return Err(TopLevelError::ParseError(code, Box::new(e), type_errs));
+        }
     };
-    let elabbed_file = toplevel.make_typechecker().check_file(&parsed_file).map_err(|e| TopLevelError::TypeError(code, e))?;
+    check_file(&mut toplevel.make_typechecker(), &parsed_file).map_err(|e| TopLevelError::TypeError(code, e))
+}
+
+// the code as the formatter would write it
+pub fn format<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'a, String> {
+    let (tree, parsed) = toplevel.make_parser().reparse_file(&code, None);
+    match parsed {
+        Ok(file) => Ok(format::format_file(&toplevel.interner, &code, &tree, &file)),
+        Err(e) => Err(TopLevelError::ParseError(code, Box::new(e), None)),
+    }
+}
+
+// how many steps of the analysis each round gets. it normally finishes
+// in far fewer, see Free in ir1.egg, but this is what stops it if not
+const ANALYSIS_STEPS: usize = 100;
+// how many rows the egraph's tables can grow to before the rewriting
+// stops early
+const EGRAPH_LIMIT: usize = 2_000_000;
+// the cost of Shift and Subst in ir1.egg
+const UNFINISHED_COST: usize = 100_000_000;
+
+// each round of rewriting first finds out all it can about what's in
+// the egraph, then rewrites with that. the analysis gets run once more
+// at the end, so that substitutions from the last round get finished.
+// everything in the egraph is equal to what went in after any round,
+// so stopping early, which even without a limit on the rounds happens
+// once the egraph has got too big, just means extracting something
+// less optimized
+pub fn run_rewrites(egraph: &mut EGraph, iterations: Option<usize>) -> Result<(), egglog::Error> {
+    let mut round = 0;
+    loop {
+        run_to_fixpoint(egraph, "analysis", ANALYSIS_STEPS)?;
+        if iterations.is_some_and(|n| round >= n) || egraph.num_tuples() > EGRAPH_LIMIT {
+            return Ok(());
+        }
+        if !run_to_fixpoint(egraph, "optimize", 1)? {
+            return Ok(());
+        }
+        round += 1;
+    }
+}
+
+// runs the ruleset until it stops changing anything, or for the given
+// number of steps, saying whether it changed anything
+fn run_to_fixpoint(egraph: &mut EGraph, ruleset: &str, steps: usize) -> Result<bool, egglog::Error> {
+    let mut changed = false;
+    for _ in 0..steps {
+        egraph.parse_and_run_program(&format!("(run-schedule {})", ruleset))?;
+        if !egraph.get_run_report().as_ref().is_some_and(|report| report.updated) {
+            break;
+        }
+        changed = true;
+    }
+    Ok(changed)
+}
+
+struct EGraphStats {
+    // none if egglog didn't say
+    eclasses: Option<usize>,
+    // what extracting each definition would cost
+    costs: Vec<(String, usize)>,
+}
+
+fn egraph_stats(egraph: &mut EGraph, def_names: &[&str]) -> Result<EGraphStats, egglog::Error> {
+    // egglog doesn't give the number of e-classes any other way. the
+    // size of just the one function comes out as just the number
+    let mut msgs = egraph.parse_and_run_program("(run-schedule (saturate stats)) (print-size EClass)")?;
+    let eclasses = msgs.pop().and_then(|msg| msg.trim().parse().ok());
+    let mut termdag = TermDag::default();
+    let costs = def_names.iter().map(|&name| {
+        let (sort, val) = egraph.eval_expr(&egglog::ast::Expr::Call((), "program".into(), vec![egglog::ast::Expr::Lit((), egglog::ast::Literal::String(name.into()))]))?;
+        let (cost, _) = egraph.extract(val, &mut termdag, &sort);
+        Ok((name.to_string(), cost))
+    }).collect::<Result<_, egglog::Error>>()?;
+    Ok(EGraphStats { eclasses, costs })
+}
+
+pub fn egglog<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'a, ()> {
+    let elabbed_file = parse_and_check(toplevel, code)?;
 
     let defs = elabbed_file.defs;
 
@@ -221,12 +501,11 @@ pub fn egglog<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'
 {prologue}
 
 {program}
-
-(run 1)
     ");
 
     let mut egraph: EGraph = Default::default();
-    egraph.parse_and_run_program(&full_program).unwrap();
+    egraph.parse_and_run_program(&full_program).map_err(TopLevelError::OptimizerError)?;
+    run_rewrites(&mut egraph, toplevel.opt_iterations).map_err(TopLevelError::OptimizerError)?;
 
     let mut from_converter = FromEgglogConverter {
         termdag: Default::default(),
@@ -252,11 +531,23 @@ pub fn egglog<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'
 }
 
 pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<'a, Vec<u8>> {
-    let parsed_file = match toplevel.make_parser().parse_file(&code) {
-        Ok(parsed_file) => parsed_file,
-        Err(e) => { return Err(TopLevelError::ParseError(code, e)); }
-    };
-    let elabbed_file = toplevel.make_typechecker().check_file(&parsed_file).map_err(|e| TopLevelError::TypeError(code, e))?;
+    compile_with_main(toplevel, code, "main")
+}
+
+// compiles the code with the given definition as the stream to play
+pub fn compile_with_main<'a>(toplevel: &mut TopLevel<'a>, code: String, main_name: &str) -> TopLevelResult<'a, Vec<u8>> {
+    toplevel.emitted.clear();
+
+    let parsed = toplevel.make_parser().parse_file(&code);
+    if let (true, Ok(parsed_file)) = (toplevel.emits(Stage::Ast), &parsed) {
+        let ast = parsed_file.pretty(&toplevel.interner).to_string();
+        toplevel.emitted.push((Stage::Ast, ast));
+    }
+    let elabbed_file = check_parsed(toplevel, code, parsed)?;
+    if toplevel.emits(Stage::Elab) {
+        let elab = elabbed_file.pretty(&toplevel.interner).to_string();
+        toplevel.emitted.push((Stage::Elab, elab));
+    }
 
     let defs = elabbed_file.defs;
 
@@ -305,13 +596,37 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
     let mut program = String::new();
 
     let mut unopt_expr_ir1 = HashMap::new();
+    let mut unopt_out = String::new();
+    // the values of the definitions that could be worked out at
+    // compile time
+    let mut consts = HashMap::new();
+    // the definitions that are stream maps, which applications of each
+    // other get fused
+    let mut stream_maps = HashMap::new();
 
     for def in defs.iter() {
         match def.body {
-            TopLevelDefBody::Def { expr, .. } => {
+            TopLevelDefBody::Def { expr, ref type_, .. } => {
                 // println!("compiling {}", toplevel.interner.resolve(def.name).unwrap());
-                let expr_ir1 = &*expr_under_arena.alloc(translator.translate(ir1::Ctx::Empty.into(), expr));
+                let mut expr_ir1 = &*expr_under_arena.alloc(translator.translate(ir1::Ctx::Empty.into(), expr));
                 unopt_expr_ir1.insert(def.name, expr_ir1);
+                if toplevel.emits(Stage::Ir1) {
+                    writeln!(unopt_out, "{} = {}\n", toplevel.interner.resolve(def.name).unwrap(), expr_ir1.pretty()).unwrap();
+                }
+                if toplevel.optimize {
+                    expr_ir1 = translator.inline_consts(&consts, expr_ir1);
+                    expr_ir1 = translator.fuse_stream_maps(&stream_maps, expr_ir1);
+                    if let Some(shape) = translator.stream_map(expr_ir1) {
+                        stream_maps.insert(translator.globals[&def.name], shape);
+                    }
+                    // anything of these types is stable and isn't a
+                    // stream, so it's the same every time it's used
+                    if matches!(type_, Type::Unit | Type::Sample | Type::Index) {
+                        if let Some(ir1::ConstValue::Packed(v)) = ir1::const_eval(&consts, expr_ir1) {
+                            consts.insert(translator.globals[&def.name], v);
+                        }
+                    }
+                }
                 let term = egglog_converter.expr_to_term(expr_ir1);
                 let res = print_with_intermediate_helper(&egglog_converter.termdag, term, &mut term_cache, &mut program);
                 writeln!(&mut program, "(set (program \"{}\") {})", toplevel.interner.resolve(def.name).unwrap(), res).unwrap();
@@ -332,12 +647,33 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
 {prologue}
 
 {program}
-
-(run 1)
     ");
+    if toplevel.emits(Stage::Ir1) {
+        toplevel.emitted.push((Stage::Ir1, unopt_out));
+    }
+    if toplevel.emits(Stage::Egglog) {
+        toplevel.emitted.push((Stage::Egglog, full_program.clone()));
+    }
 
     let mut egraph: EGraph = Default::default();
-    egraph.parse_and_run_program(&full_program).unwrap();
+    if toplevel.optimize {
+        egraph.parse_and_run_program(&full_program).map_err(TopLevelError::OptimizerError)?;
+        let def_names: Vec<&str> = defs.iter()
+            .filter(|def| matches!(def.body, TopLevelDefBody::Def { .. }))
+            .map(|def| toplevel.interner.resolve(def.name).unwrap())
+            .collect();
+        let before = toplevel.egraph_stats.then(|| egraph_stats(&mut egraph, &def_names))
+            .transpose().map_err(TopLevelError::OptimizerError)?;
+        run_rewrites(&mut egraph, toplevel.opt_iterations).map_err(TopLevelError::OptimizerError)?;
+        if let Some(before) = before {
+            let after = egraph_stats(&mut egraph, &def_names).map_err(TopLevelError::OptimizerError)?;
+            let show = |n: Option<usize>| n.map_or("?".to_string(), |n| n.to_string());
+            eprintln!("e-classes: {} before, {} after", show(before.eclasses), show(after.eclasses));
+            for ((name, cost_before), (_, cost_after)) in before.costs.iter().zip(after.costs.iter()) {
+                eprintln!("{}: cost {} before, {} after", name, cost_before, cost_after);
+            }
+        }
+    }
 
     let mut from_converter = FromEgglogConverter {
         termdag: Default::default(),
@@ -347,6 +683,7 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
     };
     
     let mut main = None;
+    let mut opt_out = String::new();
     let opt_defs_ir1: HashMap<Name, &ir1::Expr<'_>> = toplevel.global_clocks.iter().enumerate().map(|(i, &name)| {
         let clock_expr = &*expr_under_arena.alloc(
             ir1::Expr::Op(ir1::Op::GetClock(i as u32), &[])
@@ -356,14 +693,26 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
         match def.body {
             TopLevelDefBody::Def { expr: _orig_expr, .. } => {
                 let name = toplevel.interner.resolve(def.name).unwrap();
-                let kind = "program";
-                let (sort, val) = egraph.eval_expr(&egglog::ast::Expr::Call((), kind.into(), vec![egglog::ast::Expr::Lit((), egglog::ast::Literal::String(name.into()))])).unwrap();
-                let (_, extracted) = egraph.extract(val, &mut from_converter.termdag, &sort);
-                let opt_expr = from_converter.term_to_expr(extracted);
                 let unopt_expr = unopt_expr_ir1[&def.name];
-                println!("unoptimized {name}: {unopt_expr:?}");
-                println!("optimized {name}: {opt_expr:?}");
-                let (annotated, _) = translator.annotate_used_vars(opt_expr);
+                let opt_expr = if toplevel.optimize {
+                    let kind = "program";
+                    let (sort, val) = egraph.eval_expr(&egglog::ast::Expr::Call((), kind.into(), vec![egglog::ast::Expr::Lit((), egglog::ast::Literal::String(name.into()))])).unwrap();
+                    let (cost, extracted) = egraph.extract(val, &mut from_converter.termdag, &sort);
+                    // only a Shift or Subst costs this much, which means
+                    // the rewriting stopped before the analysis got it done
+                    if cost < UNFINISHED_COST {
+                        from_converter.term_to_expr(extracted)
+                    } else {
+                        unopt_expr
+                    }
+                } else {
+                    unopt_expr
+                };
+                if toplevel.emits(Stage::Ir1Opt) {
+                    writeln!(opt_out, "{} = {}\n", name, opt_expr.pretty()).unwrap();
+                }
+                let unpacked = translator.unpack_lets(opt_expr);
+                let (annotated, _) = translator.annotate_used_vars(unpacked);
                 let shifted = translator.shift(annotated, 0, 0, &imbl::HashMap::new());
                 (Name::Term(def.name), shifted)
             },
@@ -375,6 +724,9 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
             },
         }
     })).collect();
+    if toplevel.emits(Stage::Ir1Opt) {
+        toplevel.emitted.push((Stage::Ir1Opt, opt_out));
+    }
 
     let expr2_under_arena = Arena::new();
     let expr2_ptr_arena = Arena::new();
@@ -387,50 +739,114 @@ pub fn compile<'a>(toplevel: &mut TopLevel<'a>, code: String) -> TopLevelResult<
             Name::Term(sym) => translator.globals[&sym].0 as usize,
             Name::Clock(sym) => translator.global_clocks[&sym].0 as usize,
         };
-        if name == Name::Term(toplevel.interner.get_or_intern_static("main")) {
+        if name == Name::Term(toplevel.interner.get_or_intern(main_name)) {
             main = Some(def_idx);
         }
         translator2.globals[def_idx] = ir2::GlobalDef::ClosedExpr { body: expr_ir2 };
-        println!("{}: {:?}", toplevel.interner.resolve(name.symbol()).unwrap(), expr_ir2);
     }
 
+    let main = main.ok_or_else(|| TopLevelError::NoMain(main_name.to_string()))?;
+
+    translator2.direct_calls();
+    // main is all that gets exported, so whatever it can't reach can go
+    let new_indices = translator2.prune_unreachable(&[main]);
+    let main = new_indices[main].unwrap() as usize;
     let mut global_defs = translator2.globals;
-    for (i, func) in global_defs.iter().enumerate() {
-        println!("global {i}: {func:?}");
-    }
 
-    // generate partial application functions
+    // generate the partial application functions that might be used.
+    // call sites find them by the arity and number of arguments, so
+    // each has its own place in the table, whether or not the others
+    // are there
     let partial_app_def_offset = global_defs.len() as u32;
-    let max_arity = global_defs.iter().map(|def| def.arity().unwrap_or(0)).max().unwrap();
-    // let partial_app_defs = Vec::with_capacity(max_arity * (max_arity + 1) / 2 - max_arity);
-    for arity in 2..=max_arity {
-        for n_args in 1..arity {
-            let n_remaining_args = arity - n_args;
-            let args_to_call = (n_remaining_args..arity).map(|i| {
-                expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(i+1)))
-            }).chain((0..n_remaining_args).map(|i| {
-                // expr2_arena.alloc(ir2::Expr::Op(ir1::Op::UnboxedConst(ir1::Value::Index(42+i as usize)), &[]))
-                expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(n_remaining_args - 1 - i)))
-            }));
-            global_defs.push(ir2::GlobalDef::Func {
-                rec: false,
-                arity: n_remaining_args,
-                env_size: n_args + 1,
-                body: expr2_arena.alloc(ir2::Expr::CallIndirect(
-                    expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(n_remaining_args))),
-                    expr2_arena.alloc_slice_r(args_to_call)
-                )),
-            });
+    let mut partial_app_slots = Vec::new();
+    for (arity, n_args) in ir2::partial_apps_needed(&global_defs) {
+        let n_remaining_args = arity - n_args;
+        let args_to_call = (n_remaining_args..arity).map(|i| {
+            expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(i+1)))
+        }).chain((0..n_remaining_args).map(|i| {
+            // expr2_arena.alloc(ir2::Expr::Op(ir1::Op::UnboxedConst(ir1::Value::Index(42+i as usize)), &[]))
+            expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(n_remaining_args - 1 - i)))
+        }));
+        global_defs.push(ir2::GlobalDef::Func {
+            rec: false,
+            arity: n_remaining_args,
+            env_size: n_args + 1,
+            body: expr2_arena.alloc(ir2::Expr::CallIndirect(
+                expr2_arena.alloc(ir2::Expr::Var(ir1::DebruijnIndex(n_remaining_args))),
+                expr2_arena.alloc_slice_r(args_to_call)
+            )),
+        });
+        partial_app_slots.push((arity - 1) * (arity - 2) / 2 + n_args - 1);
+    }
+
+    // which definition each global came from, if any
+    let mut names = vec![None; global_defs.len()];
+    for (&sym, g) in translator.globals.iter().chain(translator.global_clocks.iter()) {
+        if let Some(new_g) = new_indices[g.0 as usize] {
+            names[new_g as usize] = toplevel.interner.resolve(sym);
+        }
+    }
+
+    if toplevel.emits(Stage::Ir2) {
+        let mut ir2_out = String::new();
+        for (i, def) in global_defs.iter().enumerate() {
+            match names[i] {
+                Some(name) => writeln!(ir2_out, "{} ({}) = {}\n", ir1::Global(i as u32), name, def).unwrap(),
+                None => writeln!(ir2_out, "{} = {}\n", ir1::Global(i as u32), def).unwrap(),
+            }
         }
+        toplevel.emitted.push((Stage::Ir2, ir2_out));
     }
 
-    let wasm_bytes = wasm::translate(&global_defs, partial_app_def_offset, main.unwrap());
+    let wasm_bytes = wasm::translate(&global_defs, partial_app_def_offset, &partial_app_slots, main).map_err(|err| {
+        let global = err.global.map(|g| match names[g as usize] {
+            Some(name) => name.to_string(),
+            None => ir1::Global(g).to_string(),
+        });
+        TopLevelError::BackendError(global, err.kind)
+    })?;
+
+    if toplevel.emits(Stage::Wat) {
+        let wat = wasmprinter::print_bytes(&wasm_bytes)
+            .unwrap_or_else(|err| format!(";; couldn't print the module: {}\n", err));
+        toplevel.emitted.push((Stage::Wat, wat));
+    }
 
     Ok(wasm_bytes)
 }
 
+// runs main with the reference interpreter rather than compiling it
+pub fn interpret<'a>(toplevel: &mut TopLevel<'a>, code: String, num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    interpret_with_main(toplevel, code, "main", num_samples)
+}
+
+pub fn interpret_with_main<'a>(toplevel: &mut TopLevel<'a>, code: String, main_name: &str, num_samples: usize) -> TopLevelResult<'a, Vec<f32>> {
+    let file = parse_and_check(toplevel, code)?;
+    let main = toplevel.interner.get_or_intern(main_name);
+    let main_type = file.defs.iter()
+        .find(|def| def.name == main)
+        .and_then(|def| def.body.get_type())
+        .ok_or_else(|| TopLevelError::NoMain(main_name.to_string()))?;
+    if !matches!(*main_type, Type::Stream(_, ref ty) if **ty == Type::Sample) {
+        return Err(TopLevelError::CannotSample(main_type.clone()));
+    }
+
+    let mut interpreter = interp::Interpreter::new(&toplevel.builtins, &toplevel.global_clocks);
+    interpreter.load(&file);
+    let stream = interpreter.global(main).unwrap().clone();
+    Ok(interpreter.sample(stream, num_samples))
+}
+
 #[cfg(feature="run")]
 pub fn run(wasm_bytes: &[u8], num_samples: usize) -> Vec<f32> {
+    run_measuring_memory(wasm_bytes, num_samples).0
+}
+
+// runs it, and gives how many bytes of memory it's taken up by the
+// end. the runtime never frees anything, so this goes up with every
+// allocation
+#[cfg(feature="run")]
+pub fn run_measuring_memory(wasm_bytes: &[u8], num_samples: usize) -> (Vec<f32>, usize) {
     use byteorder::{ReadBytesExt, LittleEndian};
 
     let engine = wasmtime::Engine::new(
@@ -464,5 +880,5 @@ pub fn run(wasm_bytes: &[u8], num_samples: usize) -> Vec<f32> {
         }
     }
 
-    all_samples
+    (all_samples, memory.data_size(&store))
 }
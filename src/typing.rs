@@ -10,6 +10,7 @@ use indenter::{Format, indented, Indented};
 use typed_arena::Arena;
 
 use crate::expr::{Binop, Expr, SourceFile, Symbol, TopLevelDef, TopLevelDefBody, TopLevelDefKind, Value};
+use crate::diagnostic::{Diagnostic, Label};
 use crate::util::parenthesize;
 
 // eventually this will get more complicated...
@@ -182,7 +183,7 @@ impl Type {
         PrettyType { interner, ty: self }
     }
 
-    fn is_stable(&self) -> bool {
+    pub(crate) fn is_stable(&self) -> bool {
         match *self {
             Type::Unit => true,
             Type::Sample => true,
@@ -303,6 +304,31 @@ impl Type {
             },
         }
     }
+
+    // does the type or clock variable x occur free in this type?
+    fn mentions(&self, x: Symbol) -> bool {
+        match *self {
+            Type::Unit |
+            Type::Sample |
+            Type::Index =>
+                false,
+            Type::Stream(c, ref ty) |
+            Type::Later(c, ref ty) =>
+                c.var == x || ty.mentions(x),
+            Type::Function(ref ty1, ref ty2) |
+            Type::Product(ref ty1, ref ty2) |
+            Type::Sum(ref ty1, ref ty2) =>
+                ty1.mentions(x) || ty2.mentions(x),
+            Type::Array(ref ty, _) |
+            Type::Box(ref ty) =>
+                ty.mentions(x),
+            Type::Forall(y, _, ref ty) |
+            Type::Exists(y, ref ty) =>
+                x != y && ty.mentions(x),
+            Type::TypeVar(y) =>
+                x == y,
+        }
+    }
 }
 
 pub struct PrettyType<'a> {
@@ -394,18 +420,33 @@ impl<'a> fmt::Display for PrettyType<'a> {
 // they can always be used
 pub type Globals = HashMap<Symbol, Type>;
 
+// why a variable in lexical scope has been hidden from use
+#[derive(Clone, Copy, Debug)]
+pub enum Hiding {
+    // its type isn't stable, and we went under a box or a lob
+    Unstable,
+    // it was bound after a tick that got stripped away to force
+    // something on this clock
+    Stripped(Clock),
+}
+
 // TODO: should probably find a more efficient representation of this,
 // but it'll work for now
 //
-// TODO: want to keep variables around that are in lexical scope but
-// removed due to tick-stripping, so that type errors can make more
-// sense. but can't immediately figure out a nice way of doing that,
-// so using this representation in the meantime.
+// variables that are in lexical scope but removed by tick-stripping
+// or box-strengthening stay around as Hidden, so that type errors can
+// say why they can't be used.
 #[derive(Clone, Debug)]
 pub enum Ctx {
     Empty,
     Tick(Clock, Rc<Ctx>),
     TermVar(Symbol, Type, Rc<Ctx>),
+    // a let-bound variable generalised over the listed type/clock
+    // variables. uses instantiate them, by default to themselves
+    GenVar(Symbol, Vec<(Symbol, Kind)>, Type, Rc<Ctx>),
+    // a variable that's in lexical scope but can't be used here,
+    // kept around for error messages
+    Hidden(Symbol, Type, Hiding, Rc<Ctx>),
     // variables behind this have "free" timing -- used as part of type synthesis
     Pretend(Rc<Ctx>),
     TypeVar(Symbol, Kind, Rc<Ctx>),
@@ -420,7 +461,8 @@ impl Ctx {
                     cs.push(c);
                     (cs, ty)
                 }),
-            Ctx::TermVar(y, ref ty, ref next) =>
+            Ctx::TermVar(y, ref ty, ref next) |
+            Ctx::GenVar(y, _, ref ty, ref next) =>
                 if x == y {
                     Some((Vec::new(), ty))
                 } else {
@@ -428,7 +470,8 @@ impl Ctx {
                 },
             Ctx::Pretend(ref next) =>
                 next.lookup_term_var(x).map(|(_, ty)| (Vec::new(), ty)),
-            Ctx::TypeVar(_, _, ref next) =>
+            Ctx::TypeVar(_, _, ref next) |
+            Ctx::Hidden(_, _, _, ref next) =>
                 next.lookup_term_var(x),
         }
     }
@@ -438,7 +481,9 @@ impl Ctx {
             Ctx::Empty => None,
             Ctx::Tick(_, ref next) =>
                 next.lookup_type_var(x),
-            Ctx::TermVar(_, _, ref next) =>
+            Ctx::TermVar(_, _, ref next) |
+            Ctx::GenVar(_, _, _, ref next) |
+            Ctx::Hidden(_, _, _, ref next) =>
                 next.lookup_type_var(x),
             Ctx::Pretend(ref next) =>
                 next.lookup_type_var(x),
@@ -451,6 +496,95 @@ impl Ctx {
         }
     }
 
+    // the variables that x was generalised over, if it is a
+    // let-generalised variable
+    fn lookup_generalized(&self, x: Symbol) -> &[(Symbol, Kind)] {
+        match *self {
+            Ctx::Empty => &[],
+            Ctx::TermVar(y, _, ref next) =>
+                if x == y {
+                    &[]
+                } else {
+                    next.lookup_generalized(x)
+                },
+            Ctx::GenVar(y, ref binders, _, ref next) =>
+                if x == y {
+                    binders
+                } else {
+                    next.lookup_generalized(x)
+                },
+            Ctx::Tick(_, ref next) |
+            Ctx::Hidden(_, _, _, ref next) |
+            Ctx::Pretend(ref next) |
+            Ctx::TypeVar(_, _, ref next) =>
+                next.lookup_generalized(x),
+        }
+    }
+
+    // can a let binding be generalised over the type/clock variable
+    // x? not if some term variable's type mentions it, and not if
+    // we're under a tick on it -- the bound expression would then
+    // only be well-timed at that particular clock
+    fn pins_type_var(&self, x: Symbol) -> bool {
+        match *self {
+            Ctx::Empty => false,
+            Ctx::Tick(c, ref next) =>
+                c.var == x || next.pins_type_var(x),
+            Ctx::TermVar(_, ref ty, ref next) =>
+                ty.mentions(x) || next.pins_type_var(x),
+            Ctx::GenVar(_, ref binders, ref ty, ref next) =>
+                (ty.mentions(x) && !binders.iter().any(|&(y, _)| x == y)) || next.pins_type_var(x),
+            // can't be used, so can't constrain anything
+            Ctx::Hidden(_, _, _, ref next) |
+            Ctx::Pretend(ref next) =>
+                next.pins_type_var(x),
+            Ctx::TypeVar(y, _, ref next) =>
+                x != y && next.pins_type_var(x),
+        }
+    }
+
+    // all the type/clock variables in scope, outermost first
+    fn type_vars(&self) -> Vec<(Symbol, Kind)> {
+        let mut vars = Vec::new();
+        let mut ctx = self;
+        loop {
+            match *ctx {
+                Ctx::Empty => break,
+                Ctx::TypeVar(x, k, ref next) => {
+                    vars.push((x, k));
+                    ctx = next;
+                },
+                Ctx::Tick(_, ref next) |
+                Ctx::TermVar(_, _, ref next) |
+                Ctx::GenVar(_, _, _, ref next) |
+                Ctx::Hidden(_, _, _, ref next) |
+                Ctx::Pretend(ref next) =>
+                    ctx = next,
+            }
+        }
+        vars.reverse();
+        vars
+    }
+
+    // only meaningful if lookup_term_var didn't find x
+    fn lookup_hidden(&self, x: Symbol) -> Option<(&Type, Hiding)> {
+        match *self {
+            Ctx::Empty => None,
+            Ctx::Hidden(y, ref ty, why, ref next) =>
+                if x == y {
+                    Some((ty, why))
+                } else {
+                    next.lookup_hidden(x)
+                },
+            Ctx::Tick(_, ref next) |
+            Ctx::TermVar(_, _, ref next) |
+            Ctx::GenVar(_, _, _, ref next) |
+            Ctx::Pretend(ref next) |
+            Ctx::TypeVar(_, _, ref next) =>
+                next.lookup_hidden(x),
+        }
+    }
+
     fn with_var(self, x: Symbol, ty: Type) -> Ctx {
         Ctx::TermVar(x, ty, Rc::new(self))
     }
@@ -468,8 +602,16 @@ impl Ctx {
                 if ty.is_stable() {
                     Ctx::TermVar(x, ty.clone(), Rc::new(next.box_strengthen()))
                 } else {
-                    next.box_strengthen()
+                    Ctx::Hidden(x, ty.clone(), Hiding::Unstable, Rc::new(next.box_strengthen()))
+                },
+            Ctx::GenVar(x, ref binders, ref ty, ref next) =>
+                if ty.is_stable() {
+                    Ctx::GenVar(x, binders.clone(), ty.clone(), Rc::new(next.box_strengthen()))
+                } else {
+                    Ctx::Hidden(x, ty.clone(), Hiding::Unstable, Rc::new(next.box_strengthen()))
                 },
+            Ctx::Hidden(x, ref ty, why, ref next) =>
+                Ctx::Hidden(x, ty.clone(), why, Rc::new(next.box_strengthen())),
             Ctx::Pretend(ref next) =>
                 Ctx::Pretend(Rc::new(next.box_strengthen())),
             Ctx::TypeVar(x, k, ref next) =>
@@ -477,35 +619,67 @@ impl Ctx {
         }
     }
 
-    fn strip_tick(&self, to_strip: Clock) -> Option<Ctx> {
+    // strips a tick on to_strip off the end of the context, giving
+    // the context that an expression of type |>^(to_strip) A must be
+    // checked in to be forced here. a tick on c k is a wait of 1/c
+    // periods of k, and stripping removes waits innermost first:
+    //
+    // - a tick on the same clock variable that's exactly as long is
+    //   removed and we're done;
+    // - a longer one is shortened by the amount stripped (this is
+    //   uncompose) and we're done;
+    // - a shorter one is removed entirely, and we carry on stripping
+    //   whatever's left further out;
+    // - a tick on some other clock variable says nothing about how
+    //   long has passed on this one, so we count it as no time at all
+    //   and drop it;
+    // - anything bound along the way is hidden, since it only came
+    //   into existence after the time we're going back to.
+    //
+    // dropping ticks only ever forgets that time has passed, so this
+    // can't let anything be used too early. hitting the binder of the
+    // clock variable (or the end of the context) means there wasn't
+    // enough tick, and the error says how much is missing.
+    //
+    // under a Pretend, timing isn't being checked anyway (the whole
+    // expression gets checked again against the real context), so
+    // stripping there always succeeds.
+    fn strip_tick(&self, to_strip: Clock) -> Result<Ctx, Clock> {
         match *self {
-            Ctx::Empty => None,
+            Ctx::Empty => Err(to_strip),
             Ctx::Tick(tick_amount, ref next) =>
-                // TODO: is this sound????????
                 match to_strip.partial_cmp(&tick_amount) {
                     Some(Ordering::Less) => {
                         let remaining_to_strip = to_strip.uncompose(&tick_amount).unwrap();
                         next.strip_tick(remaining_to_strip)
                     },
                     Some(Ordering::Equal) =>
-                        Some((**next).clone()),
+                        Ok((**next).clone()),
                     Some(Ordering::Greater) => {
                         let remaining_on_ctx = tick_amount.uncompose(&to_strip).unwrap();
-                        Some(Ctx::Tick(remaining_on_ctx, next.clone()))
+                        Ok(Ctx::Tick(remaining_on_ctx, next.clone()))
                     },
                     None =>
-                        None
+                        next.strip_tick(to_strip),
                 },
-            Ctx::TermVar(_, _, ref next) =>
-                next.strip_tick(to_strip),
-            Ctx::Pretend(ref _next) =>
-                // uhhhhhhhhhh
-                panic!("don't know what to do when trying to do tick-stripping in a pretend context!"),
-            Ctx::TypeVar(x, _, ref next) =>
+            Ctx::TermVar(x, ref ty, ref next) |
+            Ctx::GenVar(x, _, ref ty, ref next) =>
+                next.strip_tick(to_strip).map(|stripped|
+                    Ctx::Hidden(x, ty.clone(), Hiding::Stripped(to_strip), Rc::new(stripped))
+                ),
+            Ctx::Hidden(x, ref ty, why, ref next) =>
+                next.strip_tick(to_strip).map(|stripped|
+                    Ctx::Hidden(x, ty.clone(), why, Rc::new(stripped))
+                ),
+            Ctx::Pretend(_) =>
+                Ok(self.clone()),
+            Ctx::TypeVar(x, k, ref next) =>
                 if to_strip.var == x {
-                    None
+                    Err(to_strip)
                 } else {
-                    next.strip_tick(to_strip)
+                    next.strip_tick(to_strip).map(|stripped|
+                        Ctx::TypeVar(x, k, Rc::new(stripped))
+                    )
                 },
         }
     }
@@ -513,6 +687,10 @@ impl Ctx {
     fn pretty<'a>(&'a self, interner: &'a DefaultStringInterner) -> PrettyCtx<'a> {
         PrettyCtx { interner, ctx: self }
     }
+
+    fn pretty_listing<'a>(&'a self, interner: &'a DefaultStringInterner) -> PrettyCtxListing<'a> {
+        PrettyCtxListing { interner, ctx: self }
+    }
 }
 
 pub struct PrettyCtx<'a> {
@@ -535,9 +713,20 @@ impl<'a> fmt::Display for PrettyCtx<'a> {
                 write!(f, "{}, {}: {}",
                        self.for_ctx(next), self.interner.resolve(x).unwrap(),
                        PrettyType { interner: self.interner, ty }),
+            Ctx::GenVar(x, ref binders, ref ty, ref next) => {
+                write!(f, "{}, {}: ", self.for_ctx(next), self.interner.resolve(x).unwrap())?;
+                for &(y, k) in binders.iter() {
+                    write!(f, "for {} : {}. ", self.interner.resolve(y).unwrap(), k)?;
+                }
+                write!(f, "{}", PrettyType { interner: self.interner, ty })
+            },
             Ctx::Tick(ref clock, ref next) =>
                 write!(f, "{}, $^({})", self.for_ctx(next),
                        PrettyClock { interner: self.interner, clock }),
+            Ctx::Hidden(x, ref ty, _, ref next) =>
+                write!(f, "{}, {}: {} (inaccessible)",
+                       self.for_ctx(next), self.interner.resolve(x).unwrap(),
+                       PrettyType { interner: self.interner, ty }),
             Ctx::Pretend(ref next) =>
                 write!(f, "{}, XX", self.for_ctx(next)),
             Ctx::TypeVar(x, k, ref next) =>
@@ -546,6 +735,92 @@ impl<'a> fmt::Display for PrettyCtx<'a> {
     }
 }
 
+// one entry per line, outermost first, saying why any variables
+// that are in lexical scope can't be used
+pub struct PrettyCtxListing<'a> {
+    interner: &'a DefaultStringInterner,
+    ctx: &'a Ctx,
+}
+
+impl<'a> fmt::Display for PrettyCtxListing<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |x| self.interner.resolve(x).unwrap();
+
+        let mut lines = Vec::new();
+        let mut ticks_since = Vec::new();
+        let mut timing_free = false;
+        let mut seen = Vec::new();
+        let mut ctx = self.ctx;
+        loop {
+            match *ctx {
+                Ctx::Empty =>
+                    break,
+                Ctx::Tick(ref clock, ref next) => {
+                    lines.push(format!("$^({})", clock.pretty(self.interner)));
+                    ticks_since.push(*clock);
+                    ctx = next;
+                },
+                Ctx::TermVar(x, ref ty, ref next) |
+                Ctx::GenVar(x, _, ref ty, ref next) => {
+                    let mut line = format!("{}: ", name(x));
+                    if let Ctx::GenVar(_, ref binders, _, _) = *ctx {
+                        for &(y, k) in binders.iter() {
+                            write!(line, "for {} : {}. ", name(y), k)?;
+                        }
+                    }
+                    write!(line, "{}", ty.pretty(self.interner))?;
+                    if seen.contains(&x) {
+                        write!(line, " (shadowed)")?;
+                    } else if !timing_free && !ticks_since.is_empty() && !ty.is_stable() {
+                        write!(line, " (inaccessible: bound before a tick on ")?;
+                        for (i, clock) in ticks_since.iter().rev().enumerate() {
+                            if i > 0 {
+                                write!(line, " and ")?;
+                            }
+                            write!(line, "{}", clock.pretty(self.interner))?;
+                        }
+                        write!(line, ", and its type is not stable)")?;
+                    }
+                    seen.push(x);
+                    lines.push(line);
+                    ctx = next;
+                },
+                Ctx::Hidden(x, ref ty, why, ref next) => {
+                    let reason = match why {
+                        Hiding::Unstable =>
+                            "its type is not stable, and this is under a box or a lob".to_string(),
+                        Hiding::Stripped(clock) =>
+                            format!("bound after the tick on {} that was stripped to force something", clock.pretty(self.interner)),
+                    };
+                    lines.push(format!("{}: {} (inaccessible: {})", name(x), ty.pretty(self.interner), reason));
+                    seen.push(x);
+                    ctx = next;
+                },
+                Ctx::Pretend(ref next) => {
+                    lines.push("(inside a force, whose clock isn't known yet, so timing isn't checked)".to_string());
+                    timing_free = true;
+                    ctx = next;
+                },
+                Ctx::TypeVar(x, k, ref next) => {
+                    lines.push(format!("{} : {}", name(x), k));
+                    ctx = next;
+                },
+            }
+        }
+
+        if lines.is_empty() {
+            return write!(f, "  (empty)");
+        }
+        for (i, line) in lines.iter().rev().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "  {}", line)?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub enum TypeError<'a, R> {
     MismatchingTypes { expr: &'a Expr<'a, R>, synth: Type, expected: Type },
@@ -563,7 +838,8 @@ pub enum TypeError<'a, R> {
     MismatchingArraySize { range: R, expected_size: ArraySize, found_size: usize },
     UnGenningNonStream { range: R, expr: &'a Expr<'a, R>, actual_type: Type },
     VariableTimingBad { range: R, var: Symbol, timing: Vec<Clock>, var_type: Type },
-    ForcingWithNotEnoughTick { range: R, expr: &'a Expr<'a, R>, ctx: Ctx, synthesized_clock: Clock },
+    VariableInaccessible { range: R, var: Symbol, var_type: Type, why: Hiding },
+    ForcingWithNotEnoughTick { range: R, expr: &'a Expr<'a, R>, ctx: Ctx, synthesized_clock: Clock, missing: Clock },
     ForcingDoesntHoldUp { range: R, expr: &'a Expr<'a, R>, synthesized_clock: Clock, stripped_ctx: Ctx, err: Box<TypeError<'a, R>> },
     UnboxingNonBox { range: R, expr: &'a Expr<'a, R>, actual_type: Type },
     CouldntCheck { expr: &'a Expr<'a, R>, expected_type: Type, synthesis_error: Option<Box<TypeError<'a, R>>> },
@@ -573,6 +849,7 @@ pub enum TypeError<'a, R> {
     InvalidClock { range: R, purported_clock: Clock, bad_symbol: Symbol },
     ExElimNonExists { range: R, expr: &'a Expr<'a, R>, actual_type: Type },
     TypeVariableShadowing { range: R, expr: &'a Expr<'a, R>, bad_symbol: Symbol, type_: Type },
+    Hole { range: R, name: Option<Symbol>, expected: Option<Type>, ctx: Ctx },
 }
 
 impl<'a, R> TypeError<'a, R> {
@@ -620,11 +897,194 @@ impl<'a, R> TypeError<'a, R> {
         TypeError::CouldNotUnify { type1, type2 }
     }
 
+    // like Expr::map_ext, e.g. for moving an error along with the
+    // definition it's in
+    pub fn map_ext<'b, U>(&self, arena: &'b Arena<Expr<'b, U>>, f: &dyn Fn(&R) -> U) -> TypeError<'b, U> where 'a: 'b {
+        use TypeError::*;
+        let e = |expr: &'a Expr<'a, R>| &*arena.alloc(expr.map_ext(arena, f));
+        let err = |err: &TypeError<'a, R>| Box::new(err.map_ext(arena, f));
+        match *self {
+            MismatchingTypes { expr, ref synth, ref expected } =>
+                MismatchingTypes { expr: e(expr), synth: synth.clone(), expected: expected.clone() },
+            VariableNotFound { ref range, var } =>
+                VariableNotFound { range: f(range), var },
+            BadArgument { ref range, ref arg_type, fun, arg, ref arg_err } =>
+                BadArgument { range: f(range), arg_type: arg_type.clone(), fun: e(fun), arg: e(arg), arg_err: err(arg_err) },
+            NonFunctionApplication { ref range, purported_fun, ref actual_type } =>
+                NonFunctionApplication { range: f(range), purported_fun: e(purported_fun), actual_type: actual_type.clone() },
+            SynthesisUnsupported { expr } =>
+                SynthesisUnsupported { expr: e(expr) },
+            BadAnnotation { ref range, expr, ref purported_type, err: ref inner } =>
+                BadAnnotation { range: f(range), expr: e(expr), purported_type: purported_type.clone(), err: err(inner) },
+            LetSynthFailure { ref range, var, expr, err: ref inner } =>
+                LetSynthFailure { range: f(range), var, expr: e(expr), err: err(inner) },
+            LetCheckFailure { ref range, var, ref expected_type, expr, err: ref inner } =>
+                LetCheckFailure { range: f(range), var, expected_type: expected_type.clone(), expr: e(expr), err: err(inner) },
+            ForcingNonThunk { ref range, expr, ref actual_type } =>
+                ForcingNonThunk { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            UnPairingNonProduct { ref range, expr, ref actual_type } =>
+                UnPairingNonProduct { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            CasingNonSum { ref range, expr, ref actual_type } =>
+                CasingNonSum { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            CouldNotUnify { ref type1, ref type2 } =>
+                CouldNotUnify { type1: type1.clone(), type2: type2.clone() },
+            MismatchingArraySize { ref range, ref expected_size, found_size } =>
+                MismatchingArraySize { range: f(range), expected_size: expected_size.clone(), found_size },
+            UnGenningNonStream { ref range, expr, ref actual_type } =>
+                UnGenningNonStream { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            VariableTimingBad { ref range, var, ref timing, ref var_type } =>
+                VariableTimingBad { range: f(range), var, timing: timing.clone(), var_type: var_type.clone() },
+            VariableInaccessible { ref range, var, ref var_type, why } =>
+                VariableInaccessible { range: f(range), var, var_type: var_type.clone(), why },
+            ForcingWithNotEnoughTick { ref range, expr, ref ctx, synthesized_clock, missing } =>
+                ForcingWithNotEnoughTick { range: f(range), expr: e(expr), ctx: ctx.clone(), synthesized_clock, missing },
+            ForcingDoesntHoldUp { ref range, expr, synthesized_clock, ref stripped_ctx, err: ref inner } =>
+                ForcingDoesntHoldUp { range: f(range), expr: e(expr), synthesized_clock, stripped_ctx: stripped_ctx.clone(), err: err(inner) },
+            UnboxingNonBox { ref range, expr, ref actual_type } =>
+                UnboxingNonBox { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            CouldntCheck { expr, ref expected_type, ref synthesis_error } =>
+                CouldntCheck { expr: e(expr), expected_type: expected_type.clone(), synthesis_error: synthesis_error.as_deref().map(err) },
+            NonForallClockApp { ref range, purported_forall_clock, ref actual_type } =>
+                NonForallClockApp { range: f(range), purported_forall_clock: e(purported_forall_clock), actual_type: actual_type.clone() },
+            NonForallTypeApp { ref range, purported_forall_type, ref actual_type } =>
+                NonForallTypeApp { range: f(range), purported_forall_type: e(purported_forall_type), actual_type: actual_type.clone() },
+            InvalidType { ref range, ref purported_type, bad_symbol } =>
+                InvalidType { range: f(range), purported_type: purported_type.clone(), bad_symbol },
+            InvalidClock { ref range, purported_clock, bad_symbol } =>
+                InvalidClock { range: f(range), purported_clock, bad_symbol },
+            ExElimNonExists { ref range, expr, ref actual_type } =>
+                ExElimNonExists { range: f(range), expr: e(expr), actual_type: actual_type.clone() },
+            TypeVariableShadowing { ref range, expr, bad_symbol, ref type_ } =>
+                TypeVariableShadowing { range: f(range), expr: e(expr), bad_symbol, type_: type_.clone() },
+            Hole { ref range, name, ref expected, ref ctx } =>
+                Hole { range: f(range), name, expected: expected.clone(), ctx: ctx.clone() },
+        }
+    }
+
+    // holes get reported as-is, rather than buried under whatever
+    // error they happened to cause further out
+    fn is_hole(&self) -> bool {
+        matches!(*self, TypeError::Hole { .. })
+    }
+
     pub fn pretty(&'a self, interner: &'a DefaultStringInterner, program_text: &'a str) -> PrettyTypeError<'a, R> {
         PrettyTypeError { interner, program_text, error: self }
     }
 
-    fn source_type_error(&self) -> Option<&TypeError<'a, R>> {
+    // where in the source the error is, if it's anywhere in particular
+    pub fn range(&self) -> Option<&R> {
+        use TypeError::*;
+        match *self {
+            VariableNotFound { ref range, .. } |
+            BadArgument { ref range, .. } |
+            NonFunctionApplication { ref range, .. } |
+            BadAnnotation { ref range, .. } |
+            LetSynthFailure { ref range, .. } |
+            LetCheckFailure { ref range, .. } |
+            ForcingNonThunk { ref range, .. } |
+            UnPairingNonProduct { ref range, .. } |
+            CasingNonSum { ref range, .. } |
+            MismatchingArraySize { ref range, .. } |
+            UnGenningNonStream { ref range, .. } |
+            VariableTimingBad { ref range, .. } |
+            VariableInaccessible { ref range, .. } |
+            ForcingWithNotEnoughTick { ref range, .. } |
+            ForcingDoesntHoldUp { ref range, .. } |
+            UnboxingNonBox { ref range, .. } |
+            NonForallClockApp { ref range, .. } |
+            NonForallTypeApp { ref range, .. } |
+            InvalidType { ref range, .. } |
+            InvalidClock { ref range, .. } |
+            ExElimNonExists { ref range, .. } |
+            TypeVariableShadowing { ref range, .. } |
+            Hole { ref range, .. } =>
+                Some(range),
+            MismatchingTypes { expr, .. } |
+            SynthesisUnsupported { expr } |
+            CouldntCheck { expr, .. } =>
+                Some(expr.range()),
+            CouldNotUnify { .. } =>
+                None,
+        }
+    }
+
+    // stable identifiers for tools to match on; never renumber these,
+    // only add new ones
+    pub fn code(&self) -> &'static str {
+        use TypeError::*;
+        match *self {
+            MismatchingTypes { .. } => "E0201",
+            VariableNotFound { .. } => "E0202",
+            BadArgument { .. } => "E0203",
+            NonFunctionApplication { .. } => "E0204",
+            SynthesisUnsupported { .. } => "E0205",
+            BadAnnotation { .. } => "E0206",
+            LetSynthFailure { .. } => "E0207",
+            LetCheckFailure { .. } => "E0208",
+            ForcingNonThunk { .. } => "E0209",
+            UnPairingNonProduct { .. } => "E0210",
+            CasingNonSum { .. } => "E0211",
+            CouldNotUnify { .. } => "E0212",
+            MismatchingArraySize { .. } => "E0213",
+            UnGenningNonStream { .. } => "E0214",
+            VariableTimingBad { .. } => "E0215",
+            VariableInaccessible { .. } => "E0216",
+            ForcingWithNotEnoughTick { .. } => "E0217",
+            ForcingDoesntHoldUp { .. } => "E0218",
+            UnboxingNonBox { .. } => "E0219",
+            CouldntCheck { .. } => "E0220",
+            NonForallClockApp { .. } => "E0221",
+            NonForallTypeApp { .. } => "E0222",
+            InvalidType { .. } => "E0223",
+            InvalidClock { .. } => "E0224",
+            ExElimNonExists { .. } => "E0225",
+            TypeVariableShadowing { .. } => "E0226",
+            Hole { .. } => "E0227",
+        }
+    }
+
+    // the types this error is about, e.g. what was found and what was
+    // expected
+    pub fn types(&self) -> Vec<&Type> {
+        use TypeError::*;
+        match *self {
+            MismatchingTypes { ref synth, ref expected, .. } =>
+                vec![synth, expected],
+            CouldNotUnify { ref type1, ref type2 } =>
+                vec![type1, type2],
+            BadArgument { arg_type: ref ty, .. } |
+            NonFunctionApplication { actual_type: ref ty, .. } |
+            BadAnnotation { purported_type: ref ty, .. } |
+            LetCheckFailure { expected_type: ref ty, .. } |
+            ForcingNonThunk { actual_type: ref ty, .. } |
+            UnPairingNonProduct { actual_type: ref ty, .. } |
+            CasingNonSum { actual_type: ref ty, .. } |
+            UnGenningNonStream { actual_type: ref ty, .. } |
+            VariableTimingBad { var_type: ref ty, .. } |
+            VariableInaccessible { var_type: ref ty, .. } |
+            UnboxingNonBox { actual_type: ref ty, .. } |
+            CouldntCheck { expected_type: ref ty, .. } |
+            NonForallClockApp { actual_type: ref ty, .. } |
+            NonForallTypeApp { actual_type: ref ty, .. } |
+            InvalidType { purported_type: ref ty, .. } |
+            ExElimNonExists { actual_type: ref ty, .. } |
+            TypeVariableShadowing { type_: ref ty, .. } =>
+                vec![ty],
+            Hole { ref expected, .. } =>
+                expected.iter().collect(),
+            VariableNotFound { .. } |
+            SynthesisUnsupported { .. } |
+            LetSynthFailure { .. } |
+            MismatchingArraySize { .. } |
+            ForcingWithNotEnoughTick { .. } |
+            ForcingDoesntHoldUp { .. } |
+            InvalidClock { .. } =>
+                vec![],
+        }
+    }
+
+    // the error that caused this one, if any
+    pub fn source_type_error(&self) -> Option<&TypeError<'a, R>> {
         use TypeError::*;
         match *self {
             BadArgument { arg_err: ref err, .. } |
@@ -660,10 +1120,6 @@ impl<'a, R> PrettyTypeError<'a, R> {
         ty.pretty(self.interner)
     }
 
-    fn for_timing(&self, timing: &'a [Clock]) -> PrettyTiming<'a> {
-        PrettyTiming { interner: self.interner, timing }
-    }
-
     fn for_clock(&self, clock: &'a Clock) -> PrettyClock<'a> {
         PrettyClock { interner: self.interner, clock }
     }
@@ -708,12 +1164,34 @@ impl<'a> PrettyTypeError<'a, tree_sitter::Range> {
                 write!(f, "expected array of size {} but found size {}", expected_size, found_size),
             TypeError::UnGenningNonStream { expr, ref actual_type, .. } =>
                 write!(f, "expected stream to ungen, but found \"{}\" of type \"{}\"", self.for_expr(expr), self.for_type(actual_type)),
-            TypeError::VariableTimingBad { var, ref timing, ref var_type, .. } =>
-                write!(f, "found use of variable \"{}\", but it has timing {} and non-stable type \"{}\"",
-                       self.interner.resolve(var).unwrap(), self.for_timing(timing), self.for_type(var_type)),
-            TypeError::ForcingWithNotEnoughTick { expr, ref synthesized_clock, ref ctx, .. } =>
+            TypeError::VariableTimingBad { var, ref timing, ref var_type, .. } => {
+                write!(f, "variable \"{}\" is in scope but was bound before {}",
+                       self.interner.resolve(var).unwrap(),
+                       if timing.len() == 1 { "a tick on clock" } else { "ticks on clocks" })?;
+                for (i, clock) in timing.iter().enumerate() {
+                    write!(f, "{}\"{}\"", if i == 0 { " " } else { ", " }, self.for_clock(clock))?;
+                }
+                write!(f, ", and its type \"{}\" is not stable; ", self.for_type(var_type))?;
+                if let Type::Later(_, _) = *var_type {
+                    write!(f, "force it with `!` or move the definition after the tick")
+                } else {
+                    write!(f, "move the definition after the tick")
+                }
+            },
+            TypeError::VariableInaccessible { var, ref var_type, why: Hiding::Unstable, .. } =>
+                write!(f, "variable \"{}\" is in scope but can't be used under a box or lob, since its type \"{}\" is not stable, and only stable values can be used there",
+                       self.interner.resolve(var).unwrap(), self.for_type(var_type)),
+            TypeError::VariableInaccessible { var, why: Hiding::Stripped(ref clock), .. } =>
+                write!(f, "variable \"{}\" is in scope but was bound after a tick on clock \"{}\", which is stripped away inside `!`; move the definition before the tick",
+                       self.interner.resolve(var).unwrap(), self.for_clock(clock)),
+            TypeError::ForcingWithNotEnoughTick { expr, ref synthesized_clock, ref ctx, ref missing, .. } => {
                 write!(f, "trying to force expression \"{}\", but there is not enough tick for clock \"{}\" in the context \"{}\"",
-                       self.for_expr(expr), self.for_clock(synthesized_clock), self.for_ctx(ctx)),
+                       self.for_expr(expr), self.for_clock(synthesized_clock), self.for_ctx(ctx))?;
+                if missing != synthesized_clock {
+                    write!(f, " (a tick on \"{}\" is still missing)", self.for_clock(missing))?;
+                }
+                Ok(())
+            },
             TypeError::ForcingDoesntHoldUp { expr, ref stripped_ctx, .. } =>
                 write!(f, "trying to force expression \"{}\", when the context has been stripped to \"{}\", it no longer typechecks!",
                        self.for_expr(expr), self.for_ctx(stripped_ctx)),
@@ -744,6 +1222,17 @@ impl<'a> PrettyTypeError<'a, tree_sitter::Range> {
             TypeError::TypeVariableShadowing { expr, bad_symbol, ref type_, .. } =>
                 write!(f, "trying to shadow type/clock variable \"{}\" when trying to check type \"{}\" against expression \"{}\"",
                        self.interner.resolve(bad_symbol).unwrap(), self.for_type(type_), self.for_expr(expr)),
+            TypeError::Hole { name, ref expected, ref ctx, .. } => {
+                match name {
+                    Some(name) => write!(f, "found hole \"_{}\"", self.interner.resolve(name).unwrap())?,
+                    None => write!(f, "found hole \"_\"")?,
+                }
+                match *expected {
+                    Some(ref ty) => writeln!(f, " of type \"{}\", in the context", self.for_type(ty))?,
+                    None => writeln!(f, " whose type can't be inferred here, in the context")?,
+                }
+                write!(f, "{}", ctx.pretty_listing(self.interner))
+            },
         }
     }
 }
@@ -769,11 +1258,23 @@ impl<'a> fmt::Display for PrettyTypeError<'a, tree_sitter::Range> {
     }
 }
 
+// just the one error, without what caused it
+struct PrettyOneTypeError<'a, R>(PrettyTypeError<'a, R>);
+
+impl<'a> fmt::Display for PrettyOneTypeError<'a, tree_sitter::Range> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.format_one(&mut indented(f).with_str(""))
+    }
+}
+
 pub struct Typechecker<'a, 'b, R> {
     pub globals: &'a mut Globals,
     pub global_clocks: &'a [Symbol],
     pub interner: &'a mut DefaultStringInterner,
     pub arena: &'b Arena<Expr<'b, R>>,
+    // if present, gets the type of every (elaborated) subexpression
+    // that checks, innermost first, e.g. for editors to show on hover
+    pub type_log: Option<&'a mut Vec<(R, Type)>>,
 }
 
 
@@ -787,12 +1288,26 @@ pub struct Typechecker<'a, 'b, R> {
 // - [ ] proj?
 // - [X] fix
 
+// checks the body of the ith definition against its type, for
+// Typechecker::check_file_with. the error's boxed, since it's much
+// bigger than the expression
+pub type CheckBody<'t, 'a, 'b, 'd, R> =
+    dyn FnMut(&mut Typechecker<'a, 'b, R>, usize, &Ctx, &'d Expr<'d, R>, &Type) -> Result<&'b Expr<'b, R>, Box<TypeError<'d, R>>> + 't;
+
 impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
     fn alloc(&self, e: Expr<'b, R>) -> &'b Expr<'b, R> {
         self.arena.alloc(e)
     }
 
     pub fn check<'c>(&mut self, ctx: &Ctx, expr: &'c Expr<'c, R>, ty: &Type) -> Result<&'b Expr<'b, R>, TypeError<'c, R>> {
+        let res = self.check_unlogged(ctx, expr, ty);
+        if let (Ok(e_elab), Some(log)) = (&res, &mut self.type_log) {
+            log.push((e_elab.range().clone(), ty.clone()));
+        }
+        res
+    }
+
+    fn check_unlogged<'c>(&mut self, ctx: &Ctx, expr: &'c Expr<'c, R>, ty: &Type) -> Result<&'b Expr<'b, R>, TypeError<'c, R>> {
         match (ty, expr) {
             // CAREFUL! positioning this here matters, with respect to
             // the other non-type-syntax-guided rules
@@ -840,32 +1355,10 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                 let et_elab = self.check(&ctx, et, &Type::Later(clock, Box::new(ty.clone())))?;
                 Ok(self.alloc(Expr::Gen(r.clone(), eh_elab, et_elab)))
             },
-            (_, &Expr::LetIn(ref r, x, None, e1, e2)) =>
-                match self.synthesize(ctx, e1) {
-                    Ok((e1_elab, ty_x)) => {
-                        let new_ctx = ctx.clone().with_var(x, ty_x);
-                        let e2_elab = self.check(&new_ctx, e2, ty)?;
-                        Ok(self.alloc(Expr::LetIn(r.clone(), x, None, e1_elab, e2_elab)))
-                    },
-                    Err(err) =>
-                        Err(TypeError::let_failure(r.clone(), x, e1, err)),
-                },
-            (_, &Expr::LetIn(ref r, x, Some(ref e1_ty), e1, e2)) => {
-                let e1_elab = match self.check(ctx, e1, e1_ty) {
-                    Ok(e1_elab) =>
-                        e1_elab,
-                    Err(err) =>
-                        return Err(TypeError::LetCheckFailure {
-                            range: r.clone(),
-                            var: x,
-                            expected_type: e1_ty.clone(),
-                            expr: e1,
-                            err: Box::new(err)
-                        }),
-                };
-                let new_ctx = ctx.clone().with_var(x, e1_ty.clone());
+            (_, &Expr::LetIn(ref r, x, ref e1_ty, e1, e2)) => {
+                let (e1_elab, new_ctx) = self.let_bound(ctx, r, x, e1_ty.as_ref(), e1)?;
                 let e2_elab = self.check(&new_ctx, e2, ty)?;
-                Ok(self.alloc(Expr::LetIn(r.clone(), x, Some(e1_ty.clone()), e1_elab, e2_elab)))
+                Ok(self.alloc(Expr::LetIn(r.clone(), x, e1_ty.clone(), e1_elab, e2_elab)))
             },
             (&Type::Product(ref ty1, ref ty2), &Expr::Pair(ref r, e1, e2)) => {
                 let e1_elab = self.check(ctx, e1, ty1)?;
@@ -957,10 +1450,14 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                 let e_elab = self.check(ctx, e, &expected)?;
                 Ok(self.alloc(Expr::ExIntro(r.clone(), d, e_elab)))
             },
+            (_, &Expr::Hole(ref r, name)) =>
+                Err(TypeError::Hole { range: r.clone(), name, expected: Some(ty.clone()), ctx: ctx.clone() }),
             (_, _) => {
                 let (e_elab, synthesized) = match self.synthesize(ctx, expr) {
                     Ok(res) =>
                         res,
+                    Err(err) if err.is_hole() =>
+                        return Err(err),
                     Err(TypeError::SynthesisUnsupported { .. }) =>
                         return Err(TypeError::CouldntCheck {
                             expr,
@@ -985,6 +1482,20 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
     }
 
     pub fn synthesize<'c>(&mut self, ctx: &Ctx, expr: &'c Expr<'c, R>) -> Result<(&'b Expr<'b, R>, Type), TypeError<'c, R>> {
+        let res = self.synthesize_unlogged(ctx, expr);
+        if let (Ok((e_elab, ty)), Some(log)) = (&res, &mut self.type_log) {
+            log.push((e_elab.range().clone(), ty.clone()));
+        }
+        res
+    }
+
+    fn synthesize_unlogged<'c>(&mut self, ctx: &Ctx, expr: &'c Expr<'c, R>) -> Result<(&'b Expr<'b, R>, Type), TypeError<'c, R>> {
+        // uses of let-generalised variables (and explicit
+        // instantiations of them) get handled separately
+        if let Some(res) = self.synthesize_instantiation(ctx, expr)? {
+            return Ok(res);
+        }
+
         match expr {
             &Expr::Val(ref r, ref v) =>
                 match *v {
@@ -1006,6 +1517,13 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                     }
                 } else if let Some(ty) = self.globals.get(&x) {
                     Ok((self.alloc(Expr::Var(r.clone(), x)), ty.clone()))
+                } else if let Some((ty, why)) = ctx.lookup_hidden(x) {
+                    Err(TypeError::VariableInaccessible {
+                        range: r.clone(),
+                        var: x,
+                        var_type: ty.clone(),
+                        why,
+                    })
                 } else {
                     Err(TypeError::var_not_found(r.clone(), x))
                 },
@@ -1020,6 +1538,7 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
 
                 match self.check(ctx, e, ty) {
                     Ok(e_elab) => Ok((e_elab, ty.clone())),
+                    Err(err) if err.is_hole() => Err(err),
                     Err(err) => Err(TypeError::bad_annotation(r.clone(), e, ty.clone(), err)),
                 }
             },
@@ -1028,6 +1547,7 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                     (e1_elab, Type::Function(ty_a, ty_b)) => {
                         match self.check(ctx, e2, &ty_a) {
                             Ok(e2_elab) => Ok((self.alloc(Expr::App(r.clone(), e1_elab, e2_elab)), *ty_b)),
+                            Err(arg_err) if arg_err.is_hole() => Err(arg_err),
                             Err(arg_err) => Err(TypeError::bad_argument(r.clone(), *ty_a, e1, e2, arg_err)),
                         }
                     },
@@ -1049,18 +1569,23 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                     (_, Type::Later(clock, ty)) => (clock, ty),
                     (_, ty) => return Err(TypeError::forcing_non_thunk(r.clone(), e1, ty)),
                 };
-                let Some(stripped_ctx) = ctx.strip_tick(synthesized_clock) else {
-                    return Err(TypeError::ForcingWithNotEnoughTick {
-                        range: r.clone(),
-                        expr: e1,
-                        ctx: ctx.clone(),
-                        synthesized_clock,
-                    });
+                let stripped_ctx = match ctx.strip_tick(synthesized_clock) {
+                    Ok(stripped_ctx) => stripped_ctx,
+                    Err(missing) =>
+                        return Err(TypeError::ForcingWithNotEnoughTick {
+                            range: r.clone(),
+                            expr: e1,
+                            ctx: ctx.clone(),
+                            synthesized_clock,
+                            missing,
+                        }),
                 };
                 match self.check(&stripped_ctx, e1, &Type::Later(synthesized_clock, synthesized_type.clone())) {
                     Ok(e1_elab) =>
                         // TODO: bubble this up to the topmost delay during elaboration?
                         Ok((self.alloc(Expr::Adv(r.clone(), e1_elab)), *synthesized_type)),
+                    Err(err) if err.is_hole() =>
+                        Err(err),
                     Err(err) =>
                         Err(TypeError::ForcingDoesntHoldUp {
                             range: r.clone(),
@@ -1071,32 +1596,10 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                         }),
                 }
             },
-            &Expr::LetIn(ref r, x, None, e1, e2) =>
-                match self.synthesize(ctx, e1) {
-                    Ok((e1_elab, ty_x)) => {
-                        let new_ctx = ctx.clone().with_var(x, ty_x);
-                        let (e2_elab, e2_ty) = self.synthesize(&new_ctx, e2)?;
-                        Ok((self.alloc(Expr::LetIn(r.clone(), x, None, e1_elab, e2_elab)), e2_ty))
-                    },
-                    Err(err) =>
-                        Err(TypeError::let_failure(r.clone(), x, e1, err)),
-                },
-            &Expr::LetIn(ref r, x, Some(ref e1_ty), e1, e2) => {
-                let e1_elab = match self.check(ctx, e1, e1_ty) {
-                    Ok(e1_elab) =>
-                        e1_elab,
-                    Err(err) =>
-                        return Err(TypeError::LetCheckFailure {
-                            range: r.clone(),
-                            var: x,
-                            expected_type: e1_ty.clone(),
-                            expr: e1,
-                            err: Box::new(err)
-                        }),
-                };
-                let new_ctx = ctx.clone().with_var(x, e1_ty.clone());
+            &Expr::LetIn(ref r, x, ref e1_ty, e1, e2) => {
+                let (e1_elab, new_ctx) = self.let_bound(ctx, r, x, e1_ty.as_ref(), e1)?;
                 let (e2_elab, e2_ty) = self.synthesize(&new_ctx, e2)?;
-                Ok((self.alloc(Expr::LetIn(r.clone(), x, Some(e1_ty.clone()), e1_elab, e2_elab)), e2_ty))
+                Ok((self.alloc(Expr::LetIn(r.clone(), x, e1_ty.clone(), e1_elab, e2_elab)), e2_ty))
             },
             &Expr::UnPair(ref r, x1, x2, e0, e) =>
                 match self.synthesize(ctx, e0)? {
@@ -1220,19 +1723,166 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                 let e2_elab = self.check(ctx, e2, &ty2)?;
                 Ok((self.alloc(Expr::Binop(r.clone(), op, e1_elab, e2_elab)), tyret))
             },
+            &Expr::Hole(ref r, name) =>
+                Err(TypeError::Hole { range: r.clone(), name, expected: None, ctx: ctx.clone() }),
             _ =>
                 Err(TypeError::synthesis_unsupported(expr)),
         }
     }
 
+    // checks (or synthesizes) the bound expression of a let, then
+    // generalises it over the type/clock variables its type mentions
+    // that the context doesn't pin down. returns the elaborated bound
+    // expression and the context for the body.
+    fn let_bound<'c>(&mut self, ctx: &Ctx, r: &R, x: Symbol, e1_ty: Option<&Type>, e1: &'c Expr<'c, R>) -> Result<(&'b Expr<'b, R>, Ctx), TypeError<'c, R>> {
+        let (e1_elab, ty_x) = match e1_ty {
+            Some(e1_ty) =>
+                match self.check(ctx, e1, e1_ty) {
+                    Ok(e1_elab) =>
+                        (e1_elab, e1_ty.clone()),
+                    Err(err) if err.is_hole() =>
+                        return Err(err),
+                    Err(err) =>
+                        return Err(TypeError::LetCheckFailure {
+                            range: r.clone(),
+                            var: x,
+                            expected_type: e1_ty.clone(),
+                            expr: e1,
+                            err: Box::new(err)
+                        }),
+                },
+            None =>
+                match self.synthesize(ctx, e1) {
+                    Ok(res) =>
+                        res,
+                    Err(err) if err.is_hole() =>
+                        return Err(err),
+                    Err(err) =>
+                        return Err(TypeError::let_failure(r.clone(), x, e1, err)),
+                },
+        };
+
+        let binders: Vec<_> = if is_generalizable(e1) {
+            ctx.type_vars()
+                .into_iter()
+                .filter(|&(y, _)| ty_x.mentions(y) && !ctx.pins_type_var(y))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        if binders.is_empty() {
+            return Ok((e1_elab, ctx.clone().with_var(x, ty_x)));
+        }
+
+        // only clocks have a runtime representation
+        let e1_gen = binders.iter().rev().fold(e1_elab, |e, &(y, k)| match k {
+            Kind::Clock => self.alloc(Expr::ClockLam(e.range().clone(), y, e)),
+            Kind::Type => e,
+        });
+        Ok((e1_gen, Ctx::GenVar(x, binders, ty_x, Rc::new(ctx.clone()))))
+    }
+
+    // synthesizes a use of a let-generalised variable. explicit
+    // clock/type applications directly on it instantiate its
+    // generalised variables in order, and whatever's left over gets
+    // instantiated to itself. returns None if expr isn't such a use.
+    fn synthesize_instantiation<'c>(&mut self, ctx: &Ctx, expr: &'c Expr<'c, R>) -> Result<Option<(&'b Expr<'b, R>, Type)>, TypeError<'c, R>> {
+        let mut spine = Vec::new();
+        let mut head = expr;
+        while let Expr::ClockApp(_, e, _) | Expr::TypeApp(_, e, _) = *head {
+            spine.push(head);
+            head = e;
+        }
+        spine.reverse();
+
+        let Expr::Var(ref r, x) = *head else {
+            return Ok(None);
+        };
+        let binders = ctx.lookup_generalized(x);
+        if binders.is_empty() || spine.len() > binders.len() {
+            // any further applications are to the type itself, and
+            // go through the usual rules
+            return Ok(None);
+        }
+        // unwrap safety: it has binders, so it must be in the context
+        let (timing, gen_ty) = ctx.lookup_term_var(x).unwrap();
+        if !timing.is_empty() && !gen_ty.is_stable() {
+            return Err(TypeError::VariableTimingBad {
+                range: r.clone(),
+                var: x,
+                timing,
+                var_type: gen_ty.clone(),
+            });
+        }
+        let scheme = || binders.iter().rev().fold(gen_ty.clone(), |ty, &(y, k)| Type::Forall(y, k, Box::new(ty)));
+
+        // rename the binders apart first so that instantiating them
+        // one-by-one doesn't capture anything
+        let mut ty = gen_ty.clone();
+        let mut fresh = Vec::with_capacity(binders.len());
+        for &(y, k) in binders.iter() {
+            let y_fresh = mk_fresh(y, self.interner);
+            ty = ty.subst(y, &ToSubst::from_var(y_fresh, k), self.interner);
+            fresh.push(y_fresh);
+        }
+
+        let mut elab = self.alloc(Expr::Var(r.clone(), x));
+        for (i, (&(y, k), y_fresh)) in binders.iter().zip(fresh).enumerate() {
+            let (range, arg) = match spine.get(i).copied() {
+                Some(&Expr::ClockApp(ref r_app, e, c)) => {
+                    if k != Kind::Clock {
+                        return Err(TypeError::NonForallClockApp { range: r_app.clone(), purported_forall_clock: e, actual_type: scheme() });
+                    }
+                    if ctx.lookup_type_var(c.var) != Some(Kind::Clock) {
+                        return Err(TypeError::InvalidClock { range: r_app.clone(), purported_clock: c, bad_symbol: c.var });
+                    }
+                    (r_app.clone(), ToSubst::Clock(c))
+                },
+                Some(&Expr::TypeApp(ref r_app, e, ref ty_to_subst)) => {
+                    if k != Kind::Type {
+                        return Err(TypeError::NonForallTypeApp { range: r_app.clone(), purported_forall_type: e, actual_type: scheme() });
+                    }
+                    ty_to_subst.check_validity(ctx).map_err(|bad_symbol|
+                        TypeError::InvalidType {
+                            range: r_app.clone(),
+                            purported_type: ty_to_subst.clone(),
+                            bad_symbol
+                        }
+                    )?;
+                    (r_app.clone(), ToSubst::Type(ty_to_subst.clone()))
+                },
+                _ =>
+                    (r.clone(), ToSubst::from_var(y, k)),
+            };
+            elab = match arg {
+                ToSubst::Clock(c) => self.alloc(Expr::ClockApp(range, elab, c)),
+                ToSubst::Type(ref ty_arg) => self.alloc(Expr::TypeApp(range, elab, ty_arg.clone())),
+            };
+            ty = ty.subst(y_fresh, &arg, self.interner);
+        }
+
+        Ok(Some((elab, ty)))
+    }
+
     pub fn check_file<'c, 'd>(&mut self, file: &'c SourceFile<'d, R>) -> Result<SourceFile<'b, R>, FileTypeErrors<'d, R>> {
+        self.check_file_with(file, &mut |tc, _, ctx, expr, ty| tc.check(ctx, expr, ty).map_err(Box::new))
+    }
+
+    // like check_file, but the body of the ith definition gets
+    // checked by check_body(self, i, ctx, body, type), e.g. so that
+    // it can reuse what it found last time
+    pub fn check_file_with<'c, 'd>(
+        &mut self,
+        file: &'c SourceFile<'d, R>,
+        check_body: &mut CheckBody<'_, 'a, 'b, 'd, R>,
+    ) -> Result<SourceFile<'b, R>, FileTypeErrors<'d, R>> {
         let mut defs = Vec::new();
         let mut errs = Vec::new();
         let mut running_ctx = Ctx::Empty;
         for &clock_name in self.global_clocks.iter() {
             running_ctx = Ctx::TypeVar(clock_name, Kind::Clock, running_ctx.into());
         }
-        for def in file.defs.iter() {
+        for (i, def) in file.defs.iter().enumerate() {
             match def.body {
                 TopLevelDefBody::Def { kind, ref type_, expr } => {
                     let ctx = match kind {
@@ -1243,7 +1893,7 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                         errs.push(TopLevelTypeError::InvalidType(def.name, type_.clone(), missing_symbol));
                         continue;
                     }
-                    match self.check(ctx, expr, type_) {
+                    match check_body(self, i, ctx, expr, type_) {
                         Ok(body_elab) => {
                             defs.push(TopLevelDef {
                                 body: TopLevelDefBody::Def { expr: body_elab, type_: type_.clone(), kind },
@@ -1251,7 +1901,7 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
                             });
                         }
                         Err(err) => {
-                            errs.push(TopLevelTypeError::TypeError(def.name, err));
+                            errs.push(TopLevelTypeError::TypeError(def.name, *err));
                         }
                     }
                     if running_ctx.lookup_term_var(def.name).is_some() ||
@@ -1291,6 +1941,24 @@ impl<'a, 'b, R: Clone> Typechecker<'a, 'b, R> {
     }
 }
 
+// only generalise lets bound to values, since generalising over a
+// clock makes the binding a function of it, and we don't want to
+// lose sharing of anything that does real work
+fn is_generalizable<R>(expr: &Expr<'_, R>) -> bool {
+    match *expr {
+        Expr::Lam(..) |
+        Expr::Lob(..) |
+        Expr::Box(..) |
+        Expr::Delay(..) |
+        Expr::ClockLam(..) =>
+            true,
+        Expr::Annotate(_, e, _) =>
+            is_generalizable(e),
+        _ =>
+            false,
+    }
+}
+
 #[derive(Debug)]
 pub enum TopLevelTypeError<'b, R> {
     TypeError(Symbol, TypeError<'b, R>),
@@ -1303,7 +1971,74 @@ pub struct FileTypeErrors<'b, R> {
     pub errs: Vec<TopLevelTypeError<'b, R>>,
 }
 
+impl<'b> TopLevelTypeError<'b, tree_sitter::Range> {
+    pub fn diagnostic(&self, interner: &DefaultStringInterner, code: &str) -> Diagnostic {
+        match *self {
+            TopLevelTypeError::TypeError(name, ref err) => {
+                // the innermost error is what actually went wrong, and
+                // the ones around it say what we were in the middle of
+                let mut chain = vec![err];
+                while let Some(source) = chain[chain.len() - 1].source_type_error() {
+                    chain.push(source);
+                }
+                let root = chain.pop().unwrap();
+                let root_message = PrettyOneTypeError(root.pretty(interner, code)).to_string();
+                let (headline, rest) = root_message.split_once('\n').unwrap_or((&root_message, ""));
+                let mut diag = Diagnostic::new(headline).with_code(root.code());
+                for ty in root.types() {
+                    diag = diag.with_type(ty.pretty(interner).to_string());
+                }
+                let mut labelled = Vec::new();
+                if let Some(range) = root.range() {
+                    diag = diag.with_primary(Label::new(range, ""));
+                    labelled.push(range.start_byte .. range.end_byte);
+                }
+                for err in chain.into_iter().rev() {
+                    let Some(range) = err.range() else { continue };
+                    if labelled.contains(&(range.start_byte .. range.end_byte)) {
+                        continue;
+                    }
+                    labelled.push(range.start_byte .. range.end_byte);
+                    diag = diag.with_secondary(Label::new(range, PrettyOneTypeError(err.pretty(interner, code)).to_string()));
+                }
+                if !rest.is_empty() {
+                    // e.g. the context listing for holes, which is
+                    // indented to go under the headline
+                    let rest: Vec<_> = rest.lines().map(|line| line.strip_prefix("  ").unwrap_or(line)).collect();
+                    diag = diag.with_note(rest.join("\n"));
+                }
+                diag.with_note(format!("in definition of \"{}\"", interner.resolve(name).unwrap()))
+            },
+            TopLevelTypeError::CannotRedefine(name, ref range) =>
+                Diagnostic::new(format!("cannot redefine \"{}\"", interner.resolve(name).unwrap()))
+                    .with_code("E0301")
+                    .with_primary(Label::new(range, "already defined before this")),
+            TopLevelTypeError::InvalidType(name, ref ty, missing) =>
+                Diagnostic::new(format!("the type \"{}\" of definition \"{}\" is invalid", ty.pretty(interner), interner.resolve(name).unwrap()))
+                    .with_code("E0302")
+                    .with_type(ty.pretty(interner).to_string())
+                    .with_note(format!("the clock/type variable \"{}\" is not in the context", interner.resolve(missing).unwrap())),
+        }
+    }
+}
+
 impl<'b, R> FileTypeErrors<'b, R> {
+    // drops the errors that come down to using one of the given
+    // variables, e.g. definitions that couldn't be parsed
+    pub fn not_caused_by_missing(self, missing: &[Symbol]) -> FileTypeErrors<'b, R> {
+        let errs = self.errs.into_iter().filter(|err| {
+            let TopLevelTypeError::TypeError(_, ref err) = *err else {
+                return true;
+            };
+            let mut root = err;
+            while let Some(source) = root.source_type_error() {
+                root = source;
+            }
+            !matches!(*root, TypeError::VariableNotFound { var, .. } if missing.contains(&var))
+        }).collect();
+        FileTypeErrors { errs }
+    }
+
     pub fn pretty(&'b self, interner: &'b DefaultStringInterner, code: &'b str) -> PrettyFileTypeErrors<'b, R> {
         PrettyFileTypeErrors { interner, code, errs: self }
     }
@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::iter;
+use std::{fmt, iter};
 use std::rc::Rc;
 
 use wasm::FuncType;
@@ -12,7 +12,47 @@ use crate::runtime::Runtime;
 
 const RUNTIME_BYTES: &'static [u8] = include_bytes!(env!("CARGO_CDYLIB_FILE_CLOCKY_RUNTIME"));
 
-pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32, main: usize) -> Vec<u8> {
+// something the backend couldn't make code for, or code that came out
+// invalid. either way it's a bug in the compiler, not the program
+#[derive(Debug)]
+pub struct BackendError {
+    // the global it was in, if it was in one rather than the runtime
+    // or the init function
+    pub global: Option<u32>,
+    pub kind: BackendErrorKind,
+}
+
+#[derive(Debug)]
+pub enum BackendErrorKind {
+    // an op given a number of arguments it doesn't take
+    BadOp(Op, usize),
+    // a variable that nothing around it binds
+    UnboundVar(DebruijnIndex),
+    // a closure built of a global that isn't a function
+    NotAFunction(Global),
+    // what the validator said about the finished module
+    Invalid(wasmparser::BinaryReaderError),
+}
+
+impl fmt::Display for BackendErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            BackendErrorKind::BadOp(op, n_args) =>
+                write!(f, "op {} doesn't take {} argument(s)", op, n_args),
+            BackendErrorKind::UnboundVar(i) =>
+                write!(f, "variable #{} isn't bound", i.0),
+            BackendErrorKind::NotAFunction(g) =>
+                write!(f, "can't build a closure of {}, which isn't a function", g),
+            BackendErrorKind::Invalid(ref err) =>
+                write!(f, "the module came out invalid: {}", err),
+        }
+    }
+}
+
+// the globals from partial_app_def_offset on are the partial
+// application functions, and partial_app_slots has where each goes in
+// the table, counting from the first of them
+pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32, partial_app_slots: &[u32], main: usize) -> Result<Vec<u8>, BackendError> {
     // TODO: can we parse more of this at compile time?
     // probably... would have to be a build script though, I imagine
     let runtime = Runtime::from_bytes(RUNTIME_BYTES);
@@ -49,28 +89,33 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
 
 
     let func_offset = runtime.functions.len() as u32;
+    let partial_app_table_offset = partial_app_def_offset + func_offset;
+    let table_slots: Vec<u32> = (func_offset..partial_app_table_offset)
+        .chain(partial_app_slots.iter().map(|&slot| partial_app_table_offset + slot))
+        .collect();
     let mut translator = Translator {
         globals: global_defs,
         globals_offset,
+        func_offset,
         func_table_offset: func_offset, // TODO: is this right?
-        partial_app_table_offset: partial_app_def_offset + func_offset,
+        partial_app_table_offset,
         function_types: &mut function_types,
         bad_global,
         runtime_exports: &runtime.exports,
     };
 
     for (i, def) in global_defs.into_iter().enumerate() {
-        println!("doing {i}");
         globals_out.global(wasm::GlobalType {
             val_type: wasm::ValType::I32,
             mutable: true,
             shared: false,
         }, &wasm::ConstExpr::i32_const(0));
+        let in_global = |kind| BackendError { global: Some(i as u32), kind };
         match def {
             GlobalDef::Func { rec, arity, env_size, body } => {
                 let mut trans = FuncTranslator::new(&mut translator, *rec, *env_size, *arity);
                 let ctx = trans.make_initial_ctx();
-                trans.translate(ctx, body);
+                trans.translate(ctx, body).map_err(in_global)?;
                 let (type_idx, func) = trans.finish();
                 functions.function(type_idx);
                 codes.function(&func);
@@ -78,7 +123,7 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
                 init_func.instruction(&wasm::Instruction::I32Const(8));
                 init_func.instruction(&wasm::Instruction::Call(translator.runtime_exports["alloc"].1));
                 init_func.instruction(&wasm::Instruction::LocalTee(0));
-                init_func.instruction(&wasm::Instruction::I32Const(func_offset as i32 + i as i32));
+                init_func.instruction(&wasm::Instruction::I32Const(table_slots[i] as i32));
                 init_func.instruction(&wasm::Instruction::I32Store(wasm::MemArg { offset: 0, align: 2, memory_index: 0 }));
                 init_func.instruction(&wasm::Instruction::LocalGet(0));
                 init_func.instruction(&wasm::Instruction::I32Const(*arity as i32));
@@ -89,7 +134,7 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
             GlobalDef::ClosedExpr { body } => {
                 let mut trans = FuncTranslator::new(&mut translator, false, 0, 0);
                 let ctx = trans.make_initial_ctx();
-                trans.translate(ctx, body);
+                trans.translate(ctx, body).map_err(in_global)?;
                 let (type_idx, func) = trans.finish();
                 functions.function(type_idx);
                 codes.function(&func);
@@ -101,7 +146,6 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
         }
     }
 
-    println!("main is {main}");
     exports.export("main", wasm::ExportKind::Global, globals_offset + main as u32);
     runtime.emit_exports(&mut exports);
 
@@ -129,18 +173,24 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
         page_size_log2: None,
     });
 
+    let table_size = table_slots.iter().map(|&slot| slot + 1).max().unwrap_or(func_offset);
     let mut tables = wasm::TableSection::new();
     tables.table(wasm::TableType {
         element_type: wasm::RefType::FUNCREF,
-        minimum: func_offset + global_defs.len() as u32,
-        maximum: Some(func_offset + global_defs.len() as u32),
+        minimum: table_size,
+        maximum: Some(table_size),
     });
 
     let mut elems = wasm::ElementSection::new();
     runtime.emit_elements(&mut elems);
-    let function_idxs: Vec<u32> = (func_offset..func_offset + global_defs.len() as u32).collect();
+    let function_idxs: Vec<u32> = (func_offset..partial_app_table_offset).collect();
     let function_elems = wasm::Elements::Functions(&function_idxs);
     elems.active(Some(0), &wasm::ConstExpr::i32_const(func_offset as i32), function_elems);
+    // the partial application functions that weren't needed leave gaps
+    for (i, &slot) in table_slots.iter().enumerate().skip(partial_app_def_offset as usize) {
+        let function_idx = [func_offset + i as u32];
+        elems.active(Some(0), &wasm::ConstExpr::i32_const(slot as i32), wasm::Elements::Functions(&function_idx));
+    }
 
     let mut module = wasm::Module::new();
     module.section(&types);
@@ -154,25 +204,85 @@ pub fn translate<'a>(global_defs: &[GlobalDef<'a>], partial_app_def_offset: u32,
     module.section(&codes);
     module.section(&names);
 
-    module.finish()
+    let bytes = module.finish();
+    if let Err(err) = wasmparser::Validator::new().validate_all(&bytes) {
+        let global = function_at(&bytes, err.offset())
+            .and_then(|func| func.checked_sub(func_offset))
+            .filter(|&g| (g as usize) < global_defs.len());
+        return Err(BackendError { global, kind: BackendErrorKind::Invalid(err) });
+    }
+
+    Ok(bytes)
+}
+
+// the index of the function whose code has the given byte offset in
+// it. there are no imported functions, so it's the same as its place
+// in the code section
+fn function_at(bytes: &[u8], offset: usize) -> Option<u32> {
+    let mut i = 0;
+    for payload in wasmparser::Parser::new(0).parse_all(bytes) {
+        if let Ok(wasmparser::Payload::CodeSectionEntry(body)) = payload {
+            if body.range().contains(&offset) {
+                return Some(i);
+            }
+            i += 1;
+        }
+    }
+    None
 }
 
 enum Ctx {
     Empty,
-    // TODO: generalize to support multiple locals per variable, to allow for unboxed layouts and such
-    Local(u32, Rc<Ctx>),
+    // variables are words, in i32 locals, except for samples that
+    // ir1::Translator::unpack_lets has let-bound as f32s, which
+    // includes the unpacked sample parameters
+    Local(u32, wasm::ValType, Rc<Ctx>),
 }
 
 impl Ctx {
-    fn lookup(&self, i: DebruijnIndex) -> u32 {
-        match (self, i) {
-            (&Ctx::Local(n, _), DebruijnIndex(0)) => n,
-            (&Ctx::Local(_, ref next), DebruijnIndex(j)) => next.lookup(DebruijnIndex(j-1)),
-            (_, _) => panic!("lookup index out of bounds??"),
+    fn lookup(&self, i: DebruijnIndex) -> Result<(u32, wasm::ValType), BackendErrorKind> {
+        let mut ctx = self;
+        for _ in 0..i.0 {
+            match *ctx {
+                Ctx::Local(_, _, ref next) => ctx = next,
+                Ctx::Empty => return Err(BackendErrorKind::UnboundVar(i)),
+            }
+        }
+        match *ctx {
+            Ctx::Local(n, ty, _) => Ok((n, ty)),
+            Ctx::Empty => Err(BackendErrorKind::UnboundVar(i)),
         }
     }
 }
 
+// the type of what an expression leaves on the stack. everything is a
+// word except for the results of the f32 ops
+fn val_type(ctx: &Rc<Ctx>, expr: &Expr<'_>) -> Result<wasm::ValType, BackendErrorKind> {
+    Ok(match *expr {
+        Expr::Var(i) => ctx.lookup(i)?.1,
+        Expr::If(_, e1, _) => val_type(ctx, e1)?,
+        Expr::Let(es, ec) => {
+            let mut new_ctx = ctx.clone();
+            for &e in es.iter() {
+                new_ctx = Ctx::Local(0, val_type(ctx, e)?, new_ctx).into();
+            }
+            val_type(&new_ctx, ec)?
+        },
+        Expr::Op(op, _) => match op {
+            Op::Const(Value::Sample(_)) |
+            Op::UnpackF32 |
+            Op::FAdd | Op::FSub | Op::FMul | Op::FDiv |
+            Op::Sin | Op::Cos | Op::Pi |
+            Op::ReinterpI2F | Op::CastI2F =>
+                wasm::ValType::F32,
+            _ =>
+                wasm::ValType::I32,
+        },
+        Expr::CallDirect(_, _) | Expr::CallIndirect(_, _) =>
+            wasm::ValType::I32,
+    })
+}
+
 struct FunctionTypes {
     types: IndexSet<FuncType>,
 }
@@ -188,6 +298,7 @@ impl FunctionTypes {
 struct Translator<'a> {
     globals: &'a [GlobalDef<'a>],
     globals_offset: u32,
+    func_offset: u32,
     func_table_offset: u32,
     partial_app_table_offset: u32,
     function_types: &'a mut FunctionTypes,
@@ -221,7 +332,6 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
     }
 
     fn args_offset(&self) -> u32 {
-        // may in the future be different if the args are unboxed
         self.arity + 1
     }
 
@@ -229,15 +339,12 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
         // ir2 ctx: environment..., self (if recursive), args...
         // wasm calling convention: args..., closure
 
-        // TODO: we want to be able to hold unboxed floats in the
-        // environment, probably...
-
         // first load the environment into locals
         let mut ctx = Ctx::Empty.into();
         let closure_local_index = self.args_offset() - 1;
         for i in (0..self.env_size).rev() {
             let env_i_local = self.args_offset() + i;
-            ctx = Ctx::Local(env_i_local, ctx).into();
+            ctx = Ctx::Local(env_i_local, wasm::ValType::I32, ctx).into();
             self.insns.push(wasm::Instruction::LocalGet(closure_local_index));
             // self.insns.push(wasm::Instruction::I32Const(4 * (i as i32 + 1)));
             // self.insns.push(wasm::Instruction::I32Add);
@@ -246,11 +353,13 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
         }
 
         if self.rec {
-            ctx = Ctx::Local(closure_local_index, ctx).into();
+            ctx = Ctx::Local(closure_local_index, wasm::ValType::I32, ctx).into();
         }
 
+        // the args are all words; any that are samples only ever used
+        // as such get unpacked by a let, see ir1::Translator::unpack_lets
         for i in 0..self.arity {
-            ctx = Ctx::Local(i, ctx).into();
+            ctx = Ctx::Local(i, wasm::ValType::I32, ctx).into();
         }
 
         ctx
@@ -270,41 +379,49 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
         })
     }
 
-    fn translate(&mut self, ctx: Rc<Ctx>, expr: &'a Expr<'a>) {
+    fn translate(&mut self, ctx: Rc<Ctx>, expr: &'a Expr<'a>) -> Result<(), BackendErrorKind> {
         match *expr {
             Expr::Var(i) => {
-                self.insns.push(wasm::Instruction::LocalGet(ctx.lookup(i)));
+                self.insns.push(wasm::Instruction::LocalGet(ctx.lookup(i)?.0));
             },
             Expr::If(e0, e1, e2) => {
-                self.translate(ctx.clone(), e0);
-                self.insns.push(wasm::Instruction::If(wasm::BlockType::Result(wasm::ValType::I32)));
-                self.translate(ctx.clone(), e1);
+                let ty = val_type(&ctx, e1)?;
+                self.translate(ctx.clone(), e0)?;
+                self.insns.push(wasm::Instruction::If(wasm::BlockType::Result(ty)));
+                self.translate(ctx.clone(), e1)?;
                 self.insns.push(wasm::Instruction::Else);
-                self.translate(ctx, e2);
+                self.translate(ctx, e2)?;
                 self.insns.push(wasm::Instruction::End);
             },
             Expr::Let(es, ec) => {
                 let mut new_ctx = ctx.clone();
                 for &e in es.iter() {
                     // these let bindings do not get access to each other, so use ctx, not new_ctx, here
-                    self.translate(ctx.clone(), e);
-                    // TODO: we will want to be able to let-bind unboxed floats
-                    let l = self.next_local(wasm::ValType::I32);
+                    let ty = val_type(&ctx, e)?;
+                    self.translate(ctx.clone(), e)?;
+                    let l = self.next_local(ty);
                     self.insns.push(wasm::Instruction::LocalSet(l));
                     // TODO: is this the right order?
-                    new_ctx = Ctx::Local(l, new_ctx).into();
+                    new_ctx = Ctx::Local(l, ty, new_ctx).into();
                 }
-                self.translate(new_ctx, ec);
+                self.translate(new_ctx, ec)?;
             },
             Expr::Op(op, args) =>
-                self.translate_op(ctx, op, args),
-            Expr::CallDirect(_, _) =>
-                panic!("CallDirect not implemented yet :^)"),
+                self.translate_op(ctx, op, args)?,
+            Expr::CallDirect(Global(g), args) => {
+                for &arg in args.iter() {
+                    self.translate(ctx.clone(), arg)?;
+                }
+                // the function has no environment, so it never looks
+                // at its closure
+                self.insns.push(wasm::Instruction::I32Const(0));
+                self.insns.push(wasm::Instruction::Call(self.translator.func_offset + g));
+            },
             Expr::CallIndirect(target, args) => {
                 for &arg in args.iter() {
-                    self.translate(ctx.clone(), arg);
+                    self.translate(ctx.clone(), arg)?;
                 }
-                self.translate(ctx, target);
+                self.translate(ctx, target)?;
                 let call_target_closure = self.temp(wasm::ValType::I32, 0);
                 self.insns.push(wasm::Instruction::LocalTee(call_target_closure));
                 self.insns.push(wasm::Instruction::I32Load(wasm::MemArg { offset: 4, align: 2, memory_index: 0 }));
@@ -376,19 +493,14 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 self.insns.push(wasm::Instruction::End);
             },
         }
-    }
-
-    fn dup(&mut self, ty: wasm::ValType) {
-        let t = self.temp(ty, 0);
-        self.insns.push(wasm::Instruction::LocalTee(t));
-        self.insns.push(wasm::Instruction::LocalGet(t));
+        Ok(())
     }
 
     fn alloc(&mut self) {
         self.insns.push(wasm::Instruction::Call(self.translator.runtime_exports["alloc"].1));
     }
 
-    fn translate_op(&mut self, ctx: Rc<Ctx>, op: Op, args: &'a [&'a Expr<'a>]) {
+    fn translate_op(&mut self, ctx: Rc<Ctx>, op: Op, args: &'a [&'a Expr<'a>]) -> Result<(), BackendErrorKind> {
         match (op, args) {
             (Op::Const(Value::Unit), &[]) => {
                 self.insns.push(wasm::Instruction::I32Const(0));
@@ -396,36 +508,26 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
             (Op::Const(Value::Index(i)), &[]) => {
                 self.insns.push(wasm::Instruction::I32Const(i as i32));
             },
-            (Op::AllocI32, &[e]) => {
-                self.insns.push(wasm::Instruction::I32Const(4));
-                self.alloc();
-                self.dup(wasm::ValType::I32);
-                self.translate(ctx, e);
-                self.insns.push(wasm::Instruction::I32Store(wasm::MemArg { offset: 0, align: 2, memory_index: 0 }));
-            },
-            (Op::DerefI32, &[e]) => {
-                self.translate(ctx, e);
-                self.insns.push(wasm::Instruction::I32Load(wasm::MemArg { offset: 0, align: 2, memory_index: 0 }));
+            (Op::PackI32 | Op::UnpackI32, &[e]) => {
+                // an index is already its own word
+                self.translate(ctx, e)?;
             },
             (Op::Const(Value::Sample(x)), &[]) => {
                 self.insns.push(wasm::Instruction::F32Const(x));
             },
-            (Op::AllocF32, &[e]) => {
-                self.insns.push(wasm::Instruction::I32Const(4));
-                self.alloc();
-                self.dup(wasm::ValType::I32);
-                self.translate(ctx, e);
-                self.insns.push(wasm::Instruction::F32Store(wasm::MemArg { offset: 0, align: 2, memory_index: 0 }));
+            (Op::PackF32, &[e]) => {
+                self.translate(ctx, e)?;
+                self.insns.push(wasm::Instruction::I32ReinterpretF32);
             },
-            (Op::DerefF32, &[e]) => {
-                self.translate(ctx, e);
-                self.insns.push(wasm::Instruction::F32Load(wasm::MemArg { offset: 0, align: 2, memory_index: 0 }));
+            (Op::UnpackF32, &[e]) => {
+                self.translate(ctx, e)?;
+                self.insns.push(wasm::Instruction::F32ReinterpretI32);
             },
             // TODO: extract these out into a "BinOp" op?
             (Op::FAdd | Op::FSub | Op::FMul | Op::FDiv, &[e1, e2]) => {
                 // TODO: is this the right order?
-                self.translate(ctx.clone(), e1);
-                self.translate(ctx, e2);
+                self.translate(ctx.clone(), e1)?;
+                self.translate(ctx, e2)?;
                 self.insns.push(match op {
                     Op::FAdd => wasm::Instruction::F32Add,
                     Op::FSub => wasm::Instruction::F32Sub,
@@ -440,8 +542,8 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 // TODO: obviously it is silly to allocate a fresh
                 // boolean for every comparison. we should statically
                 // allocate two booleans.
-                self.translate(ctx.clone(), e1);
-                self.translate(ctx, e2);
+                self.translate(ctx.clone(), e1)?;
+                self.translate(ctx, e2)?;
                 self.insns.push(match op {
                     Op::FGt => wasm::Instruction::F32Gt,
                     Op::FGe => wasm::Instruction::F32Ge,
@@ -459,7 +561,7 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                     _ => unreachable!(),
                 };
                 let prim_func = self.translator.runtime_exports[op_name].1;
-                self.translate(ctx, e);
+                self.translate(ctx, e)?;
                 self.insns.push(wasm::Instruction::Call(prim_func));
             },
             (Op::Pi, &[]) => {
@@ -467,8 +569,8 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 self.insns.push(wasm::Instruction::F32Const(std::f32::consts::PI));
             },
             (Op::IAdd | Op::ISub | Op::IMul | Op::IDiv | Op::Shl | Op::Shr | Op::And | Op::Xor | Op::Or, &[e1, e2]) => {
-                self.translate(ctx.clone(), e1);
-                self.translate(ctx, e2);
+                self.translate(ctx.clone(), e1)?;
+                self.translate(ctx, e2)?;
                 self.insns.push(match op {
                     Op::IAdd => wasm::Instruction::I32Add,
                     Op::ISub => wasm::Instruction::I32Sub,
@@ -483,8 +585,8 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 });
             },
             (Op::IGt | Op::IGe | Op::ILt | Op::ILe | Op::IEq | Op::INe, &[e1, e2]) => {
-                self.translate(ctx.clone(), e1);
-                self.translate(ctx, e2);
+                self.translate(ctx.clone(), e1)?;
+                self.translate(ctx, e2)?;
                 self.insns.push(match op {
                     Op::IGt => wasm::Instruction::I32GtU,
                     Op::IGe => wasm::Instruction::I32GeU,
@@ -496,11 +598,11 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 });
             },
             (Op::ReinterpF2I, &[e]) => {
-                self.translate(ctx, e);
+                self.translate(ctx, e)?;
                 self.insns.push(wasm::Instruction::I32ReinterpretF32);
             },
             (Op::ReinterpI2F | Op::CastI2F, &[e]) => {
-                self.translate(ctx, e);
+                self.translate(ctx, e)?;
                 self.insns.push(match op {
                     Op::ReinterpI2F => wasm::Instruction::F32ReinterpretI32,
                     Op::CastI2F => wasm::Instruction::F32ConvertI32U,
@@ -508,12 +610,12 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 });
             },
             (Op::Proj(i), &[e]) => {
-                self.translate(ctx, e);
+                self.translate(ctx, e)?;
                 self.insns.push(wasm::Instruction::I32Load(wasm::MemArg { offset: 4 * i as u64, align: 2, memory_index: 0 }));
             },
             (Op::UnGen, &[e]) => {
                 // nop for now
-                self.translate(ctx, e);
+                self.translate(ctx, e)?;
             },
             (Op::AllocAndFill, _) => {
                 self.insns.push(wasm::Instruction::I32Const(args.len() as i32 * 4));
@@ -522,15 +624,15 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 let l = self.next_local(wasm::ValType::I32);
                 self.insns.push(wasm::Instruction::LocalTee(l));
                 for (i, &arg) in args.iter().enumerate() {
-                    self.translate(ctx.clone(), arg);
+                    self.translate(ctx.clone(), arg)?;
                     self.insns.push(wasm::Instruction::I32Store(wasm::MemArg { offset: 4 * i as u64, align: 2, memory_index: 0 }));
                     self.insns.push(wasm::Instruction::LocalGet(l));
                 }
             },
             (Op::BuildClosure(Global(g)), _) => {
-                let g_arity = match self.translator.globals[g as usize] {
-                    GlobalDef::Func { arity, .. } => arity,
-                    GlobalDef::ClosedExpr { .. } => panic!("shouldn't be seeing closedexprs in buildclosure anymore"),
+                let g_arity = match self.translator.globals.get(g as usize) {
+                    Some(&GlobalDef::Func { arity, .. }) => arity,
+                    _ => return Err(BackendErrorKind::NotAFunction(Global(g))),
                 };
                 self.insns.push(wasm::Instruction::I32Const((args.len() as i32 + 2) * 4));
                 self.alloc();
@@ -544,7 +646,7 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 self.insns.push(wasm::Instruction::I32Store(wasm::MemArg { offset: 4, align: 2, memory_index: 0 }));
                 self.insns.push(wasm::Instruction::LocalGet(l));
                 for (i, &arg) in args.iter().enumerate() {
-                    self.translate(ctx.clone(), arg);
+                    self.translate(ctx.clone(), arg)?;
                     self.insns.push(wasm::Instruction::I32Store(wasm::MemArg { offset: 4 * (i + 2) as u64, align: 2, memory_index: 0 }));
                     self.insns.push(wasm::Instruction::LocalGet(l));
                 }
@@ -554,14 +656,14 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
             },
             (Op::ApplyCoeff(_coeff), &[clock]) => {
                 // TODO: actually apply the coefficient
-                self.translate(ctx, clock);
+                self.translate(ctx, clock)?;
             },
             (Op::SinceLastTickStream, &[clock]) => {
-                self.translate(ctx, clock);
+                self.translate(ctx, clock)?;
                 self.insns.push(wasm::Instruction::Call(self.translator.runtime_exports["since_last_tick_stream"].1));
             },
             (Op::Advance, &[delayed]) => {
-                self.translate(ctx, delayed);
+                self.translate(ctx, delayed)?;
                 // on the stack now is a pointer to either a closure
                 // or a value, offsetted. essentially a thunk. we
                 // check which one by seeing if the first word is 0.
@@ -595,13 +697,13 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 self.insns.push(wasm::Instruction::End);
             },
             (Op::Wait, &[clk]) => {
-                self.translate(ctx, clk);
+                self.translate(ctx, clk)?;
                 self.insns.push(wasm::Instruction::Call(self.translator.runtime_exports["wait_closure"].1));
             },
             (Op::Schedule, &[source_clock, target_clock, clos]) => {
-                self.translate(ctx.clone(), source_clock);
-                self.translate(ctx.clone(), target_clock);
-                self.translate(ctx, clos);
+                self.translate(ctx.clone(), source_clock)?;
+                self.translate(ctx.clone(), target_clock)?;
+                self.translate(ctx, clos)?;
                 self.insns.push(wasm::Instruction::Call(self.translator.runtime_exports["schedule"].1));
             },
             (Op::MakeClock(freq), &[]) => {
@@ -613,8 +715,9 @@ impl<'a, 'b> FuncTranslator<'a, 'b> {
                 self.insns.push(wasm::Instruction::Call(self.translator.runtime_exports["get_clock_set"].1));
             },
             _ =>
-                panic!("did not expect {} arguments for op {:?}", args.len(), op)
+                return Err(BackendErrorKind::BadOp(op, args.len())),
         }
+        Ok(())
     }
 
     fn finish(mut self) -> (u32, wasm::Function) {
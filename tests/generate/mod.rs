@@ -0,0 +1,387 @@
+// random well-typed programs, for checking that everything after the
+// parser copes with whatever it's given. the generator is type
+// directed: it's always asked for an expression of some type, in a
+// context of what can be used there, and only builds things that
+// check at that type
+use std::fmt;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Ty {
+    Unit,
+    Sample,
+    Index,
+    Product(Box<Ty>, Box<Ty>),
+    Sum(Box<Ty>, Box<Ty>),
+    Array(Box<Ty>, usize),
+    Box(Box<Ty>),
+    Later(String, Box<Ty>),
+    Stream(String, Box<Ty>),
+    Function(Box<Ty>, Box<Ty>),
+    // ?c. ~^(c) ty, where ty doesn't mention c
+    Exists(Box<Ty>),
+}
+
+impl Ty {
+    fn is_stable(&self) -> bool {
+        match *self {
+            Ty::Unit | Ty::Sample | Ty::Index | Ty::Box(_) => true,
+            Ty::Product(ref ty1, ref ty2) | Ty::Sum(ref ty1, ref ty2) => ty1.is_stable() && ty2.is_stable(),
+            Ty::Array(ref ty, _) => ty.is_stable(),
+            Ty::Later(_, _) | Ty::Stream(_, _) | Ty::Function(_, _) | Ty::Exists(_) => false,
+        }
+    }
+}
+
+// everything but the base types gets parenthesized, so there's no
+// need to think about precedence
+impl fmt::Display for Ty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Ty::Unit => write!(f, "unit"),
+            Ty::Sample => write!(f, "sample"),
+            Ty::Index => write!(f, "index"),
+            Ty::Product(ref ty1, ref ty2) => write!(f, "({} * {})", ty1, ty2),
+            Ty::Sum(ref ty1, ref ty2) => write!(f, "({} + {})", ty1, ty2),
+            Ty::Array(ref ty, n) => write!(f, "[{}; {}]", ty, n),
+            Ty::Box(ref ty) => write!(f, "([]{})", ty),
+            Ty::Later(ref k, ref ty) => write!(f, "(|>^({}) {})", k, ty),
+            Ty::Stream(ref k, ref ty) => write!(f, "(~^({}) {})", k, ty),
+            Ty::Function(ref ty1, ref ty2) => write!(f, "({} -> {})", ty1, ty2),
+            Ty::Exists(ref ty) => write!(f, "(?c. ~^(c) {})", ty),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Access {
+    // a def, which can be used anywhere
+    Global,
+    // an ordinary variable, which can only be used after a tick or in
+    // a box if its type is stable
+    Var,
+    // something forced with !, which is only there until the next
+    // tick or box
+    Forced,
+}
+
+#[derive(Clone)]
+struct Entry {
+    expr: String,
+    ty: Ty,
+    access: Access,
+}
+
+type Ctx = Vec<Entry>;
+
+fn with(ctx: &Ctx, expr: &str, ty: Ty) -> Ctx {
+    let mut ctx = ctx.clone();
+    ctx.push(Entry { expr: expr.to_string(), ty, access: Access::Var });
+    ctx
+}
+
+// what's left in a box or the body of a lob
+fn boxed(ctx: &Ctx) -> Ctx {
+    ctx.iter()
+        .filter(|e| e.access == Access::Global || (e.access == Access::Var && e.ty.is_stable()))
+        .cloned()
+        .collect()
+}
+
+// what's left after a tick of clock k, including the things that can
+// now be forced
+fn delayed(ctx: &Ctx, k: &str) -> Ctx {
+    let mut new_ctx = boxed(ctx);
+    for e in ctx.iter().filter(|e| e.access != Access::Forced) {
+        let forced = match e.ty {
+            Ty::Later(ref l, ref ty) if l == k && e.access == Access::Var =>
+                Some((format!("(!{})", e.expr), ty)),
+            Ty::Box(ref inner) => match **inner {
+                Ty::Later(ref l, ref ty) if l == k =>
+                    Some((format!("(!(unbox {}))", e.expr), ty)),
+                _ => None,
+            },
+            _ => None,
+        };
+        if let Some((expr, ty)) = forced {
+            new_ctx.push(Entry { expr, ty: (**ty).clone(), access: Access::Forced });
+        }
+    }
+    new_ctx
+}
+
+const SAMPLES: &[&str] = &["0.0", "0.25", "0.5", "1.0", "2.0", "3.5"];
+
+pub struct Generator {
+    state: u64,
+    fresh: usize,
+    // whether audio is in scope, which it isn't in defs
+    clocks: bool,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Generator {
+        Generator { state: seed.wrapping_mul(0x9e3779b97f4a7c15) | 1, fresh: 0, clocks: false }
+    }
+
+    // xorshift, so that a seed always gives the same program
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    fn pick<'c, T>(&mut self, xs: &'c [T]) -> &'c T {
+        &xs[self.below(xs.len())]
+    }
+
+    fn name(&mut self, prefix: &str) -> String {
+        self.fresh += 1;
+        format!("{}{}", prefix, self.fresh)
+    }
+
+    // a few defs and lets, and then a main that uses them
+    pub fn program(&mut self) -> String {
+        let mut code = String::new();
+        let mut globals = Vec::new();
+        for _ in 0..self.below(3) {
+            let name = self.name("d");
+            let ty = self.ty(2);
+            let body = self.expr(&globals, &ty, 3);
+            code.push_str(&format!("def {}: {} =\n  {};;\n\n", name, ty, body));
+            globals.push(Entry { expr: name, ty, access: Access::Global });
+        }
+        let mut ctx = globals;
+        self.clocks = true;
+        for _ in 0..self.below(3) {
+            let name = self.name("l");
+            let ty = self.ty(2);
+            let body = self.expr(&ctx, &ty, 3);
+            code.push_str(&format!("let {}: {} =\n  {};;\n\n", name, ty, body));
+            ctx = with(&ctx, &name, ty);
+        }
+        let main_ty = Ty::Stream("audio".to_string(), Box::new(Ty::Sample));
+        let body = self.expr(&ctx, &main_ty, 4);
+        code.push_str(&format!("let main: ~^(audio) sample =\n  {};;\n", body));
+        code
+    }
+
+    fn ty(&mut self, depth: usize) -> Ty {
+        if depth == 0 {
+            return self.pick(&[Ty::Unit, Ty::Sample, Ty::Sample, Ty::Index]).clone();
+        }
+        let audio = "audio".to_string();
+        // the ones from 5 on mention audio
+        match self.below(if self.clocks { 10 } else { 5 }) {
+            0 => Ty::Product(Box::new(self.ty(depth - 1)), Box::new(self.ty(depth - 1))),
+            1 => Ty::Sum(Box::new(self.ty(depth - 1)), Box::new(self.ty(depth - 1))),
+            2 => Ty::Array(Box::new(self.ty(depth - 1)), 1 + self.below(3)),
+            3 => Ty::Box(Box::new(self.ty(depth - 1))),
+            4 => Ty::Function(Box::new(self.ty(depth - 1)), Box::new(self.ty(depth - 1))),
+            5 => Ty::Later(audio, Box::new(self.ty(depth - 1))),
+            6 => Ty::Stream(audio, Box::new(self.ty(depth - 1))),
+            7 => Ty::Exists(Box::new(self.ty(depth - 1))),
+            _ => self.ty(0),
+        }
+    }
+
+    // an expression of type ty. depth bounds how many more binders and
+    // operators get stacked up, but not how far ty gets taken apart
+    fn expr(&mut self, ctx: &Ctx, ty: &Ty, depth: usize) -> String {
+        let matching: Vec<&Entry> = ctx.iter().filter(|e| e.ty == *ty).collect();
+        if !matching.is_empty() && (depth == 0 || self.one_in(3)) {
+            return self.pick(&matching).expr.clone();
+        }
+        if depth > 0 && self.one_in(4) {
+            return self.elim(ctx, ty, depth - 1);
+        }
+        self.intro(ctx, ty, depth.saturating_sub(1))
+    }
+
+    // something that binds or branches, and then goes on to make a ty
+    fn elim(&mut self, ctx: &Ctx, ty: &Ty, depth: usize) -> String {
+        let usable: Vec<Entry> = ctx.iter()
+            .filter(|e| matches!(e.ty, Ty::Product(..) | Ty::Sum(..) | Ty::Box(_) | Ty::Stream(..) | Ty::Function(..) | Ty::Exists(_)))
+            .cloned()
+            .collect();
+        if usable.is_empty() || self.one_in(3) {
+            if self.one_in(2) {
+                let bound_ty = self.ty(1);
+                let x = self.name("x");
+                let bound = self.expr(ctx, &bound_ty, depth);
+                let body = self.expr(&with(ctx, &x, bound_ty.clone()), ty, depth);
+                return format!("(let {}: {} = {} in {})", x, bound_ty, bound, body);
+            }
+            let (x, y) = (self.expr(ctx, &Ty::Sample, depth), self.expr(ctx, &Ty::Sample, depth));
+            let op = *self.pick(&["<", "<=", ">", ">=", "==", "!="]);
+            let z = self.name("z");
+            let ctx = with(ctx, &z, Ty::Unit);
+            let (e1, e2) = (self.expr(&ctx, ty, depth), self.expr(&ctx, ty, depth));
+            return format!("(case {} {} {} {{ inl {} => {} | inr {} => {} }})", x, op, y, z, e1, z, e2);
+        }
+        let e = self.pick(&usable).clone();
+        match e.ty {
+            Ty::Product(ty1, ty2) => {
+                let (x1, x2) = (self.name("x"), self.name("x"));
+                let body = self.expr(&with(&with(ctx, &x1, *ty1), &x2, *ty2), ty, depth);
+                format!("(let ({}, {}) = {} in {})", x1, x2, e.expr, body)
+            },
+            Ty::Sum(ty1, ty2) => {
+                let (x1, x2) = (self.name("x"), self.name("x"));
+                let e1 = self.expr(&with(ctx, &x1, *ty1), ty, depth);
+                let e2 = self.expr(&with(ctx, &x2, *ty2), ty, depth);
+                format!("(case {} {{ inl {} => {} | inr {} => {} }})", e.expr, x1, e1, x2, e2)
+            },
+            Ty::Box(inner) => {
+                let x = self.name("x");
+                let body = self.expr(&with(ctx, &x, *inner), ty, depth);
+                format!("(let {} = unbox {} in {})", x, e.expr, body)
+            },
+            Ty::Stream(k, inner) => {
+                let (x, xs) = (self.name("x"), self.name("xs"));
+                let tail_ty = Ty::Later(k.clone(), Box::new(Ty::Stream(k, inner.clone())));
+                let body = self.expr(&with(&with(ctx, &x, *inner), &xs, tail_ty), ty, depth);
+                format!("(let ({}, {}) = %{} in {})", x, xs, e.expr, body)
+            },
+            Ty::Function(ty1, ty2) => {
+                let x = self.name("x");
+                let arg = self.expr(ctx, &ty1, depth);
+                let body = self.expr(&with(ctx, &x, (*ty2).clone()), ty, depth);
+                format!("(let {}: {} = {} ({}) in {})", x, ty2, e.expr, arg, body)
+            },
+            Ty::Exists(inner) => {
+                let (k, x) = (self.name("k"), self.name("x"));
+                let body = self.expr(&with(ctx, &x, Ty::Stream(k.clone(), inner)), ty, depth);
+                format!("(let clock {} and {} = {} in {})", k, x, e.expr, body)
+            },
+            _ => unreachable!(),
+        }
+    }
+
+    fn intro(&mut self, ctx: &Ctx, ty: &Ty, depth: usize) -> String {
+        match *ty {
+            Ty::Unit =>
+                "()".to_string(),
+            Ty::Sample => match if depth == 0 { 0 } else { self.below(5) } {
+                0 | 1 =>
+                    self.pick(SAMPLES).to_string(),
+                2 => {
+                    let op = *self.pick(&["+", "-", "*"]);
+                    let (x, y) = (self.expr(ctx, ty, depth), self.expr(ctx, ty, depth));
+                    format!("({} {} {})", x, op, y)
+                },
+                3 => {
+                    let f = *self.pick(&["sin", "cos"]);
+                    format!("({} {})", f, self.expr(ctx, ty, depth))
+                },
+                _ =>
+                    format!("(cast {})", self.expr(ctx, &Ty::Index, depth)),
+            },
+            Ty::Index => match if depth == 0 { 0 } else { self.below(4) } {
+                0 | 1 =>
+                    self.below(100).to_string(),
+                2 => {
+                    let op = *self.pick(&[".+.", ".-.", ".*.", ".^.", ".&.", ".|."]);
+                    let (x, y) = (self.expr(ctx, ty, depth), self.expr(ctx, ty, depth));
+                    format!("({} {} {})", x, op, y)
+                },
+                _ =>
+                    format!("(reinterpf {})", self.expr(ctx, &Ty::Sample, depth)),
+            },
+            Ty::Product(ref ty1, ref ty2) =>
+                format!("({}, {})", self.expr(ctx, ty1, depth), self.expr(ctx, ty2, depth)),
+            Ty::Sum(ref ty1, ref ty2) =>
+                if self.one_in(2) {
+                    format!("(inl {})", self.expr(ctx, ty1, depth))
+                } else {
+                    format!("(inr {})", self.expr(ctx, ty2, depth))
+                },
+            Ty::Array(ref ty, n) => {
+                let es: Vec<String> = (0..n).map(|_| self.expr(ctx, ty, depth)).collect();
+                format!("[{}]", es.join(", "))
+            },
+            Ty::Box(ref ty) =>
+                format!("(box {})", self.expr(&boxed(ctx), ty, depth)),
+            Ty::Later(ref k, ref ty) =>
+                format!("`({})", self.expr(&delayed(ctx, k), ty, depth)),
+            Ty::Stream(ref k, ref elem_ty) =>
+                self.stream(ctx, k, elem_ty, depth),
+            Ty::Function(ref ty1, ref ty2) => {
+                let x = self.name("x");
+                format!("(\\{}. {})", x, self.expr(&with(ctx, &x, (**ty1).clone()), ty2, depth))
+            },
+            Ty::Exists(ref elem_ty) => {
+                let packable: Vec<Entry> = ctx.iter()
+                    .filter(|e| matches!(e.ty, Ty::Stream(ref k, ref t) if k != "audio" && **t == **elem_ty))
+                    .cloned()
+                    .collect();
+                if !packable.is_empty() && self.one_in(2) {
+                    let e = self.pick(&packable);
+                    let Ty::Stream(ref k, _) = e.ty else { unreachable!() };
+                    return format!("(clock {} and {})", k, e.expr);
+                }
+                let stream_ty = Ty::Stream("audio".to_string(), elem_ty.clone());
+                format!("(clock audio and {})", self.expr(ctx, &stream_ty, depth))
+            },
+        }
+    }
+
+    fn stream(&mut self, ctx: &Ctx, k: &str, elem_ty: &Ty, depth: usize) -> String {
+        let ty = Ty::Stream(k.to_string(), Box::new(elem_ty.clone()));
+        let later_ty = Ty::Later(k.to_string(), Box::new(ty.clone()));
+        let r = self.name("r");
+        let sources: Vec<Entry> = ctx.iter().filter(|e| matches!(e.ty, Ty::Stream(ref l, _) if l == k)).cloned().collect();
+        match if depth == 0 { 0 } else { self.below(5) } {
+            // the same thing forever
+            0 => {
+                let lob_ctx = with(&boxed(ctx), &r, Ty::Box(Box::new(later_ty)));
+                let head = self.expr(&lob_ctx, elem_ty, depth);
+                format!("(&^({}) {}. ({}) :: `(!(unbox {})))", k, r, head, r)
+            },
+            // a head in front of some later stream
+            1 => {
+                let head = self.expr(ctx, elem_ty, depth);
+                let tail = self.expr(ctx, &later_ty, depth);
+                format!("(({}) :: {})", head, tail)
+            },
+            // a map over a stream that's already around
+            2 if !sources.is_empty() => {
+                let source = self.pick(&sources).clone();
+                let Ty::Stream(_, ref source_elem_ty) = source.ty else { unreachable!() };
+                let (s, x, xs) = (self.name("s"), self.name("x"), self.name("xs"));
+                let fn_ty = Ty::Function(Box::new(source.ty.clone()), Box::new(ty.clone()));
+                let lob_ctx = with(&boxed(ctx), &r, Ty::Box(Box::new(Ty::Later(k.to_string(), Box::new(fn_ty.clone())))));
+                let head_ctx = with(&with(&lob_ctx, &x, (**source_elem_ty).clone()), &xs, Ty::Later(k.to_string(), Box::new(source.ty.clone())));
+                let head = self.expr(&head_ctx, elem_ty, depth);
+                format!("(((&^({}) {}. \\{}. let ({}, {}) = %{} in ({}) :: `(!(unbox {}) !{})) : {}) ({}))",
+                        k, r, s, x, xs, s, head, r, xs, fn_ty, source.expr)
+            },
+            // a stream with some state carried along
+            3 => {
+                let state_ty = self.pick(&[Ty::Sample, Ty::Index]).clone();
+                let acc = self.name("acc");
+                let fn_ty = Ty::Function(Box::new(state_ty.clone()), Box::new(ty.clone()));
+                let lob_ctx = with(&boxed(ctx), &r, Ty::Box(Box::new(Ty::Later(k.to_string(), Box::new(fn_ty.clone())))));
+                let body_ctx = with(&lob_ctx, &acc, state_ty.clone());
+                let head = self.expr(&body_ctx, elem_ty, depth);
+                let next = self.expr(&delayed(&body_ctx, k), &state_ty, depth);
+                let init = self.expr(ctx, &state_ty, depth);
+                format!("(((&^({}) {}. \\{}. ({}) :: `(!(unbox {}) ({}))) : {}) ({}))",
+                        k, r, acc, head, r, next, fn_ty, init)
+            },
+            // whatever else the body of a lob makes of it
+            _ => {
+                let lob_ctx = with(&boxed(ctx), &r, Ty::Box(Box::new(later_ty)));
+                format!("(&^({}) {}. {})", k, r, self.expr(&lob_ctx, &ty, depth))
+            },
+        }
+    }
+}
@@ -1,15 +1,26 @@
+mod generate;
+
 use std::fs::{self, File};
 use std::io::Read;
 #[cfg(feature = "run")]
 use std::io;
 
-use clocky::toplevel::{compile, TopLevel};
+use clocky::interp;
+use clocky::ir1::{self, ConstValue, Global, Op, Value};
+use clocky::toplevel::{compile, format, interpret, parse_and_check, run_rewrites, Stage, TopLevel, TopLevelError};
+use clocky::lsp::Analysis;
+use clocky::repl::Repl;
+use clocky::session::Session;
+use clocky::typing::TopLevelTypeError;
 #[cfg(feature = "run")]
-use clocky::toplevel::run;
+use clocky::toplevel::{run, run_measuring_memory};
 #[cfg(feature = "run")]
-use hound::{Error::IoError, WavReader};
+use hound::Error::IoError;
+use hound::WavReader;
 use typed_arena::Arena;
 
+use generate::Generator;
+
 #[test]
 fn test_accepts() {
     for test_file in fs::read_dir("tests/accept").unwrap() {
@@ -42,3 +53,1008 @@ fn test_accepts() {
         drop(wasm_bytes);
     }
 }
+
+#[test]
+fn test_hole_reports_context() {
+    let code = "def foo: for k : clock. ~^(k) sample -> |>^(k) sample =\n  \\s. let (x, sp) = %s in `(_here);;\n".to_string();
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let parsed_file = toplevel.make_parser().parse_file(&code).unwrap();
+    let errs = toplevel.make_typechecker().check_file(&parsed_file).unwrap_err();
+    let msg = errs.pretty(&toplevel.interner, &code).to_string();
+    assert!(msg.contains("found hole \"_here\" of type \"sample\""), "{}", msg);
+    assert!(msg.contains("  x: sample\n"), "{}", msg);
+    assert!(msg.contains("  sp: |>^(k) ~^(k) sample (inaccessible: bound before a tick on k"), "{}", msg);
+}
+
+fn type_error_for(path: &str) -> String {
+    let code = fs::read_to_string(path).unwrap();
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let parsed_file = toplevel.make_parser().parse_file(&code).unwrap();
+    let errs = toplevel.make_typechecker().check_file(&parsed_file).unwrap_err();
+    errs.pretty(&toplevel.interner, &code).to_string()
+}
+
+#[test]
+fn test_inaccessible_variables_explained() {
+    let msg = type_error_for("tests/reject/hidden_by_force.cky");
+    assert!(msg.contains("variable \"w\" is in scope but was bound after a tick on clock \"k\""), "{}", msg);
+    let msg = type_error_for("tests/reject/hidden_by_box.cky");
+    assert!(msg.contains("variable \"s\" is in scope but can't be used under a box or lob"), "{}", msg);
+    let msg = type_error_for("tests/reject/timing_bad.cky");
+    assert!(msg.contains("variable \"sp\" is in scope but was bound before a tick on clock \"k\""), "{}", msg);
+    assert!(msg.contains("force it with `!`"), "{}", msg);
+}
+
+#[test]
+fn test_forcing_needs_enough_tick() {
+    let msg = type_error_for("tests/reject/force_too_early.cky");
+    assert!(msg.contains("a tick on \"2 k\" is still missing"), "{}", msg);
+    for path in ["tests/reject/force_nested_too_early.cky", "tests/reject/force_other_clock.cky"] {
+        let msg = type_error_for(path);
+        assert!(msg.contains("there is not enough tick for clock \"k\""), "{}", msg);
+    }
+}
+
+// each file in tests/reject starts with comment lines saying how it
+// should fail, e.g.
+//
+//   -- error: VariableTimingBad at 4:12
+//   -- parse error: BadLiteral at 1:20
+//
+// where "error" means a type error, and lines and columns count from
+// 1 (the "at" part can be left off). every error reported has to be
+// one of these, and each of these has to be reported. a type error
+// counts as any of the errors in its chain of causes.
+#[derive(Debug, PartialEq)]
+struct ExpectedError {
+    stage: &'static str,
+    variant: String,
+    at: Option<(usize, usize)>,
+}
+
+fn expected_errors(code: &str) -> Vec<ExpectedError> {
+    let mut expected = Vec::new();
+    for line in code.lines() {
+        let (stage, rest) = if let Some(rest) = line.strip_prefix("-- error: ") {
+            ("type", rest)
+        } else if let Some(rest) = line.strip_prefix("-- parse error: ") {
+            ("parse", rest)
+        } else {
+            continue;
+        };
+        let (variant, at) = match rest.split_once(" at ") {
+            Some((variant, at)) => {
+                let (l, c) = at.trim().split_once(':').expect("position should be line:column");
+                (variant, Some((l.parse().unwrap(), c.parse().unwrap())))
+            },
+            None => (rest, None),
+        };
+        expected.push(ExpectedError { stage, variant: variant.trim().to_string(), at });
+    }
+    expected
+}
+
+fn variant_name<T: std::fmt::Debug>(x: &T) -> String {
+    let debug = format!("{:?}", x);
+    debug[..debug.find(|c: char| !c.is_alphanumeric()).unwrap_or(debug.len())].to_string()
+}
+
+fn position(range: &tree_sitter::Range) -> (usize, usize) {
+    (range.start_point.row + 1, range.start_point.column + 1)
+}
+
+type ErrorChain = Vec<(String, Option<(usize, usize)>)>;
+
+// for each error reported, everything it could be matched against
+fn actual_errors(code: &str) -> (&'static str, Vec<ErrorChain>) {
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let parsed_file = match toplevel.make_parser().parse_file(code) {
+        Ok(parsed_file) => parsed_file,
+        Err(err) =>
+            return ("parse", err.errors.iter().map(|err| vec![(variant_name(err), Some(position(&err.range())))]).collect()),
+    };
+    let errs = match toplevel.make_typechecker().check_file(&parsed_file) {
+        Ok(_) => return ("nothing", Vec::new()),
+        Err(errs) => errs,
+    };
+    let actual = errs.errs.iter().map(|err| match *err {
+        TopLevelTypeError::TypeError(_, ref err) => {
+            let mut chain = Vec::new();
+            let mut cur = Some(err);
+            while let Some(err) = cur {
+                chain.push((variant_name(err), err.range().map(position)));
+                cur = err.source_type_error();
+            }
+            chain
+        },
+        TopLevelTypeError::CannotRedefine(_, ref range) =>
+            vec![(variant_name(err), Some(position(range)))],
+        TopLevelTypeError::InvalidType(..) =>
+            vec![(variant_name(err), None)],
+    }).collect();
+    ("type", actual)
+}
+
+#[test]
+fn test_rejects() {
+    for test_file in fs::read_dir("tests/reject").unwrap() {
+        let test_file_path = test_file.unwrap().path();
+        if !matches!(test_file_path.extension(), Some(ext) if ext == "cky") {
+            continue;
+        }
+        println!("testing file {:?}", test_file_path);
+        let code = fs::read_to_string(&test_file_path).unwrap();
+        let expected = expected_errors(&code);
+        assert!(!expected.is_empty(), "{:?} doesn't say what errors to expect", test_file_path);
+        let (stage, actual) = actual_errors(&code);
+        assert!(stage != "nothing", "{:?} was accepted", test_file_path);
+        for exp in expected.iter() {
+            assert_eq!(exp.stage, stage, "{:?} was expected to fail at {} but failed at {}", test_file_path, exp.stage, stage);
+        }
+        let matches = |exp: &ExpectedError, &(ref variant, at): &(String, Option<(usize, usize)>)|
+            exp.variant == *variant && (exp.at.is_none() || exp.at == at);
+        for exp in expected.iter() {
+            assert!(actual.iter().any(|chain| chain.iter().any(|err| matches(exp, err))),
+                    "{:?}: expected {:?}, but got {:?}", test_file_path, exp, actual);
+        }
+        for chain in actual.iter() {
+            assert!(expected.iter().any(|exp| chain.iter().any(|err| matches(exp, err))),
+                    "{:?}: unexpected error {:?}", test_file_path, chain);
+        }
+    }
+}
+
+#[test]
+fn test_parse_errors_dont_stop_typechecking() {
+    let code = "def a: sample = 1.0 +;;\ndef b: sample = a + 2.0;;\ndef c: sample = (2.0;;\ndef d: sample = foo;;\n".to_string();
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let Err(TopLevelError::ParseError(_, parse_err, Some(type_errs))) = parse_and_check(&mut toplevel, code) else {
+        panic!("expected parse and type errors");
+    };
+    assert_eq!(parse_err.errors.len(), 2, "{:?}", parse_err.errors);
+    assert_eq!(parse_err.partial.defs.len(), 2);
+    // b only fails because a didn't parse, so only d's error is left
+    assert_eq!(type_errs.errs.len(), 1, "{:?}", type_errs.errs);
+}
+
+#[test]
+fn test_rendered_diagnostics() {
+    let code = fs::read_to_string("tests/reject/timing_bad.cky").unwrap();
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let err = compile(&mut toplevel, code).unwrap_err();
+    let rendered = err.pretty(&toplevel.interner, "timing_bad.cky").to_string();
+    assert!(rendered.starts_with("error: variable \"sp\" is in scope"), "{}", rendered);
+    assert!(rendered.contains(" --> timing_bad.cky:3:33\n"), "{}", rendered);
+    assert!(rendered.contains("3 |   \\s. let (x, sp) = %s in `(x + sp);;\n"), "{}", rendered);
+    assert!(rendered.contains("  |                                 ^^\n"), "{}", rendered);
+    assert!(rendered.contains("  = note: in definition of \"h\"\n"), "{}", rendered);
+
+    let code = fs::read_to_string("tests/reject/syntax_errors.cky").unwrap();
+    let err = compile(&mut toplevel, code).unwrap_err();
+    let rendered = err.pretty(&toplevel.interner, "syntax_errors.cky").to_string();
+    assert!(rendered.contains("error: missing \")\"\n --> syntax_errors.cky:8:21\n"), "{}", rendered);
+}
+
+#[test]
+fn test_json_diagnostics() {
+    let code = fs::read_to_string("tests/reject/timing_bad.cky").unwrap();
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let err = compile(&mut toplevel, code).unwrap_err();
+    let json = err.json(&toplevel.interner, "timing_bad.cky").to_string();
+    assert_eq!(json.lines().count(), 1, "{}", json);
+    assert!(json.starts_with("{\"file\":\"timing_bad.cky\",\"severity\":\"error\",\"code\":\"E0215\",\"message\":\"variable \\\"sp\\\" is in scope"), "{}", json);
+    assert!(json.contains("\"primary\":{\"byte_start\":"), "{}", json);
+    assert!(json.contains("\"line_start\":2,\"column_start\":32,"), "{}", json);
+    assert!(json.contains("\"notes\":[\"in definition of \\\"h\\\"\"]"), "{}", json);
+    assert!(json.contains("\"types\":[\""), "{}", json);
+
+    let code = fs::read_to_string("tests/reject/syntax_errors.cky").unwrap();
+    let err = compile(&mut toplevel, code).unwrap_err();
+    let json = err.json(&toplevel.interner, "syntax_errors.cky").to_string();
+    assert!(json.lines().all(|line| line.starts_with('{') && line.ends_with('}')), "{}", json);
+    assert!(json.contains("\"code\":\"E0108\",\"message\":\"missing \\\")\\\"\""), "{}", json);
+}
+
+#[test]
+fn test_lsp_analysis() {
+    let code = fs::read_to_string("tests/accept/map.cky").unwrap();
+    let analysis = Analysis::new(&code);
+    assert!(analysis.diagnostics.is_empty());
+
+    let x = code.find("sin x").unwrap() + 4;
+    let (range, ty) = analysis.type_at(x).unwrap();
+    assert_eq!(range, x .. x + 1);
+    assert_eq!(ty, "sample");
+
+    let use_of_mapsig = code.rfind("mapsig").unwrap() + 2;
+    let def_range = analysis.definition_at(&code, use_of_mapsig).unwrap();
+    assert!(code[def_range].starts_with("def mapsig:"));
+    let def_names: Vec<_> = analysis.def_names().collect();
+    assert_eq!(def_names, ["mapsig", "lin", "main"]);
+
+    let code = fs::read_to_string("tests/reject/timing_bad.cky").unwrap();
+    assert_eq!(Analysis::new(&code).diagnostics.len(), 1);
+}
+
+#[test]
+fn test_session_rechecks_only_what_changed() {
+    let code = fs::read_to_string("tests/accept/map.cky").unwrap();
+    let arena = Arena::new();
+    let mut session = Session::new(&arena, code);
+    let names = |session: &Session| -> Vec<String> {
+        session.rechecked().iter().map(|&name| session.toplevel.interner.resolve(name).unwrap().to_string()).collect()
+    };
+
+    session.check().unwrap();
+    assert_eq!(names(&session), ["mapsig", "lin", "main"]);
+
+    // main uses lin, but only its signature matters
+    let one = session.text().find("1.0").unwrap();
+    session.edit(one .. one + 3, "2.0");
+    session.check().unwrap();
+    assert_eq!(names(&session), ["lin"]);
+
+    // moving everything down doesn't need any rechecking
+    session.edit(0 .. 0, "\n\n");
+    session.check().unwrap();
+    assert!(names(&session).is_empty());
+
+    // but changing a signature rechecks what uses it
+    let sig = session.text().find("def lin: for k : clock. ~^(k) sample").unwrap();
+    session.edit(sig .. sig + "def lin: for k : clock. ~^(k) sample".len(), "def lin: for k : clock. ~^(k) index");
+    let err = session.check().unwrap_err();
+    assert_eq!(names(&session), ["lin", "main"]);
+    assert_eq!(err.diagnostics(&session.toplevel.interner).len(), 2);
+}
+
+#[test]
+fn test_session_moves_errors_along() {
+    let code = fs::read_to_string("tests/reject/timing_bad.cky").unwrap();
+    let arena = Arena::new();
+    let mut session = Session::new(&arena, code);
+    session.check().unwrap_err();
+
+    session.edit(0 .. 0, "\n");
+    let err = session.check().unwrap_err();
+    assert!(session.rechecked().is_empty());
+    let rendered = err.pretty(&session.toplevel.interner, "timing_bad.cky").to_string();
+    assert!(rendered.contains(" --> timing_bad.cky:4:33\n"), "{}", rendered);
+    assert!(rendered.contains("4 |   \\s. let (x, sp) = %s in `(x + sp);;\n"), "{}", rendered);
+}
+
+fn parse_dump(code: &str) -> String {
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let parsed_file = toplevel.make_parser().parse_file(code).unwrap();
+    parsed_file.pretty(&toplevel.interner).to_string()
+}
+
+fn formatted(code: &str) -> String {
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    format(&mut toplevel, code.to_string()).unwrap()
+}
+
+#[test]
+fn test_format_round_trips() {
+    for test_file in fs::read_dir("tests/accept").unwrap() {
+        let test_file_path = test_file.unwrap().path();
+        if !matches!(test_file_path.extension(), Some(ext) if ext == "cky") {
+            continue;
+        }
+        let code = fs::read_to_string(&test_file_path).unwrap();
+        let once = formatted(&code);
+        assert_eq!(parse_dump(&code), parse_dump(&once), "{:?} changed meaning:\n{}", test_file_path, once);
+        assert_eq!(once, formatted(&once), "{:?} isn't stable", test_file_path);
+        for line in code.lines().filter(|line| line.trim_start().starts_with("--")) {
+            assert!(once.contains(line.trim()), "{:?} lost comment {:?}:\n{}", test_file_path, line, once);
+        }
+    }
+}
+
+#[test]
+fn test_format_layout() {
+    let code = "def f : for k : clock. ~^(k) sample -> ~^(k) sample = \\s. let (x, sp) = %s in\n  -- keep going\n  (x :: `(g !sp) : ~^(k) sample);; -- done\n";
+    assert_eq!(formatted(code), "\
+def f: for k : clock. ~^(k) sample -> ~^(k) sample =
+  \\s.
+    let (x, sp) = %s in
+    -- keep going
+    x :: (`(g !sp) : ~^(k) sample);; -- done
+");
+    assert_eq!(formatted("def f: sample = (f x) @(k) (g @(k) y) (!(unbox s));;"), "def f: sample = (f x) @(k) (g @(k) y) !(unbox s);;\n");
+}
+
+#[test]
+fn test_repl() {
+    let arena = Arena::new();
+    let mut repl = Repl::new(&arena);
+    assert!(!Repl::is_complete("def lin: for k : clock. ~^(k) sample =\n"));
+    let lin = "def lin: for k : clock. ~^(k) sample =\n  ((&^(k) s. \\x. x :: `(!(unbox s) (x + 1.0))) : sample -> ~^(k) sample) 0.0;;\n";
+    assert!(Repl::is_complete(lin));
+    assert_eq!(repl.eval(lin).unwrap(), "lin: for k : clock. ~^(k) sample\n");
+    assert_eq!(repl.eval("let x: sample = 2.0;;").unwrap(), "x: sample\n");
+    assert_eq!(repl.eval(":t lin @(audio)").unwrap(), "~^(audio) sample\n");
+    assert_eq!(repl.eval(":t x").unwrap(), "sample\n");
+
+    // declarations that don't check don't stick around
+    let err = repl.eval("def bad: sample = lin;;").unwrap_err();
+    assert!(err.contains(" --> <repl>:"), "{}", err);
+    repl.eval(":t bad").unwrap_err();
+    assert_eq!(repl.eval("def bad: sample = 1.0;;").unwrap(), "bad: sample\n");
+}
+
+#[test]
+fn test_repl_take() {
+    let arena = Arena::new();
+    let mut repl = Repl::new(&arena);
+    repl.eval("def lin: for k : clock. ~^(k) sample =\n  ((&^(k) s. \\x. x :: `(!(unbox s) (x + 1.0))) : sample -> ~^(k) sample) 0.0;;").unwrap();
+    assert_eq!(repl.eval(":take 4 lin @(audio)").unwrap(), "0 1 2 3\n");
+    // and everything declared is still there afterwards
+    assert_eq!(repl.eval(":t lin @(audio)").unwrap(), "~^(audio) sample\n");
+}
+
+#[test]
+fn test_interpreter_matches_wavs() {
+    for test_file in fs::read_dir("tests/accept").unwrap() {
+        let test_file_path = test_file.unwrap().path();
+        if !matches!(test_file_path.extension(), Some(ext) if ext == "cky") {
+            continue;
+        }
+        let wav_file = match WavReader::open(test_file_path.with_extension("wav")) {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+        let code = fs::read_to_string(&test_file_path).unwrap();
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        let interpreted = interpret(&mut toplevel, code, 48000 * 2).unwrap();
+        let expected_samples = wav_file.into_samples().collect::<Result<Vec<f32>, _>>().unwrap();
+        assert_eq!(interpreted.len(), expected_samples.len());
+        // sin is the host's rather than the runtime's, so it can be
+        // off in the last bit
+        for (i, (x, y)) in interpreted.iter().zip(expected_samples.iter()).enumerate() {
+            assert!((x - y).abs() <= 1e-6, "{:?} differs at sample {}: {} vs {}", test_file_path, i, x, y);
+        }
+    }
+}
+
+#[test]
+fn test_interpreter_cases() {
+    let code = "\
+def count: for k : clock. index -> ~^(k) index =
+  &^(k) s. \\i. i :: `(!(unbox s) (i .+. 1));;
+
+let main: ~^(audio) sample =
+  ((&^(audio) s. \\c.
+      let (i, cp) = %c in
+      (case i .>. 1 { inl u => 1.0 | inr u => 0.0 }) :: `(!(unbox s) !cp))
+   : ~^(audio) index -> ~^(audio) sample) (count @(audio) 0);;
+";
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    assert_eq!(interpret(&mut toplevel, code.to_string(), 5).unwrap(), [0.0, 0.0, 1.0, 1.0, 1.0]);
+    toplevel.reset_globals();
+    let err = interpret(&mut toplevel, "let main: sample = 1.0;;".to_string(), 5).unwrap_err();
+    assert!(matches!(err, TopLevelError::CannotSample(_)), "{}", err);
+    toplevel.reset_globals();
+    let err = interpret(&mut toplevel, "let notmain: sample = 1.0;;".to_string(), 5).unwrap_err();
+    assert!(matches!(err, TopLevelError::NoMain(ref name) if name == "main"), "{}", err);
+    toplevel.reset_globals();
+    let err = compile(&mut toplevel, "let notmain: sample = 1.0;;".to_string()).unwrap_err();
+    assert!(matches!(err, TopLevelError::NoMain(ref name) if name == "main"), "{}", err);
+}
+
+// the thunks a stream's done with get reused, so the interpreter's
+// heap stays the same size however long it runs
+#[test]
+fn test_interpreter_collects_thunks() {
+    let code = "\
+def count: for k : clock. index -> ~^(k) index =
+  &^(k) s. \\i. i :: `(!(unbox s) (i .+. 1));;
+
+let main: ~^(audio) sample =
+  ((&^(audio) s. \\c.
+      let (i, cp) = %c in
+      (case i .>. 2 { inl u => 1.0 | inr u => 0.0 }) :: `(!(unbox s) !cp))
+   : ~^(audio) index -> ~^(audio) sample) (count @(audio) 0);;
+";
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let file = parse_and_check(&mut toplevel, code.to_string()).unwrap();
+    let main = toplevel.interner.get_or_intern("main");
+    let heap_size_after = |num_samples| {
+        let mut interpreter = interp::Interpreter::new(&toplevel.builtins, &toplevel.global_clocks);
+        interpreter.load(&file);
+        let stream = interpreter.global(main).unwrap().clone();
+        let samples = interpreter.sample(stream, num_samples);
+        assert_eq!(samples[..4], [0.0, 0.0, 0.0, 1.0]);
+        assert!(samples[4..].iter().all(|&x| x == 1.0));
+        interpreter.heap_size()
+    };
+    let short = heap_size_after(48000);
+    let long = heap_size_after(48000 * 4);
+    assert!(long <= short, "the heap went from {} thunks to {}", short, long);
+}
+
+// everything should sound the same with and without the egglog pass
+#[test]
+fn test_optimizing_preserves_output() {
+    for test_file in fs::read_dir("tests/accept").unwrap() {
+        let test_file_path = test_file.unwrap().path();
+        if !matches!(test_file_path.extension(), Some(ext) if ext == "cky") {
+            continue;
+        }
+        let code = fs::read_to_string(&test_file_path).unwrap();
+        let compile_with = |optimize: bool| {
+            let arena = Arena::new();
+            let mut toplevel = TopLevel::new(&arena);
+            toplevel.optimize = optimize;
+            compile(&mut toplevel, code.clone()).unwrap()
+        };
+        let optimized = compile_with(true);
+        let unoptimized = compile_with(false);
+        #[cfg(feature = "run")]
+        {
+            let optimized_samples = run(&optimized, 48000);
+            let unoptimized_samples = run(&unoptimized, 48000);
+            let differing = optimized_samples.iter().zip(unoptimized_samples.iter())
+                .position(|(x, y)| x.to_bits() != y.to_bits());
+            if let Some(i) = differing {
+                panic!("{:?}: optimizing changed sample {} from {} to {}",
+                       test_file_path, i, unoptimized_samples[i], optimized_samples[i]);
+            }
+        }
+        drop((optimized, unoptimized));
+    }
+}
+
+// the egglog pass should fold the constants away, and running it until
+// it stops changing anything should give the same result too
+#[test]
+fn test_optimizing_simplifies() {
+    let code = "\
+let main: ~^(audio) sample =
+  &^(audio) s. (2.0 * 3.0 + (case 1.0 > 0.5 { inl u => 0.25 | inr u => 0.0 }) * (1.0 / 4.0)) :: `!(unbox s);;
+";
+    let compile_with = |optimize: bool, opt_iterations: Option<usize>| {
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        toplevel.optimize = optimize;
+        toplevel.opt_iterations = opt_iterations;
+        compile(&mut toplevel, code.to_string()).unwrap()
+    };
+    let unoptimized = compile_with(false, Some(8));
+    let optimized = compile_with(true, Some(8));
+    let saturated = compile_with(true, None);
+    assert!(optimized.len() < unoptimized.len(), "optimizing went from {} bytes to {}", unoptimized.len(), optimized.len());
+    #[cfg(feature = "run")]
+    {
+        // run goes a whole chunk of 128 samples at a time
+        assert_eq!(run(&unoptimized, 128), [6.0625; 128]);
+        assert_eq!(run(&optimized, 128), [6.0625; 128]);
+        assert_eq!(run(&saturated, 128), [6.0625; 128]);
+    }
+    drop(saturated);
+}
+
+// whether the egglog pass makes the two ir1.egg terms equal
+fn rewrites_to(lhs: &str, rhs: &str) -> bool {
+    let mut egraph = egglog::EGraph::default();
+    egraph.parse_and_run_program(include_str!("../src/ir1.egg")).unwrap();
+    egraph.parse_and_run_program(&format!("(let lhs {})\n(let rhs {})", lhs, rhs)).unwrap();
+    run_rewrites(&mut egraph, Some(8)).unwrap();
+    egraph.parse_and_run_program("(check (= lhs rhs))").is_ok()
+}
+
+fn op(o: &str, args: &[&str]) -> String {
+    let list = args.iter().rev().fold("(ELNil)".to_string(), |tail, arg| format!("(ELCons {} {})", arg, tail));
+    format!("(Op ({}) {})", o, list)
+}
+
+fn sample(x: f64) -> String {
+    format!("(Val (Sample {:?}))", x)
+}
+
+fn index(i: u32) -> String {
+    format!("(Val (Index {}))", i)
+}
+
+#[test]
+fn test_rewrite_constant_folding() {
+    let (a, b) = (sample(3.0), sample(0.5));
+    let (i, j) = (index(7), index(2));
+    let folds = [
+        (op("Const (Sample 2.0)", &[]), sample(2.0)),
+        (op("FAdd", &[&a, &b]), sample(3.5)),
+        (op("FSub", &[&a, &b]), sample(2.5)),
+        (op("FMul", &[&a, &b]), sample(1.5)),
+        (op("FDiv", &[&a, &b]), sample(6.0)),
+        (op("FGt", &[&a, &b]), index(1)),
+        (op("FGe", &[&a, &b]), index(1)),
+        (op("FLt", &[&a, &b]), index(0)),
+        (op("FLe", &[&a, &b]), index(0)),
+        (op("FEq", &[&a, &b]), index(0)),
+        (op("FNe", &[&a, &b]), index(1)),
+        (op("FEq", &[&sample(0.0), &sample(-0.0)]), index(1)),
+        (op("Pi", &[]), sample(std::f32::consts::PI as f64)),
+        (op("CastI2F", &[&i]), sample(7.0)),
+        (op("IAdd", &[&i, &j]), index(9)),
+        (op("ISub", &[&i, &j]), index(5)),
+        (op("ISub", &[&j, &i]), index(2u32.wrapping_sub(7))),
+        (op("IMul", &[&i, &j]), index(14)),
+        (op("IMul", &[&index(65537), &index(65537)]), index(65537u32.wrapping_mul(65537))),
+        (op("IDiv", &[&i, &j]), index(3)),
+        (op("Shl", &[&i, &j]), index(28)),
+        (op("Shl", &[&i, &index(33)]), index(14)),
+        (op("Shr", &[&i, &j]), index(1)),
+        (op("And", &[&i, &j]), index(2)),
+        (op("Xor", &[&i, &j]), index(5)),
+        (op("Or", &[&i, &j]), index(7)),
+        (op("IGt", &[&i, &j]), index(1)),
+        (op("IGe", &[&i, &j]), index(1)),
+        (op("ILt", &[&i, &j]), index(0)),
+        (op("ILe", &[&i, &j]), index(0)),
+        (op("IEq", &[&i, &j]), index(0)),
+        (op("INe", &[&i, &j]), index(1)),
+    ];
+    for (lhs, rhs) in folds.iter() {
+        assert!(rewrites_to(lhs, rhs), "{} should fold to {}", lhs, rhs);
+    }
+    // 0.1 isn't an f32, sin needn't round like ours, and the rest trap
+    // or have nothing to fold to
+    let stays = [
+        (op("FAdd", &[&sample(0.1), &b]), sample(0.6)),
+        (op("Sin", &[&sample(0.0)]), sample(0.0)),
+        (op("IDiv", &[&i, &index(0)]), index(0)),
+        (op("ReinterpF2I", &[&sample(1.0)]), index(1.0f32.to_bits())),
+    ];
+    for (lhs, rhs) in stays.iter() {
+        assert!(!rewrites_to(lhs, rhs), "{} shouldn't fold to {}", lhs, rhs);
+    }
+}
+
+#[test]
+fn test_rewrite_identities() {
+    let x = "(Var 0)";
+    // calling something might do anything
+    let call = "(App (Var 1) (Var 0))";
+    let holds = [
+        (op("FMul", &[x, &sample(1.0)]), x.to_string()),
+        (op("FMul", &[&sample(1.0), x]), x.to_string()),
+        (op("FDiv", &[x, &sample(1.0)]), x.to_string()),
+        (op("IAdd", &[&index(0), x]), x.to_string()),
+        (op("ISub", &[x, &index(0)]), x.to_string()),
+        (op("IMul", &[x, &index(1)]), x.to_string()),
+        (op("IDiv", &[x, &index(1)]), x.to_string()),
+        (op("Shl", &[x, &index(0)]), x.to_string()),
+        (op("Shr", &[x, &index(0)]), x.to_string()),
+        (op("Or", &[x, &index(0)]), x.to_string()),
+        (op("Xor", &[&index(0), x]), x.to_string()),
+        (op("And", &[x, &index(u32::MAX)]), x.to_string()),
+        (op("IMul", &[x, &index(0)]), index(0)),
+        (op("And", &[&index(0), x]), index(0)),
+        (op("UnpackF32", &[&op("PackF32", &[x])]), x.to_string()),
+        (op("PackI32", &[&op("UnpackI32", &[x])]), x.to_string()),
+        (op("Proj 1", &[&format!("(Con (Pair) (ELCons {} (ELCons {} (ELNil))))", index(1), x)]), x.to_string()),
+        (format!("(If {} {} (Var 1))", index(1), x), x.to_string()),
+    ];
+    for (lhs, rhs) in holds.iter() {
+        assert!(rewrites_to(lhs, rhs), "{} should be {}", lhs, rhs);
+    }
+    let fails = [
+        // -0.0 + 0.0 is 0.0
+        (op("FAdd", &[x, &sample(0.0)]), x.to_string()),
+        (op("IMul", &[call, &index(0)]), index(0)),
+        (op("Proj 0", &[&format!("(Con (Pair) (ELCons {} (ELCons {} (ELNil))))", x, call)]), x.to_string()),
+    ];
+    for (lhs, rhs) in fails.iter() {
+        assert!(!rewrites_to(lhs, rhs), "{} shouldn't be {}", lhs, rhs);
+    }
+}
+
+#[test]
+fn test_rewrite_functions_and_lets() {
+    let call = "(App (Var 1) (Var 0))";
+    let x_plus_1 = op("FAdd", &["(Var 0)", &sample(1.0)]);
+    let holds = [
+        // (\y. y + x) 1.0, and under a binder, where x gets shifted
+        (format!("(App (Lam {}) {})", op("FAdd", &["(Var 0)", "(Var 1)"]), sample(1.0)), op("FAdd", &[&sample(1.0), "(Var 0)"])),
+        (format!("(App (Lam (Lam {})) (Var 0))", op("FAdd", &["(Var 0)", "(Var 1)"])), format!("(Lam {})", op("FAdd", &["(Var 0)", "(Var 1)"]))),
+        ("(Unbox (Box (Var 0)))".to_string(), "(Var 0)".to_string()),
+        (format!("(Adv (Delay {}))", call), call.to_string()),
+        ("(Delay (Adv (Var 0)))".to_string(), "(Var 0)".to_string()),
+        // used once, and copying variables and values
+        (format!("(Let {} {})", x_plus_1, op("FMul", &["(Var 0)", &sample(2.0)])), op("FMul", &[&x_plus_1, &sample(2.0)])),
+        (format!("(Let (Var 3) {})", op("FMul", &["(Var 0)", "(Var 0)"])), op("FMul", &["(Var 3)", "(Var 3)"])),
+        (format!("(Let {} {})", sample(2.0), op("FMul", &["(Var 0)", "(Var 0)"])), op("FMul", &[&sample(2.0), &sample(2.0)])),
+        // dead
+        (format!("(Let {} (Var 1))", x_plus_1), "(Var 0)".to_string()),
+        // a let of a variable can equal its own body, which mustn't
+        // send the analysis round in circles
+        (format!("(Lam (Let (Var 0) {}))", x_plus_1), format!("(Lam {})", x_plus_1)),
+    ];
+    for (lhs, rhs) in holds.iter() {
+        assert!(rewrites_to(lhs, rhs), "{} should be {}", lhs, rhs);
+    }
+    let fails = [
+        ("(Delay (Adv (App (Var 1) (Var 0))))".to_string(), call.to_string()),
+        // used twice, or under a lambda, or not pure
+        (format!("(Let {} {})", x_plus_1, op("FMul", &["(Var 0)", "(Var 0)"])), op("FMul", &[&x_plus_1, &x_plus_1])),
+        (format!("(Let {} (Lam (Var 1)))", x_plus_1), format!("(Lam {})", op("FAdd", &["(Var 1)", &sample(1.0)]))),
+        (format!("(Let {} (Var 0))", call), call.to_string()),
+        (format!("(Let {} (Var 1))", call), "(Var 0)".to_string()),
+    ];
+    for (lhs, rhs) in fails.iter() {
+        assert!(!rewrites_to(lhs, rhs), "{} shouldn't be {}", lhs, rhs);
+    }
+}
+
+// definitions like persamp get worked out at compile time, but not
+// where the compiled code might get something else
+#[test]
+fn test_const_eval() {
+    use ir1::Expr;
+    let consts = [(Global(0), Value::Sample(3.0))].into_iter().collect();
+    let persamp = Expr::Op(Op::UnpackF32, &[&Expr::Glob(Global(0))]);
+    let two = Expr::Val(Value::Sample(2.0));
+    let half = Expr::Op(Op::FDiv, &[&persamp, &two]);
+    let packed = Expr::Op(Op::PackF32, &[&half]);
+    assert_eq!(ir1::const_eval(&consts, &packed), Some(ConstValue::Packed(Value::Sample(1.5))));
+    // let x = 2.0 in x * x
+    let square = Expr::LetIn(&two, &Expr::Op(Op::FMul, &[&Expr::Var(ir1::DebruijnIndex(0)), &Expr::Var(ir1::DebruijnIndex(0))]));
+    assert_eq!(ir1::const_eval(&consts, &square), Some(ConstValue::Raw(Value::Sample(4.0))));
+    let sin = Expr::Op(Op::Sin, &[&two]);
+    assert_eq!(ir1::const_eval(&consts, &sin), None);
+    let zero = Expr::Val(Value::Index(0));
+    let div_by_zero = Expr::Op(Op::IDiv, &[&zero, &zero]);
+    assert_eq!(ir1::const_eval(&consts, &div_by_zero), None);
+    let unknown = Expr::Op(Op::UnpackF32, &[&Expr::Glob(Global(1))]);
+    assert_eq!(ir1::const_eval(&consts, &unknown), None);
+}
+
+// a let-bound sample only gets unpacked once, unless a closure needs
+// it packed
+#[test]
+fn test_unpack_lets() {
+    use ir1::{DebruijnIndex, Expr};
+    let under_arena = Arena::new();
+    let ptr_arena = Arena::new();
+    let arena = clocky::util::ArenaPlus { arena: &under_arena, ptr_arena: &ptr_arena };
+    let translator = ir1::Translator { globals: Default::default(), global_clocks: Default::default(), arena: &arena };
+    let x = Expr::Op(Op::UnpackF32, &[&Expr::Var(DebruijnIndex(0))]);
+    let sum = Expr::Op(Op::FAdd, &[&Expr::Val(Value::Sample(1.0)), &Expr::Val(Value::Sample(2.0))]);
+    let packed_sum = Expr::Op(Op::PackF32, &[&sum]);
+    // let x = 1.0 + 2.0 in x * x
+    let square = Expr::LetIn(&packed_sum, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::FMul, &[&x, &x])]));
+    let raw_x = Expr::Var(DebruijnIndex(0));
+    assert_eq!(
+        *translator.unpack_lets(&square),
+        Expr::LetIn(&sum, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::FMul, &[&raw_x, &raw_x])]))
+    );
+    // let x = 1.0 + 2.0 in \y. x
+    let captured = Expr::LetIn(&packed_sum, &Expr::Lam(None, 1, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::UnpackF32, &[&Expr::Var(DebruijnIndex(1))])])));
+    assert_eq!(*translator.unpack_lets(&captured), captured);
+    // \x. x * x unpacks x once on the way in
+    let param_square = Expr::Lam(None, 1, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::FMul, &[&x, &x])]));
+    assert_eq!(
+        *translator.unpack_lets(&param_square),
+        Expr::Lam(None, 1, &Expr::LetIn(&x, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::FMul, &[&raw_x, &raw_x])])))
+    );
+    // \x. \y. x has x captured, and y unused, so neither gets unpacked
+    let curried = Expr::Lam(None, 1, &Expr::Lam(None, 1, &Expr::Op(Op::PackF32, &[&Expr::Op(Op::UnpackF32, &[&Expr::Var(DebruijnIndex(1))])])));
+    assert_eq!(*translator.unpack_lets(&curried), curried);
+}
+
+// calls to builtins get inlined, and calls to lambda defs go straight
+// to their code, but only once they have all their arguments
+#[test]
+fn test_direct_calls() {
+    use clocky::ir2::{Expr, GlobalDef};
+    use ir1::DebruijnIndex;
+    let under_arena = Arena::new();
+    let ptr_arena = Arena::new();
+    let arena = clocky::util::ArenaPlus { arena: &under_arena, ptr_arena: &ptr_arena };
+    let x = arena.alloc(Expr::Op(Op::Const(Value::Sample(1.0)), &[]));
+    let sin = Expr::Op(Op::PackF32, &[&Expr::Op(Op::Sin, &[&Expr::Op(Op::UnpackF32, &[&Expr::Var(DebruijnIndex(0))])])]);
+    let call = |f, arg| arena.alloc(Expr::CallIndirect(f, arena.alloc_slice([arg])));
+    let load = |g| arena.alloc(Expr::Op(Op::LoadGlobal(Global(g)), &[]));
+    let applied_once = call(load(2), x);
+    let globals = vec![
+        GlobalDef::Func { rec: false, arity: 1, env_size: 0, body: &sin },
+        GlobalDef::Func { rec: false, arity: 2, env_size: 0, body: x },
+        GlobalDef::ClosedExpr { body: &Expr::Op(Op::BuildClosure(Global(1)), &[]) },
+        GlobalDef::ClosedExpr { body: call(load(0), x) },
+        GlobalDef::ClosedExpr { body: call(applied_once, x) },
+        GlobalDef::ClosedExpr { body: applied_once },
+    ];
+    let mut translator = clocky::ir2::Translator { arena: &arena, globals };
+    translator.direct_calls();
+    let body = |g: usize| match translator.globals[g] {
+        GlobalDef::ClosedExpr { body } => body,
+        GlobalDef::Func { .. } => unreachable!(),
+    };
+    assert!(matches!(*body(3), Expr::Let(&[_], &Expr::Op(Op::PackF32, _))), "{:?}", body(3));
+    assert!(matches!(*body(4), Expr::CallDirect(Global(1), &[_, _])), "{:?}", body(4));
+    assert!(matches!(*body(5), Expr::CallIndirect(_, &[_])), "{:?}", body(5));
+}
+
+// what main can't reach gets dropped, and only the partial
+// applications of what's left get generated
+#[test]
+fn test_pruning_and_partial_apps() {
+    use clocky::ir2::{self, Expr, GlobalDef};
+    let under_arena = Arena::new();
+    let ptr_arena = Arena::new();
+    let arena = clocky::util::ArenaPlus { arena: &under_arena, ptr_arena: &ptr_arena };
+    let x = arena.alloc(Expr::Var(ir1::DebruijnIndex(0)));
+    let partial_call = arena.alloc(Expr::CallIndirect(
+        arena.alloc(Expr::Op(Op::LoadGlobal(Global(1)), &[])),
+        arena.alloc_slice([arena.alloc(Expr::Op(Op::Const(Value::Unit), &[]))])
+    ));
+    let globals = vec![
+        GlobalDef::Func { rec: false, arity: 3, env_size: 0, body: x },
+        GlobalDef::ClosedExpr { body: arena.alloc(Expr::Op(Op::BuildClosure(Global(2)), &[])) },
+        GlobalDef::Func { rec: false, arity: 2, env_size: 0, body: x },
+        GlobalDef::ClosedExpr { body: partial_call },
+    ];
+    let mut translator = ir2::Translator { arena: &arena, globals };
+    assert_eq!(ir2::partial_apps_needed(&translator.globals).into_iter().collect::<Vec<_>>(), [(2, 1), (3, 1)]);
+    let new_indices = translator.prune_unreachable(&[3]);
+    assert_eq!(new_indices, [None, Some(0), Some(1), Some(2)]);
+    assert!(matches!(translator.globals[0], GlobalDef::ClosedExpr { body: &Expr::Op(Op::BuildClosure(Global(1)), &[]) }));
+    assert!(matches!(translator.globals[2], GlobalDef::ClosedExpr { body: &Expr::CallIndirect(&Expr::Op(Op::LoadGlobal(Global(0)), &[]), &[_]) }));
+    assert_eq!(ir2::partial_apps_needed(&translator.globals).into_iter().collect::<Vec<_>>(), [(2, 1)]);
+}
+
+// the backend says which global it couldn't make code for instead of
+// panicking, and an invalid module doesn't get out
+#[test]
+fn test_backend_errors() {
+    use clocky::ir2::{Expr, GlobalDef};
+    use clocky::wasm::{self, BackendErrorKind};
+    let bad_op = [
+        GlobalDef::ClosedExpr { body: &Expr::Op(Op::Const(Value::Unit), &[]) },
+        GlobalDef::ClosedExpr { body: &Expr::Op(Op::Proj(0), &[]) },
+    ];
+    let err = wasm::translate(&bad_op, 2, &[], 0).unwrap_err();
+    assert_eq!(err.global, Some(1));
+    assert!(matches!(err.kind, BackendErrorKind::BadOp(Op::Proj(0), 0)), "{:?}", err.kind);
+    // a sample where a word should be
+    let ill_typed = [
+        GlobalDef::ClosedExpr { body: &Expr::Op(Op::Const(Value::Sample(1.0)), &[]) },
+    ];
+    let err = wasm::translate(&ill_typed, 1, &[], 0).unwrap_err();
+    assert_eq!(err.global, Some(0));
+    assert!(matches!(err.kind, BackendErrorKind::Invalid(_)), "{:?}", err.kind);
+}
+
+const STREAM_MAPS: &str = "\
+def mapsig: for k : clock. [](sample -> sample) -> ~^(k) sample -> ~^(k) sample =
+  \\f. (&^(k) mappedsig. \\sigin.
+         let (x, siginp) = %sigin in
+         (unbox f) x :: `(!(unbox mappedsig) !siginp));;
+
+def mapalt: for k : clock. [](sample -> sample) -> ~^(k) sample -> ~^(k) sample =
+  \\g. &^(k) again. \\input.
+    let (first, rest) = %input in
+    ((unbox g) first) :: `(!(unbox again) !rest);;
+
+def zipsig: for k : clock. [](sample -> sample -> sample) -> ~^(k) sample -> ~^(k) sample -> ~^(k) sample =
+  \\f. &^(k) zipped. \\s1. \\s2.
+    let (x, s1p) = %s1 in
+    let (y, s2p) = %s2 in
+    (unbox f) x y :: `(!(unbox zipped) !s1p !s2p);;
+
+def lin: for k : clock. ~^(k) sample =
+  ((&^(k) s. \\x. x :: `(!(unbox s) (x + 1.0))) : sample -> ~^(k) sample) 0.0;;
+";
+
+// maps and zips of each other should come out as one map or zip of
+// the functions composed, and play the same as they did before
+#[test]
+fn test_stream_map_fusion() {
+    use ir1::{Expr, StreamMap};
+    let main = "\
+def pipeline: for k : clock. ~^(k) sample =
+  mapsig @(k) (box (\\x. x * 2.0))
+    (zipsig @(k) (box (\\a. \\b. a - b))
+      (mapalt @(k) (box (\\x. x + 0.5)) (lin @(k)))
+      (mapsig @(k) (box (\\x. x * x)) (lin @(k))));;
+
+let main: ~^(audio) sample = pipeline @(audio);;
+";
+    let code = format!("{}\n{}", STREAM_MAPS, main);
+
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    let file = parse_and_check(&mut toplevel, code.clone()).unwrap();
+    let under_arena = Arena::new();
+    let ptr_arena = Arena::new();
+    let ir1_arena = clocky::util::ArenaPlus { arena: &under_arena, ptr_arena: &ptr_arena };
+    let globals = file.defs.iter().enumerate().map(|(i, def)| (def.name, Global(i as u32))).collect();
+    let translator = ir1::Translator { globals, global_clocks: Default::default(), arena: &ir1_arena };
+    // all but main, which needs the audio clock
+    let translated: Vec<&Expr> = file.defs[..5].iter().map(|def| {
+        ir1_arena.alloc(translator.translate(ir1::Ctx::Empty.into(), def.body.get_expr().unwrap()))
+    }).collect();
+    let shapes: Vec<_> = translated.iter().map(|expr| translator.stream_map(expr)).collect();
+    assert_eq!(shapes, [
+        Some(StreamMap { clocks: 1, streams: 1 }),
+        Some(StreamMap { clocks: 1, streams: 1 }),
+        Some(StreamMap { clocks: 1, streams: 2 }),
+        None,
+        None,
+    ]);
+    let maps = (0..3).map(|i| (Global(i), shapes[i as usize].unwrap())).collect();
+    let fused = translator.fuse_stream_maps(&maps, translated[4]);
+    let printed = format!("{:?}", fused);
+    let applied = |i| printed.matches(&format!("Glob(Global({}))", i)).count();
+    assert_eq!((applied(0), applied(1), applied(2)), (0, 0, 1), "{}", printed);
+
+    let compile_with = |optimize: bool| {
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        toplevel.optimize = optimize;
+        compile(&mut toplevel, code.clone()).unwrap()
+    };
+    let fused = compile_with(true);
+    let unfused = compile_with(false);
+    toplevel.reset_globals();
+    let interpreted = interpret(&mut toplevel, code.clone(), 1024).unwrap();
+    assert_eq!(interpreted[..4], [1.0, 1.0, -3.0, -11.0]);
+    #[cfg(feature = "run")]
+    {
+        assert_eq!(run(&fused, 1024), interpreted);
+        assert_eq!(run(&unfused, 1024), interpreted);
+    }
+    drop((fused, unfused));
+}
+
+// a chain of maps fused into one should only allocate as much as the
+// one map does
+#[cfg(feature = "run")]
+#[test]
+fn test_stream_map_fusion_allocates_once() {
+    let chain = |n: usize| {
+        let maps = (0..n).fold("lin @(audio)".to_string(), |s, i|
+            format!("mapsig @(audio) (box (\\x. x + {}.0)) ({})", i, s)
+        );
+        format!("{}\nlet main: ~^(audio) sample = {};;\n", STREAM_MAPS, maps)
+    };
+    let measure = |code: String, optimize: bool| {
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        toplevel.optimize = optimize;
+        let wasm_bytes = compile(&mut toplevel, code).unwrap();
+        run_measuring_memory(&wasm_bytes, 48000).1
+    };
+    let one = measure(chain(1), false);
+    let eight = measure(chain(8), false);
+    let fused = measure(chain(8), true);
+    assert!(eight > one, "eight maps took {} bytes, one took {}", eight, one);
+    assert!(fused <= one, "eight maps fused took {} bytes, one took {}", fused, one);
+}
+
+// how long each kind of node takes to run, in float adds, which is
+// where the costs in src/ir1.egg come from. each program runs the node
+// nested some number of times per sample, unoptimized so it's all
+// still there, and the time over the same program without it, per
+// node, is the node's time. run with
+//
+//   cargo test --release --features run -- --ignored bench_extraction_costs --nocapture
+#[cfg(feature = "run")]
+#[ignore]
+#[test]
+fn bench_extraction_costs() {
+    // compiling the deeply nested programs takes more stack than a test gets
+    std::thread::Builder::new().stack_size(256 << 20).spawn(|| {
+        use std::time::{Duration, Instant};
+        const NESTED: usize = 128;
+        const SAMPLES: usize = 48000 * 10;
+        // {} is the nested node; x is the sample so far, so nothing is constant.
+        // inc and one are globals, so calling them builds nothing
+        let nodes = [
+            ("fadd", "({} + 1.0)"),
+            ("fdiv", "({} / 1.5)"),
+            ("sin", "(sin {})"),
+            ("app + fadd", "(inc {})"),
+            ("lam + app + fadd", "(((\\y. y + x) : sample -> sample) {})"),
+            ("unbox + fadd", "((unbox one) + {})"),
+            ("box + unbox + fadd", "((unbox ((box 1.0) : [](sample))) + {})"),
+            ("con + proj + fadd", "(let (a, b) = (({}, x) : sample * sample) in a + b)"),
+        ];
+        let time = |body: &str| {
+            let code = format!(
+                "def inc: sample -> sample = \\y. y + 1.0;;\ndef one: [](sample) = box 1.0;;\n\
+                 let main: ~^(audio) sample =\n  ((&^(audio) s. \\x. {} :: `(!(unbox s) (x + 1.0))) : sample -> ~^(audio) sample) 0.0;;\n",
+                body
+            );
+            let arena = Arena::new();
+            let mut toplevel = TopLevel::new(&arena);
+            toplevel.optimize = false;
+            let wasm_bytes = compile(&mut toplevel, code).unwrap();
+            // the fastest of a few, to leave out whatever else the machine is doing
+            (0..5).map(|_| {
+                let start = Instant::now();
+                run(&wasm_bytes, SAMPLES);
+                start.elapsed()
+            }).min().unwrap()
+        };
+        let baseline = time("x");
+        let per_node = |template: &str| {
+            let body = (0..NESTED).fold("x".to_string(), |e, _| template.replace("{}", &e));
+            time(&body).saturating_sub(baseline).as_secs_f64() / NESTED as f64
+        };
+        let fadd = per_node(nodes[0].1);
+        println!("{} samples, {:?} without any nodes", SAMPLES, baseline);
+        for (name, template) in nodes {
+            let t = if name == "fadd" { fadd } else { per_node(template) };
+            println!("{:>20}: {:>6.1} fadds, {:?} per sample", name, t / fadd, Duration::from_secs_f64(t / SAMPLES as f64));
+        }
+    }).unwrap().join().unwrap();
+}
+
+// each stage asked for comes out once, in the order they run, and
+// nothing does otherwise
+#[test]
+fn test_emit_stages() {
+    use ir1::{DebruijnIndex, Expr};
+    let x = Expr::Var(DebruijnIndex(0));
+    let square = Expr::LetIn(&Expr::Val(Value::Sample(2.0)), &Expr::Op(Op::FMul, &[&x, &x]));
+    assert_eq!(square.pretty().to_string(), "let 2 in\nfmul(#0, #0)");
+
+    let code = "let main: ~^(audio) sample = &^(audio) s. 0.5 :: `!(unbox s);;\n";
+    let arena = Arena::new();
+    let mut toplevel = TopLevel::new(&arena);
+    toplevel.emit = vec![Stage::Wat, Stage::Ast, Stage::Ir2, Stage::Ir1Opt, Stage::Egglog, Stage::Elab, Stage::Ir1];
+    compile(&mut toplevel, code.to_string()).unwrap();
+    let stages: Vec<Stage> = toplevel.emitted.iter().map(|&(stage, _)| stage).collect();
+    assert_eq!(stages, [Stage::Ast, Stage::Elab, Stage::Ir1, Stage::Egglog, Stage::Ir1Opt, Stage::Ir2, Stage::Wat]);
+    for (stage, text) in toplevel.emitted.iter() {
+        match stage {
+            Stage::Ast | Stage::Elab => assert!(text.starts_with("let main"), "{}", text),
+            Stage::Ir1 | Stage::Ir1Opt => assert!(text.starts_with("main = "), "{}", text),
+            Stage::Egglog => assert!(text.contains("(set (program \"main\")"), "{}", text),
+            Stage::Ir2 => assert!(text.contains("(main) = "), "{}", text),
+            Stage::Wat => assert!(text.starts_with("(module"), "{}", text),
+        }
+    }
+
+    toplevel.emit.clear();
+    toplevel.reset_globals();
+    compile(&mut toplevel, code.to_string()).unwrap();
+    assert!(toplevel.emitted.is_empty());
+}
+
+// random well-typed programs should make it all the way through, and
+// sound the same compiled as interpreted
+#[test]
+fn test_generated_programs() {
+    for seed in 0..200 {
+        let code = Generator::new(seed).program();
+        let arena = Arena::new();
+        let mut toplevel = TopLevel::new(&arena);
+        let wasm_bytes = match compile(&mut toplevel, code.clone()) {
+            Ok(wasm_bytes) => wasm_bytes,
+            Err(err) => panic!("seed {} gave\n{}\nwhich didn't compile:\n{}", seed, code, err.pretty(&toplevel.interner, "<generated>")),
+        };
+        toplevel.reset_globals();
+        let interpreted = interpret(&mut toplevel, code.clone(), 100).unwrap();
+        #[cfg(feature = "run")]
+        {
+            let ran_samples = run(&wasm_bytes, 100);
+            for (i, (x, y)) in ran_samples.iter().zip(interpreted.iter()).enumerate() {
+                let same = x.to_bits() == y.to_bits() || (x.is_nan() && y.is_nan()) || (x - y).abs() <= 1e-5 * (1.0 + y.abs());
+                assert!(same, "seed {} gave\n{}\nwhich plays {} at sample {}, but should play {}", seed, code, x, i, y);
+            }
+        }
+        drop((wasm_bytes, interpreted));
+    }
+}
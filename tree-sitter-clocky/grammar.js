@@ -82,7 +82,7 @@ module.exports = grammar({
 
         wrap_expression: $ => seq('(', field('expr', $.expression), ')'),    
 
-        identifier: $ => /[a-z][a-z0-9_]*/,
+        identifier: $ => /[a-z_][a-z0-9_]*/,
 
         literal: $ => choice(/\d+/, seq('0x', /[\da-fA-F]+/)),
 
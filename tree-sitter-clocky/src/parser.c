@@ -1169,45 +1169,45 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [20] = 20,
   [21] = 21,
   [22] = 22,
-  [23] = 22,
-  [24] = 24,
-  [25] = 17,
+  [23] = 23,
+  [24] = 22,
+  [25] = 25,
   [26] = 26,
-  [27] = 10,
-  [28] = 13,
-  [29] = 15,
-  [30] = 20,
+  [27] = 27,
+  [28] = 28,
+  [29] = 29,
+  [30] = 30,
   [31] = 31,
   [32] = 32,
   [33] = 33,
-  [34] = 8,
-  [35] = 7,
-  [36] = 36,
-  [37] = 37,
-  [38] = 38,
+  [34] = 34,
+  [35] = 29,
+  [36] = 3,
+  [37] = 4,
+  [38] = 5,
   [39] = 6,
-  [40] = 5,
-  [41] = 14,
-  [42] = 18,
-  [43] = 43,
-  [44] = 43,
-  [45] = 38,
-  [46] = 46,
-  [47] = 4,
-  [48] = 9,
-  [49] = 49,
-  [50] = 37,
-  [51] = 11,
-  [52] = 2,
-  [53] = 19,
-  [54] = 36,
-  [55] = 16,
-  [56] = 49,
-  [57] = 3,
-  [58] = 31,
-  [59] = 33,
-  [60] = 32,
-  [61] = 12,
+  [40] = 2,
+  [41] = 7,
+  [42] = 8,
+  [43] = 9,
+  [44] = 14,
+  [45] = 10,
+  [46] = 11,
+  [47] = 12,
+  [48] = 13,
+  [49] = 20,
+  [50] = 15,
+  [51] = 16,
+  [52] = 17,
+  [53] = 18,
+  [54] = 19,
+  [55] = 26,
+  [56] = 28,
+  [57] = 31,
+  [58] = 32,
+  [59] = 34,
+  [60] = 25,
+  [61] = 33,
   [62] = 62,
   [63] = 63,
   [64] = 64,
@@ -1233,30 +1233,30 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [84] = 84,
   [85] = 85,
   [86] = 86,
-  [87] = 72,
-  [88] = 69,
-  [89] = 76,
-  [90] = 71,
-  [91] = 66,
-  [92] = 68,
-  [93] = 70,
-  [94] = 67,
+  [87] = 75,
+  [88] = 76,
+  [89] = 65,
+  [90] = 66,
+  [91] = 67,
+  [92] = 70,
+  [93] = 71,
+  [94] = 72,
   [95] = 73,
   [96] = 74,
-  [97] = 75,
-  [98] = 64,
-  [99] = 63,
-  [100] = 65,
-  [101] = 80,
-  [102] = 79,
-  [103] = 84,
-  [104] = 85,
-  [105] = 77,
+  [97] = 63,
+  [98] = 68,
+  [99] = 69,
+  [100] = 64,
+  [101] = 79,
+  [102] = 84,
+  [103] = 86,
+  [104] = 83,
+  [105] = 80,
   [106] = 82,
-  [107] = 83,
-  [108] = 78,
-  [109] = 86,
-  [110] = 81,
+  [107] = 78,
+  [108] = 81,
+  [109] = 77,
+  [110] = 85,
   [111] = 111,
   [112] = 111,
   [113] = 113,
@@ -1268,149 +1268,149 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [119] = 119,
   [120] = 120,
   [121] = 121,
-  [122] = 122,
+  [122] = 115,
   [123] = 123,
-  [124] = 124,
+  [124] = 121,
   [125] = 125,
   [126] = 126,
   [127] = 127,
   [128] = 128,
   [129] = 129,
-  [130] = 123,
-  [131] = 131,
+  [130] = 116,
+  [131] = 118,
   [132] = 132,
   [133] = 133,
   [134] = 134,
-  [135] = 135,
-  [136] = 132,
+  [135] = 123,
+  [136] = 136,
   [137] = 137,
   [138] = 138,
-  [139] = 139,
+  [139] = 133,
   [140] = 140,
-  [141] = 122,
-  [142] = 140,
-  [143] = 128,
-  [144] = 133,
-  [145] = 134,
-  [146] = 146,
-  [147] = 146,
-  [148] = 125,
-  [149] = 115,
-  [150] = 135,
-  [151] = 118,
-  [152] = 139,
-  [153] = 138,
+  [141] = 141,
+  [142] = 142,
+  [143] = 125,
+  [144] = 126,
+  [145] = 145,
+  [146] = 136,
+  [147] = 147,
+  [148] = 148,
+  [149] = 141,
+  [150] = 119,
+  [151] = 142,
+  [152] = 152,
+  [153] = 153,
   [154] = 154,
   [155] = 155,
-  [156] = 126,
-  [157] = 137,
-  [158] = 158,
-  [159] = 159,
-  [160] = 154,
-  [161] = 155,
-  [162] = 159,
-  [163] = 129,
-  [164] = 117,
-  [165] = 124,
-  [166] = 116,
-  [167] = 119,
-  [168] = 131,
-  [169] = 158,
-  [170] = 120,
+  [156] = 129,
+  [157] = 154,
+  [158] = 127,
+  [159] = 153,
+  [160] = 138,
+  [161] = 117,
+  [162] = 155,
+  [163] = 137,
+  [164] = 147,
+  [165] = 132,
+  [166] = 145,
+  [167] = 140,
+  [168] = 128,
+  [169] = 120,
+  [170] = 148,
   [171] = 171,
   [172] = 172,
-  [173] = 173,
+  [173] = 171,
   [174] = 174,
   [175] = 175,
   [176] = 176,
   [177] = 177,
   [178] = 178,
-  [179] = 179,
+  [179] = 174,
   [180] = 180,
-  [181] = 177,
+  [181] = 181,
   [182] = 182,
-  [183] = 183,
-  [184] = 175,
-  [185] = 172,
+  [183] = 180,
+  [184] = 181,
+  [185] = 182,
   [186] = 186,
-  [187] = 187,
-  [188] = 174,
-  [189] = 173,
-  [190] = 179,
-  [191] = 173,
-  [192] = 192,
-  [193] = 180,
-  [194] = 172,
-  [195] = 182,
-  [196] = 176,
-  [197] = 180,
-  [198] = 171,
-  [199] = 183,
-  [200] = 179,
-  [201] = 174,
-  [202] = 182,
-  [203] = 178,
-  [204] = 176,
-  [205] = 175,
-  [206] = 183,
-  [207] = 192,
+  [187] = 175,
+  [188] = 176,
+  [189] = 189,
+  [190] = 177,
+  [191] = 171,
+  [192] = 174,
+  [193] = 175,
+  [194] = 176,
+  [195] = 180,
+  [196] = 181,
+  [197] = 182,
+  [198] = 178,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 201,
+  [203] = 189,
+  [204] = 172,
+  [205] = 201,
+  [206] = 172,
+  [207] = 186,
   [208] = 177,
   [209] = 209,
   [210] = 210,
   [211] = 211,
-  [212] = 74,
-  [213] = 66,
-  [214] = 73,
-  [215] = 70,
-  [216] = 68,
+  [212] = 73,
+  [213] = 68,
+  [214] = 75,
+  [215] = 64,
+  [216] = 74,
   [217] = 69,
-  [218] = 72,
-  [219] = 76,
-  [220] = 63,
+  [218] = 67,
+  [219] = 63,
+  [220] = 70,
   [221] = 71,
   [222] = 65,
-  [223] = 67,
-  [224] = 75,
+  [223] = 66,
+  [224] = 72,
   [225] = 225,
   [226] = 226,
   [227] = 227,
-  [228] = 227,
+  [228] = 228,
   [229] = 229,
   [230] = 230,
-  [231] = 225,
-  [232] = 230,
-  [233] = 233,
-  [234] = 234,
+  [231] = 228,
+  [232] = 232,
+  [233] = 229,
+  [234] = 229,
   [235] = 235,
-  [236] = 233,
+  [236] = 236,
   [237] = 237,
   [238] = 238,
   [239] = 239,
-  [240] = 234,
+  [240] = 240,
   [241] = 241,
-  [242] = 241,
-  [243] = 243,
-  [244] = 237,
-  [245] = 245,
-  [246] = 243,
-  [247] = 233,
+  [242] = 235,
+  [243] = 236,
+  [244] = 225,
+  [245] = 238,
+  [246] = 225,
+  [247] = 237,
   [248] = 235,
-  [249] = 225,
-  [250] = 230,
-  [251] = 243,
+  [249] = 241,
+  [250] = 236,
+  [251] = 230,
   [252] = 252,
   [253] = 253,
-  [254] = 253,
+  [254] = 252,
   [255] = 253,
-  [256] = 252,
+  [256] = 253,
   [257] = 257,
   [258] = 258,
-  [259] = 257,
+  [259] = 259,
   [260] = 260,
-  [261] = 261,
-  [262] = 262,
-  [263] = 262,
-  [264] = 257,
+  [261] = 257,
+  [262] = 257,
+  [263] = 260,
+  [264] = 264,
   [265] = 265,
   [266] = 266,
   [267] = 267,
@@ -1421,104 +1421,104 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [272] = 272,
   [273] = 273,
   [274] = 274,
-  [275] = 275,
+  [275] = 265,
   [276] = 276,
   [277] = 277,
   [278] = 278,
-  [279] = 279,
-  [280] = 269,
-  [281] = 273,
-  [282] = 279,
+  [279] = 274,
+  [280] = 280,
+  [281] = 281,
+  [282] = 271,
   [283] = 283,
   [284] = 284,
   [285] = 285,
   [286] = 286,
   [287] = 287,
   [288] = 288,
-  [289] = 283,
-  [290] = 290,
+  [289] = 289,
+  [290] = 273,
   [291] = 291,
   [292] = 292,
-  [293] = 284,
+  [293] = 293,
   [294] = 294,
-  [295] = 265,
+  [295] = 295,
   [296] = 296,
-  [297] = 297,
-  [298] = 298,
-  [299] = 299,
-  [300] = 288,
-  [301] = 279,
-  [302] = 283,
-  [303] = 284,
-  [304] = 285,
+  [297] = 286,
+  [298] = 276,
+  [299] = 274,
+  [300] = 280,
+  [301] = 281,
+  [302] = 271,
+  [303] = 303,
+  [304] = 270,
   [305] = 305,
   [306] = 306,
   [307] = 307,
-  [308] = 285,
-  [309] = 309,
+  [308] = 308,
+  [309] = 280,
   [310] = 310,
   [311] = 311,
-  [312] = 274,
-  [313] = 286,
+  [312] = 283,
+  [313] = 313,
   [314] = 314,
-  [315] = 287,
+  [315] = 315,
   [316] = 316,
-  [317] = 310,
+  [317] = 294,
   [318] = 318,
-  [319] = 269,
-  [320] = 320,
+  [319] = 293,
+  [320] = 276,
   [321] = 321,
-  [322] = 322,
-  [323] = 306,
+  [322] = 270,
+  [323] = 323,
   [324] = 324,
   [325] = 325,
-  [326] = 292,
-  [327] = 327,
-  [328] = 266,
-  [329] = 314,
-  [330] = 324,
+  [326] = 326,
+  [327] = 281,
+  [328] = 307,
+  [329] = 308,
+  [330] = 315,
   [331] = 331,
-  [332] = 306,
-  [333] = 333,
+  [332] = 316,
+  [333] = 318,
   [334] = 334,
-  [335] = 333,
-  [336] = 321,
-  [337] = 325,
+  [335] = 267,
+  [336] = 278,
+  [337] = 313,
   [338] = 338,
-  [339] = 297,
-  [340] = 334,
-  [341] = 314,
-  [342] = 324,
-  [343] = 331,
+  [339] = 339,
+  [340] = 307,
+  [341] = 308,
+  [342] = 315,
+  [343] = 338,
   [344] = 344,
-  [345] = 271,
-  [346] = 270,
-  [347] = 268,
+  [345] = 334,
+  [346] = 269,
+  [347] = 347,
   [348] = 348,
-  [349] = 349,
+  [349] = 285,
   [350] = 331,
-  [351] = 348,
-  [352] = 322,
-  [353] = 305,
-  [354] = 354,
-  [355] = 271,
-  [356] = 270,
-  [357] = 268,
-  [358] = 316,
-  [359] = 359,
-  [360] = 344,
-  [361] = 309,
-  [362] = 275,
-  [363] = 338,
-  [364] = 364,
-  [365] = 365,
-  [366] = 311,
-  [367] = 364,
-  [368] = 320,
-  [369] = 327,
-  [370] = 354,
-  [371] = 371,
-  [372] = 359,
+  [351] = 284,
+  [352] = 326,
+  [353] = 353,
+  [354] = 344,
+  [355] = 334,
+  [356] = 269,
+  [357] = 357,
+  [358] = 358,
+  [359] = 339,
+  [360] = 305,
+  [361] = 266,
+  [362] = 289,
+  [363] = 287,
+  [364] = 357,
+  [365] = 303,
+  [366] = 277,
+  [367] = 295,
+  [368] = 268,
+  [369] = 306,
+  [370] = 311,
+  [371] = 344,
+  [372] = 372,
 };
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1610,7 +1610,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == '\r' ||
           lookahead == ' ') SKIP(1)
       if (('1' <= lookahead && lookahead <= '9')) ADVANCE(131);
-      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
+      if (('_' <= lookahead && lookahead <= 'z')) ADVANCE(129);
       END_STATE();
     case 2:
       if (lookahead == '!') ADVANCE(140);
@@ -1643,7 +1643,7 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == '\r' ||
           lookahead == ' ') SKIP(2)
       if (('1' <= lookahead && lookahead <= '9')) ADVANCE(131);
-      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
+      if (('_' <= lookahead && lookahead <= 'z')) ADVANCE(129);
       END_STATE();
     case 3:
       if (lookahead == '!') ADVANCE(33);
@@ -1682,7 +1682,8 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == '\r' ||
           lookahead == ' ') SKIP(4)
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(206);
-      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
+      if (lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
       END_STATE();
     case 5:
       if (lookahead == '(') ADVANCE(94);
@@ -1694,7 +1695,8 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == '\r' ||
           lookahead == ' ') SKIP(5)
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(92);
-      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
+      if (lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
       END_STATE();
     case 6:
       if (lookahead == '-') ADVANCE(77);
@@ -1724,7 +1726,8 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
           lookahead == '\r' ||
           lookahead == ' ') SKIP(11)
       if (('0' <= lookahead && lookahead <= '9')) ADVANCE(206);
-      if (('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
+      if (lookahead == '_' ||
+          ('a' <= lookahead && lookahead <= 'z')) ADVANCE(129);
       END_STATE();
     case 12:
       if (lookahead == '-') ADVANCE(6);
@@ -2586,17 +2589,17 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [22] = {.lex_state = 1},
   [23] = {.lex_state = 1},
   [24] = {.lex_state = 1},
-  [25] = {.lex_state = 2},
-  [26] = {.lex_state = 1},
-  [27] = {.lex_state = 2},
-  [28] = {.lex_state = 2},
-  [29] = {.lex_state = 2},
-  [30] = {.lex_state = 2},
-  [31] = {.lex_state = 1},
-  [32] = {.lex_state = 1},
-  [33] = {.lex_state = 2},
+  [25] = {.lex_state = 1},
+  [26] = {.lex_state = 2},
+  [27] = {.lex_state = 1},
+  [28] = {.lex_state = 1},
+  [29] = {.lex_state = 1},
+  [30] = {.lex_state = 1},
+  [31] = {.lex_state = 2},
+  [32] = {.lex_state = 2},
+  [33] = {.lex_state = 1},
   [34] = {.lex_state = 2},
-  [35] = {.lex_state = 2},
+  [35] = {.lex_state = 1},
   [36] = {.lex_state = 2},
   [37] = {.lex_state = 2},
   [38] = {.lex_state = 2},
@@ -2604,13 +2607,13 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [40] = {.lex_state = 2},
   [41] = {.lex_state = 2},
   [42] = {.lex_state = 2},
-  [43] = {.lex_state = 1},
-  [44] = {.lex_state = 1},
+  [43] = {.lex_state = 2},
+  [44] = {.lex_state = 2},
   [45] = {.lex_state = 2},
-  [46] = {.lex_state = 1},
+  [46] = {.lex_state = 2},
   [47] = {.lex_state = 2},
   [48] = {.lex_state = 2},
-  [49] = {.lex_state = 1},
+  [49] = {.lex_state = 2},
   [50] = {.lex_state = 2},
   [51] = {.lex_state = 2},
   [52] = {.lex_state = 2},
@@ -2619,10 +2622,10 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [55] = {.lex_state = 2},
   [56] = {.lex_state = 1},
   [57] = {.lex_state = 2},
-  [58] = {.lex_state = 1},
+  [58] = {.lex_state = 2},
   [59] = {.lex_state = 2},
   [60] = {.lex_state = 1},
-  [61] = {.lex_state = 2},
+  [61] = {.lex_state = 1},
   [62] = {.lex_state = 1},
   [63] = {.lex_state = 1},
   [64] = {.lex_state = 1},
@@ -2787,152 +2790,152 @@ static const TSLexMode ts_lex_modes[STATE_COUNT] = {
   [223] = {.lex_state = 4},
   [224] = {.lex_state = 4},
   [225] = {.lex_state = 4},
-  [226] = {.lex_state = 0},
-  [227] = {.lex_state = 11},
-  [228] = {.lex_state = 11},
+  [226] = {.lex_state = 4},
+  [227] = {.lex_state = 0},
+  [228] = {.lex_state = 4},
   [229] = {.lex_state = 4},
   [230] = {.lex_state = 11},
   [231] = {.lex_state = 4},
-  [232] = {.lex_state = 11},
-  [233] = {.lex_state = 11},
-  [234] = {.lex_state = 11},
-  [235] = {.lex_state = 4},
+  [232] = {.lex_state = 4},
+  [233] = {.lex_state = 4},
+  [234] = {.lex_state = 4},
+  [235] = {.lex_state = 11},
   [236] = {.lex_state = 11},
-  [237] = {.lex_state = 4},
-  [238] = {.lex_state = 0},
+  [237] = {.lex_state = 11},
+  [238] = {.lex_state = 4},
   [239] = {.lex_state = 0},
-  [240] = {.lex_state = 11},
+  [240] = {.lex_state = 0},
   [241] = {.lex_state = 11},
   [242] = {.lex_state = 11},
-  [243] = {.lex_state = 4},
+  [243] = {.lex_state = 11},
   [244] = {.lex_state = 4},
   [245] = {.lex_state = 4},
   [246] = {.lex_state = 4},
   [247] = {.lex_state = 11},
-  [248] = {.lex_state = 4},
-  [249] = {.lex_state = 4},
+  [248] = {.lex_state = 11},
+  [249] = {.lex_state = 11},
   [250] = {.lex_state = 11},
-  [251] = {.lex_state = 4},
+  [251] = {.lex_state = 11},
   [252] = {.lex_state = 5},
   [253] = {.lex_state = 0},
-  [254] = {.lex_state = 0},
+  [254] = {.lex_state = 5},
   [255] = {.lex_state = 0},
-  [256] = {.lex_state = 5},
+  [256] = {.lex_state = 0},
   [257] = {.lex_state = 4},
   [258] = {.lex_state = 0},
-  [259] = {.lex_state = 4},
-  [260] = {.lex_state = 0},
-  [261] = {.lex_state = 11},
+  [259] = {.lex_state = 0},
+  [260] = {.lex_state = 4},
+  [261] = {.lex_state = 4},
   [262] = {.lex_state = 4},
   [263] = {.lex_state = 4},
-  [264] = {.lex_state = 4},
-  [265] = {.lex_state = 4},
+  [264] = {.lex_state = 11},
+  [265] = {.lex_state = 0},
   [266] = {.lex_state = 0},
-  [267] = {.lex_state = 11},
+  [267] = {.lex_state = 0},
   [268] = {.lex_state = 11},
-  [269] = {.lex_state = 4},
-  [270] = {.lex_state = 0},
-  [271] = {.lex_state = 0},
+  [269] = {.lex_state = 11},
+  [270] = {.lex_state = 11},
+  [271] = {.lex_state = 4},
   [272] = {.lex_state = 0},
-  [273] = {.lex_state = 12},
-  [274] = {.lex_state = 11},
+  [273] = {.lex_state = 0},
+  [274] = {.lex_state = 0},
   [275] = {.lex_state = 0},
-  [276] = {.lex_state = 1},
-  [277] = {.lex_state = 0},
-  [278] = {.lex_state = 0},
+  [276] = {.lex_state = 4},
+  [277] = {.lex_state = 12},
+  [278] = {.lex_state = 4},
   [279] = {.lex_state = 0},
-  [280] = {.lex_state = 4},
-  [281] = {.lex_state = 12},
-  [282] = {.lex_state = 0},
+  [280] = {.lex_state = 0},
+  [281] = {.lex_state = 0},
+  [282] = {.lex_state = 4},
   [283] = {.lex_state = 0},
   [284] = {.lex_state = 0},
   [285] = {.lex_state = 4},
   [286] = {.lex_state = 0},
-  [287] = {.lex_state = 4},
+  [287] = {.lex_state = 0},
   [288] = {.lex_state = 0},
-  [289] = {.lex_state = 0},
-  [290] = {.lex_state = 11},
-  [291] = {.lex_state = 0},
-  [292] = {.lex_state = 0},
-  [293] = {.lex_state = 0},
-  [294] = {.lex_state = 0},
-  [295] = {.lex_state = 4},
+  [289] = {.lex_state = 11},
+  [290] = {.lex_state = 0},
+  [291] = {.lex_state = 5},
+  [292] = {.lex_state = 11},
+  [293] = {.lex_state = 4},
+  [294] = {.lex_state = 11},
+  [295] = {.lex_state = 1},
   [296] = {.lex_state = 0},
-  [297] = {.lex_state = 1},
-  [298] = {.lex_state = 0},
-  [299] = {.lex_state = 4},
+  [297] = {.lex_state = 0},
+  [298] = {.lex_state = 4},
+  [299] = {.lex_state = 0},
   [300] = {.lex_state = 0},
   [301] = {.lex_state = 0},
-  [302] = {.lex_state = 0},
-  [303] = {.lex_state = 0},
-  [304] = {.lex_state = 4},
-  [305] = {.lex_state = 11},
-  [306] = {.lex_state = 11},
-  [307] = {.lex_state = 4},
-  [308] = {.lex_state = 4},
+  [302] = {.lex_state = 4},
+  [303] = {.lex_state = 11},
+  [304] = {.lex_state = 11},
+  [305] = {.lex_state = 0},
+  [306] = {.lex_state = 0},
+  [307] = {.lex_state = 0},
+  [308] = {.lex_state = 0},
   [309] = {.lex_state = 0},
-  [310] = {.lex_state = 11},
+  [310] = {.lex_state = 0},
   [311] = {.lex_state = 11},
-  [312] = {.lex_state = 11},
-  [313] = {.lex_state = 0},
-  [314] = {.lex_state = 0},
-  [315] = {.lex_state = 4},
+  [312] = {.lex_state = 0},
+  [313] = {.lex_state = 4},
+  [314] = {.lex_state = 11},
+  [315] = {.lex_state = 0},
   [316] = {.lex_state = 0},
   [317] = {.lex_state = 11},
-  [318] = {.lex_state = 5},
+  [318] = {.lex_state = 0},
   [319] = {.lex_state = 4},
-  [320] = {.lex_state = 1},
-  [321] = {.lex_state = 11},
-  [322] = {.lex_state = 0},
+  [320] = {.lex_state = 4},
+  [321] = {.lex_state = 0},
+  [322] = {.lex_state = 11},
   [323] = {.lex_state = 11},
-  [324] = {.lex_state = 0},
-  [325] = {.lex_state = 4},
-  [326] = {.lex_state = 0},
-  [327] = {.lex_state = 11},
+  [324] = {.lex_state = 11},
+  [325] = {.lex_state = 11},
+  [326] = {.lex_state = 11},
+  [327] = {.lex_state = 0},
   [328] = {.lex_state = 0},
   [329] = {.lex_state = 0},
   [330] = {.lex_state = 0},
-  [331] = {.lex_state = 0},
-  [332] = {.lex_state = 11},
+  [331] = {.lex_state = 11},
+  [332] = {.lex_state = 0},
   [333] = {.lex_state = 0},
   [334] = {.lex_state = 0},
   [335] = {.lex_state = 0},
-  [336] = {.lex_state = 11},
+  [336] = {.lex_state = 4},
   [337] = {.lex_state = 4},
-  [338] = {.lex_state = 4},
-  [339] = {.lex_state = 1},
+  [338] = {.lex_state = 1},
+  [339] = {.lex_state = 11},
   [340] = {.lex_state = 0},
   [341] = {.lex_state = 0},
   [342] = {.lex_state = 0},
-  [343] = {.lex_state = 0},
-  [344] = {.lex_state = 11},
+  [343] = {.lex_state = 1},
+  [344] = {.lex_state = 0},
   [345] = {.lex_state = 0},
-  [346] = {.lex_state = 0},
-  [347] = {.lex_state = 11},
-  [348] = {.lex_state = 11},
-  [349] = {.lex_state = 11},
-  [350] = {.lex_state = 0},
-  [351] = {.lex_state = 11},
-  [352] = {.lex_state = 0},
-  [353] = {.lex_state = 11},
+  [346] = {.lex_state = 11},
+  [347] = {.lex_state = 4},
+  [348] = {.lex_state = 4},
+  [349] = {.lex_state = 4},
+  [350] = {.lex_state = 11},
+  [351] = {.lex_state = 0},
+  [352] = {.lex_state = 11},
+  [353] = {.lex_state = 0},
   [354] = {.lex_state = 0},
   [355] = {.lex_state = 0},
-  [356] = {.lex_state = 0},
-  [357] = {.lex_state = 11},
-  [358] = {.lex_state = 0},
-  [359] = {.lex_state = 0},
-  [360] = {.lex_state = 11},
+  [356] = {.lex_state = 11},
+  [357] = {.lex_state = 0},
+  [358] = {.lex_state = 1},
+  [359] = {.lex_state = 11},
+  [360] = {.lex_state = 0},
   [361] = {.lex_state = 0},
-  [362] = {.lex_state = 0},
-  [363] = {.lex_state = 4},
+  [362] = {.lex_state = 11},
+  [363] = {.lex_state = 0},
   [364] = {.lex_state = 0},
   [365] = {.lex_state = 11},
-  [366] = {.lex_state = 11},
-  [367] = {.lex_state = 0},
-  [368] = {.lex_state = 1},
-  [369] = {.lex_state = 11},
-  [370] = {.lex_state = 0},
-  [371] = {.lex_state = 11},
+  [366] = {.lex_state = 12},
+  [367] = {.lex_state = 1},
+  [368] = {.lex_state = 11},
+  [369] = {.lex_state = 0},
+  [370] = {.lex_state = 11},
+  [371] = {.lex_state = 0},
   [372] = {.lex_state = 0},
 };
 
@@ -3012,42 +3015,42 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_type] = ACTIONS(1),
   },
   [1] = {
-    [sym_source_file] = STATE(298),
-    [sym_top_level_def] = STATE(211),
-    [sym_top_level_let] = STATE(211),
-    [sym_top_level_clock] = STATE(211),
-    [aux_sym_source_file_repeat1] = STATE(211),
+    [sym_source_file] = STATE(372),
+    [sym_top_level_def] = STATE(210),
+    [sym_top_level_let] = STATE(210),
+    [sym_top_level_clock] = STATE(210),
+    [aux_sym_source_file_repeat1] = STATE(210),
     [sym_comment] = ACTIONS(3),
     [anon_sym_def] = ACTIONS(5),
     [anon_sym_let] = ACTIONS(7),
     [anon_sym_clock] = ACTIONS(9),
   },
   [2] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
     [anon_sym_COLON] = ACTIONS(11),
     [anon_sym_SEMI_SEMI] = ACTIONS(13),
@@ -3106,31 +3109,31 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(13),
   },
   [3] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
     [anon_sym_COLON] = ACTIONS(15),
     [anon_sym_SEMI_SEMI] = ACTIONS(17),
@@ -3189,31 +3192,31 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(17),
   },
   [4] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
     [anon_sym_COLON] = ACTIONS(19),
     [anon_sym_SEMI_SEMI] = ACTIONS(21),
@@ -3272,66 +3275,66 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(21),
   },
   [5] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
     [anon_sym_COLON] = ACTIONS(23),
     [anon_sym_SEMI_SEMI] = ACTIONS(25),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(23),
+    [anon_sym_clock] = ACTIONS(23),
+    [anon_sym_LPAREN] = ACTIONS(23),
     [anon_sym_RPAREN] = ACTIONS(25),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
+    [sym_identifier] = ACTIONS(23),
+    [aux_sym_literal_token1] = ACTIONS(23),
+    [anon_sym_0x] = ACTIONS(25),
+    [sym_sample] = ACTIONS(25),
+    [anon_sym_BSLASH] = ACTIONS(25),
+    [anon_sym_AMP] = ACTIONS(25),
+    [anon_sym_BANG] = ACTIONS(23),
     [anon_sym_COLON_COLON] = ACTIONS(25),
     [anon_sym_COMMA] = ACTIONS(25),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
+    [anon_sym_inl] = ACTIONS(23),
+    [anon_sym_inr] = ACTIONS(23),
+    [anon_sym_case] = ACTIONS(23),
     [anon_sym_LBRACE] = ACTIONS(23),
     [anon_sym_PIPE] = ACTIONS(25),
     [anon_sym_RBRACE] = ACTIONS(25),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_LBRACK] = ACTIONS(25),
     [anon_sym_RBRACK] = ACTIONS(25),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
+    [anon_sym_PERCENT] = ACTIONS(25),
+    [sym_unit_expression] = ACTIONS(25),
+    [anon_sym_BQUOTE] = ACTIONS(25),
+    [anon_sym_box] = ACTIONS(23),
+    [anon_sym_unbox] = ACTIONS(23),
     [anon_sym_AT] = ACTIONS(25),
     [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
+    [anon_sym_STAR] = ACTIONS(25),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(25),
+    [anon_sym_SLASH] = ACTIONS(25),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(25),
     [anon_sym_PLUS] = ACTIONS(23),
     [anon_sym_DOT_PLUS_DOT] = ACTIONS(25),
     [anon_sym_DASH] = ACTIONS(23),
@@ -3355,1564 +3358,1641 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
   },
   [6] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(23),
-    [anon_sym_SEMI_SEMI] = ACTIONS(25),
+    [anon_sym_COLON] = ACTIONS(27),
+    [anon_sym_SEMI_SEMI] = ACTIONS(29),
     [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(25),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(25),
-    [anon_sym_COMMA] = ACTIONS(25),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(23),
-    [anon_sym_PIPE] = ACTIONS(25),
-    [anon_sym_RBRACE] = ACTIONS(25),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(25),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(25),
-    [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(23),
-    [anon_sym_GT_EQ] = ACTIONS(25),
-    [anon_sym_LT] = ACTIONS(23),
-    [anon_sym_LT_EQ] = ACTIONS(25),
-    [anon_sym_EQ_EQ] = ACTIONS(25),
-    [anon_sym_BANG_EQ] = ACTIONS(25),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
+    [anon_sym_clock] = ACTIONS(27),
+    [anon_sym_LPAREN] = ACTIONS(27),
+    [anon_sym_RPAREN] = ACTIONS(29),
+    [sym_identifier] = ACTIONS(27),
+    [aux_sym_literal_token1] = ACTIONS(27),
+    [anon_sym_0x] = ACTIONS(29),
+    [sym_sample] = ACTIONS(29),
+    [anon_sym_BSLASH] = ACTIONS(29),
+    [anon_sym_AMP] = ACTIONS(29),
+    [anon_sym_BANG] = ACTIONS(27),
+    [anon_sym_COLON_COLON] = ACTIONS(29),
+    [anon_sym_COMMA] = ACTIONS(29),
+    [anon_sym_inl] = ACTIONS(27),
+    [anon_sym_inr] = ACTIONS(27),
+    [anon_sym_case] = ACTIONS(27),
+    [anon_sym_LBRACE] = ACTIONS(27),
+    [anon_sym_PIPE] = ACTIONS(29),
+    [anon_sym_RBRACE] = ACTIONS(29),
+    [anon_sym_LBRACK] = ACTIONS(29),
+    [anon_sym_RBRACK] = ACTIONS(29),
+    [anon_sym_PERCENT] = ACTIONS(29),
+    [sym_unit_expression] = ACTIONS(29),
+    [anon_sym_BQUOTE] = ACTIONS(29),
+    [anon_sym_box] = ACTIONS(27),
+    [anon_sym_unbox] = ACTIONS(27),
+    [anon_sym_AT] = ACTIONS(29),
+    [anon_sym_DOLLAR] = ACTIONS(29),
+    [anon_sym_STAR] = ACTIONS(29),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(29),
+    [anon_sym_SLASH] = ACTIONS(29),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(29),
+    [anon_sym_PLUS] = ACTIONS(27),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(29),
+    [anon_sym_DASH] = ACTIONS(27),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(29),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(29),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(29),
+    [anon_sym_GT] = ACTIONS(27),
+    [anon_sym_GT_EQ] = ACTIONS(29),
+    [anon_sym_LT] = ACTIONS(27),
+    [anon_sym_LT_EQ] = ACTIONS(29),
+    [anon_sym_EQ_EQ] = ACTIONS(29),
+    [anon_sym_BANG_EQ] = ACTIONS(29),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(29),
   },
   [7] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_SEMI_SEMI] = ACTIONS(71),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
+    [anon_sym_COLON] = ACTIONS(31),
+    [anon_sym_SEMI_SEMI] = ACTIONS(33),
+    [anon_sym_let] = ACTIONS(31),
+    [anon_sym_clock] = ACTIONS(31),
     [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(71),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(71),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(75),
-    [anon_sym_PIPE] = ACTIONS(71),
-    [anon_sym_RBRACE] = ACTIONS(71),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(71),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_RPAREN] = ACTIONS(33),
+    [sym_identifier] = ACTIONS(31),
+    [aux_sym_literal_token1] = ACTIONS(31),
+    [anon_sym_0x] = ACTIONS(33),
+    [sym_sample] = ACTIONS(33),
+    [anon_sym_BSLASH] = ACTIONS(33),
+    [anon_sym_AMP] = ACTIONS(33),
+    [anon_sym_BANG] = ACTIONS(31),
+    [anon_sym_COLON_COLON] = ACTIONS(33),
+    [anon_sym_COMMA] = ACTIONS(33),
+    [anon_sym_inl] = ACTIONS(31),
+    [anon_sym_inr] = ACTIONS(31),
+    [anon_sym_case] = ACTIONS(31),
+    [anon_sym_LBRACE] = ACTIONS(31),
+    [anon_sym_PIPE] = ACTIONS(33),
+    [anon_sym_RBRACE] = ACTIONS(33),
+    [anon_sym_LBRACK] = ACTIONS(33),
+    [anon_sym_RBRACK] = ACTIONS(33),
+    [anon_sym_PERCENT] = ACTIONS(33),
+    [sym_unit_expression] = ACTIONS(33),
+    [anon_sym_BQUOTE] = ACTIONS(33),
+    [anon_sym_box] = ACTIONS(31),
+    [anon_sym_unbox] = ACTIONS(31),
+    [anon_sym_AT] = ACTIONS(33),
+    [anon_sym_DOLLAR] = ACTIONS(33),
+    [anon_sym_STAR] = ACTIONS(33),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(33),
+    [anon_sym_SLASH] = ACTIONS(33),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(33),
+    [anon_sym_PLUS] = ACTIONS(31),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(33),
+    [anon_sym_DASH] = ACTIONS(31),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(33),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(33),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(33),
+    [anon_sym_GT] = ACTIONS(31),
+    [anon_sym_GT_EQ] = ACTIONS(33),
+    [anon_sym_LT] = ACTIONS(31),
+    [anon_sym_LT_EQ] = ACTIONS(33),
+    [anon_sym_EQ_EQ] = ACTIONS(33),
+    [anon_sym_BANG_EQ] = ACTIONS(33),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(33),
   },
   [8] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_SEMI_SEMI] = ACTIONS(85),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(85),
-    [sym_identifier] = ACTIONS(33),
+    [anon_sym_COLON] = ACTIONS(35),
+    [anon_sym_SEMI_SEMI] = ACTIONS(37),
+    [anon_sym_let] = ACTIONS(35),
+    [anon_sym_clock] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(35),
+    [anon_sym_RPAREN] = ACTIONS(37),
+    [sym_identifier] = ACTIONS(35),
     [aux_sym_literal_token1] = ACTIONS(35),
     [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(85),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(87),
-    [anon_sym_PIPE] = ACTIONS(85),
-    [anon_sym_RBRACE] = ACTIONS(85),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(85),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [sym_sample] = ACTIONS(37),
+    [anon_sym_BSLASH] = ACTIONS(37),
+    [anon_sym_AMP] = ACTIONS(37),
+    [anon_sym_BANG] = ACTIONS(35),
+    [anon_sym_COLON_COLON] = ACTIONS(37),
+    [anon_sym_COMMA] = ACTIONS(37),
+    [anon_sym_inl] = ACTIONS(35),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_case] = ACTIONS(35),
+    [anon_sym_LBRACE] = ACTIONS(35),
+    [anon_sym_PIPE] = ACTIONS(37),
+    [anon_sym_RBRACE] = ACTIONS(37),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [anon_sym_RBRACK] = ACTIONS(37),
+    [anon_sym_PERCENT] = ACTIONS(37),
+    [sym_unit_expression] = ACTIONS(37),
+    [anon_sym_BQUOTE] = ACTIONS(37),
+    [anon_sym_box] = ACTIONS(35),
+    [anon_sym_unbox] = ACTIONS(35),
+    [anon_sym_AT] = ACTIONS(37),
+    [anon_sym_DOLLAR] = ACTIONS(37),
+    [anon_sym_STAR] = ACTIONS(37),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(37),
+    [anon_sym_SLASH] = ACTIONS(37),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(37),
+    [anon_sym_PLUS] = ACTIONS(35),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(37),
+    [anon_sym_DASH] = ACTIONS(35),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(37),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(37),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(35),
+    [anon_sym_GT_EQ] = ACTIONS(37),
+    [anon_sym_LT] = ACTIONS(35),
+    [anon_sym_LT_EQ] = ACTIONS(37),
+    [anon_sym_EQ_EQ] = ACTIONS(37),
+    [anon_sym_BANG_EQ] = ACTIONS(37),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(37),
   },
   [9] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(89),
-    [anon_sym_SEMI_SEMI] = ACTIONS(91),
-    [anon_sym_let] = ACTIONS(89),
-    [anon_sym_clock] = ACTIONS(89),
-    [anon_sym_LPAREN] = ACTIONS(89),
-    [anon_sym_RPAREN] = ACTIONS(91),
-    [sym_identifier] = ACTIONS(89),
-    [aux_sym_literal_token1] = ACTIONS(89),
-    [anon_sym_0x] = ACTIONS(91),
-    [sym_sample] = ACTIONS(91),
-    [anon_sym_BSLASH] = ACTIONS(91),
-    [anon_sym_AMP] = ACTIONS(91),
-    [anon_sym_BANG] = ACTIONS(89),
-    [anon_sym_COLON_COLON] = ACTIONS(91),
-    [anon_sym_COMMA] = ACTIONS(91),
-    [anon_sym_inl] = ACTIONS(89),
-    [anon_sym_inr] = ACTIONS(89),
-    [anon_sym_case] = ACTIONS(89),
-    [anon_sym_LBRACE] = ACTIONS(89),
-    [anon_sym_PIPE] = ACTIONS(91),
-    [anon_sym_RBRACE] = ACTIONS(91),
-    [anon_sym_LBRACK] = ACTIONS(91),
-    [anon_sym_RBRACK] = ACTIONS(91),
-    [anon_sym_PERCENT] = ACTIONS(91),
-    [sym_unit_expression] = ACTIONS(91),
-    [anon_sym_BQUOTE] = ACTIONS(91),
-    [anon_sym_box] = ACTIONS(89),
-    [anon_sym_unbox] = ACTIONS(89),
-    [anon_sym_AT] = ACTIONS(91),
-    [anon_sym_DOLLAR] = ACTIONS(91),
-    [anon_sym_STAR] = ACTIONS(91),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(91),
-    [anon_sym_SLASH] = ACTIONS(91),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(91),
-    [anon_sym_PLUS] = ACTIONS(89),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(91),
-    [anon_sym_DASH] = ACTIONS(89),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(91),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(91),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(91),
-    [anon_sym_GT] = ACTIONS(89),
-    [anon_sym_GT_EQ] = ACTIONS(91),
-    [anon_sym_LT] = ACTIONS(89),
-    [anon_sym_LT_EQ] = ACTIONS(91),
-    [anon_sym_EQ_EQ] = ACTIONS(91),
-    [anon_sym_BANG_EQ] = ACTIONS(91),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(91),
+    [anon_sym_COLON] = ACTIONS(39),
+    [anon_sym_SEMI_SEMI] = ACTIONS(41),
+    [anon_sym_let] = ACTIONS(39),
+    [anon_sym_clock] = ACTIONS(39),
+    [anon_sym_LPAREN] = ACTIONS(39),
+    [anon_sym_RPAREN] = ACTIONS(41),
+    [sym_identifier] = ACTIONS(39),
+    [aux_sym_literal_token1] = ACTIONS(39),
+    [anon_sym_0x] = ACTIONS(41),
+    [sym_sample] = ACTIONS(41),
+    [anon_sym_BSLASH] = ACTIONS(41),
+    [anon_sym_AMP] = ACTIONS(41),
+    [anon_sym_BANG] = ACTIONS(39),
+    [anon_sym_COLON_COLON] = ACTIONS(41),
+    [anon_sym_COMMA] = ACTIONS(41),
+    [anon_sym_inl] = ACTIONS(39),
+    [anon_sym_inr] = ACTIONS(39),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_LBRACE] = ACTIONS(39),
+    [anon_sym_PIPE] = ACTIONS(41),
+    [anon_sym_RBRACE] = ACTIONS(41),
+    [anon_sym_LBRACK] = ACTIONS(41),
+    [anon_sym_RBRACK] = ACTIONS(41),
+    [anon_sym_PERCENT] = ACTIONS(41),
+    [sym_unit_expression] = ACTIONS(41),
+    [anon_sym_BQUOTE] = ACTIONS(41),
+    [anon_sym_box] = ACTIONS(39),
+    [anon_sym_unbox] = ACTIONS(39),
+    [anon_sym_AT] = ACTIONS(41),
+    [anon_sym_DOLLAR] = ACTIONS(41),
+    [anon_sym_STAR] = ACTIONS(41),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(41),
+    [anon_sym_SLASH] = ACTIONS(41),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(41),
+    [anon_sym_PLUS] = ACTIONS(39),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(41),
+    [anon_sym_DASH] = ACTIONS(39),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(41),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(41),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(41),
+    [anon_sym_GT] = ACTIONS(39),
+    [anon_sym_GT_EQ] = ACTIONS(41),
+    [anon_sym_LT] = ACTIONS(39),
+    [anon_sym_LT_EQ] = ACTIONS(41),
+    [anon_sym_EQ_EQ] = ACTIONS(41),
+    [anon_sym_BANG_EQ] = ACTIONS(41),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(41),
   },
   [10] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_SEMI_SEMI] = ACTIONS(93),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(93),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(93),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(95),
-    [anon_sym_PIPE] = ACTIONS(93),
-    [anon_sym_RBRACE] = ACTIONS(93),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(93),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(43),
+    [anon_sym_SEMI_SEMI] = ACTIONS(45),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(45),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_COMMA] = ACTIONS(45),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(43),
+    [anon_sym_PIPE] = ACTIONS(45),
+    [anon_sym_RBRACE] = ACTIONS(45),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(45),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(45),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(45),
+    [anon_sym_SLASH] = ACTIONS(45),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(45),
+    [anon_sym_PLUS] = ACTIONS(43),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(45),
+    [anon_sym_DASH] = ACTIONS(43),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(45),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(45),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(45),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
   [11] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(97),
-    [anon_sym_SEMI_SEMI] = ACTIONS(99),
-    [anon_sym_let] = ACTIONS(97),
-    [anon_sym_clock] = ACTIONS(97),
-    [anon_sym_LPAREN] = ACTIONS(97),
-    [anon_sym_RPAREN] = ACTIONS(99),
-    [sym_identifier] = ACTIONS(97),
-    [aux_sym_literal_token1] = ACTIONS(97),
-    [anon_sym_0x] = ACTIONS(99),
-    [sym_sample] = ACTIONS(99),
-    [anon_sym_BSLASH] = ACTIONS(99),
-    [anon_sym_AMP] = ACTIONS(99),
-    [anon_sym_BANG] = ACTIONS(97),
-    [anon_sym_COLON_COLON] = ACTIONS(99),
-    [anon_sym_COMMA] = ACTIONS(99),
-    [anon_sym_inl] = ACTIONS(97),
-    [anon_sym_inr] = ACTIONS(97),
-    [anon_sym_case] = ACTIONS(97),
-    [anon_sym_LBRACE] = ACTIONS(97),
-    [anon_sym_PIPE] = ACTIONS(99),
-    [anon_sym_RBRACE] = ACTIONS(99),
-    [anon_sym_LBRACK] = ACTIONS(99),
-    [anon_sym_RBRACK] = ACTIONS(99),
-    [anon_sym_PERCENT] = ACTIONS(99),
-    [sym_unit_expression] = ACTIONS(99),
-    [anon_sym_BQUOTE] = ACTIONS(99),
-    [anon_sym_box] = ACTIONS(97),
-    [anon_sym_unbox] = ACTIONS(97),
-    [anon_sym_AT] = ACTIONS(99),
-    [anon_sym_DOLLAR] = ACTIONS(99),
-    [anon_sym_STAR] = ACTIONS(99),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(99),
-    [anon_sym_SLASH] = ACTIONS(99),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(99),
-    [anon_sym_PLUS] = ACTIONS(97),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(99),
-    [anon_sym_DASH] = ACTIONS(97),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(99),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(99),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(99),
-    [anon_sym_GT] = ACTIONS(97),
-    [anon_sym_GT_EQ] = ACTIONS(99),
-    [anon_sym_LT] = ACTIONS(97),
-    [anon_sym_LT_EQ] = ACTIONS(99),
-    [anon_sym_EQ_EQ] = ACTIONS(99),
-    [anon_sym_BANG_EQ] = ACTIONS(99),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(99),
+    [anon_sym_COLON] = ACTIONS(43),
+    [anon_sym_SEMI_SEMI] = ACTIONS(45),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(45),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_COMMA] = ACTIONS(45),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(43),
+    [anon_sym_PIPE] = ACTIONS(45),
+    [anon_sym_RBRACE] = ACTIONS(45),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(45),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(43),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(45),
+    [anon_sym_DASH] = ACTIONS(43),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(45),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(45),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(45),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
   [12] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_SEMI_SEMI] = ACTIONS(101),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(101),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(101),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(103),
-    [anon_sym_PIPE] = ACTIONS(101),
-    [anon_sym_RBRACE] = ACTIONS(101),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(101),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(43),
+    [anon_sym_SEMI_SEMI] = ACTIONS(45),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(45),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_COMMA] = ACTIONS(45),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(43),
+    [anon_sym_PIPE] = ACTIONS(45),
+    [anon_sym_RBRACE] = ACTIONS(45),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(45),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
   [13] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_SEMI_SEMI] = ACTIONS(91),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(91),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(91),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(95),
+    [anon_sym_PIPE] = ACTIONS(91),
+    [anon_sym_RBRACE] = ACTIONS(91),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(91),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [14] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
+    [anon_sym_COLON] = ACTIONS(89),
     [anon_sym_SEMI_SEMI] = ACTIONS(105),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
     [anon_sym_RPAREN] = ACTIONS(105),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(105),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
     [anon_sym_LBRACE] = ACTIONS(107),
     [anon_sym_PIPE] = ACTIONS(105),
     [anon_sym_RBRACE] = ACTIONS(105),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_LBRACK] = ACTIONS(73),
     [anon_sym_RBRACK] = ACTIONS(105),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [14] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(23),
-    [anon_sym_SEMI_SEMI] = ACTIONS(25),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(25),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(25),
-    [anon_sym_COMMA] = ACTIONS(25),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(23),
-    [anon_sym_PIPE] = ACTIONS(25),
-    [anon_sym_RBRACE] = ACTIONS(25),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(25),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(25),
-    [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(25),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(25),
-    [anon_sym_SLASH] = ACTIONS(25),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(25),
-    [anon_sym_PLUS] = ACTIONS(23),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(25),
-    [anon_sym_DASH] = ACTIONS(23),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(25),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(25),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(25),
-    [anon_sym_GT] = ACTIONS(23),
-    [anon_sym_GT_EQ] = ACTIONS(25),
-    [anon_sym_LT] = ACTIONS(23),
-    [anon_sym_LT_EQ] = ACTIONS(25),
-    [anon_sym_EQ_EQ] = ACTIONS(25),
-    [anon_sym_BANG_EQ] = ACTIONS(25),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [15] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
+    [anon_sym_COLON] = ACTIONS(89),
     [anon_sym_SEMI_SEMI] = ACTIONS(109),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
     [anon_sym_RPAREN] = ACTIONS(109),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(109),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
     [anon_sym_LBRACE] = ACTIONS(111),
     [anon_sym_PIPE] = ACTIONS(109),
     [anon_sym_RBRACE] = ACTIONS(109),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_LBRACK] = ACTIONS(73),
     [anon_sym_RBRACK] = ACTIONS(109),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [16] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(113),
-    [anon_sym_SEMI_SEMI] = ACTIONS(115),
-    [anon_sym_let] = ACTIONS(113),
-    [anon_sym_clock] = ACTIONS(113),
-    [anon_sym_LPAREN] = ACTIONS(113),
-    [anon_sym_RPAREN] = ACTIONS(115),
-    [sym_identifier] = ACTIONS(113),
-    [aux_sym_literal_token1] = ACTIONS(113),
-    [anon_sym_0x] = ACTIONS(115),
-    [sym_sample] = ACTIONS(115),
-    [anon_sym_BSLASH] = ACTIONS(115),
-    [anon_sym_AMP] = ACTIONS(115),
-    [anon_sym_BANG] = ACTIONS(113),
-    [anon_sym_COLON_COLON] = ACTIONS(115),
-    [anon_sym_COMMA] = ACTIONS(115),
-    [anon_sym_inl] = ACTIONS(113),
-    [anon_sym_inr] = ACTIONS(113),
-    [anon_sym_case] = ACTIONS(113),
-    [anon_sym_LBRACE] = ACTIONS(113),
-    [anon_sym_PIPE] = ACTIONS(115),
-    [anon_sym_RBRACE] = ACTIONS(115),
-    [anon_sym_LBRACK] = ACTIONS(115),
-    [anon_sym_RBRACK] = ACTIONS(115),
-    [anon_sym_PERCENT] = ACTIONS(115),
-    [sym_unit_expression] = ACTIONS(115),
-    [anon_sym_BQUOTE] = ACTIONS(115),
-    [anon_sym_box] = ACTIONS(113),
-    [anon_sym_unbox] = ACTIONS(113),
-    [anon_sym_AT] = ACTIONS(115),
-    [anon_sym_DOLLAR] = ACTIONS(115),
-    [anon_sym_STAR] = ACTIONS(115),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(115),
-    [anon_sym_SLASH] = ACTIONS(115),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(115),
-    [anon_sym_PLUS] = ACTIONS(113),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(115),
-    [anon_sym_DASH] = ACTIONS(113),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(115),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(115),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(115),
-    [anon_sym_GT] = ACTIONS(113),
-    [anon_sym_GT_EQ] = ACTIONS(115),
-    [anon_sym_LT] = ACTIONS(113),
-    [anon_sym_LT_EQ] = ACTIONS(115),
-    [anon_sym_EQ_EQ] = ACTIONS(115),
-    [anon_sym_BANG_EQ] = ACTIONS(115),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(115),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_SEMI_SEMI] = ACTIONS(113),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(113),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(113),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(115),
+    [anon_sym_PIPE] = ACTIONS(113),
+    [anon_sym_RBRACE] = ACTIONS(113),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(113),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [17] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(117),
-    [anon_sym_SEMI_SEMI] = ACTIONS(119),
-    [anon_sym_let] = ACTIONS(117),
-    [anon_sym_clock] = ACTIONS(117),
-    [anon_sym_LPAREN] = ACTIONS(117),
-    [anon_sym_RPAREN] = ACTIONS(119),
-    [sym_identifier] = ACTIONS(117),
-    [aux_sym_literal_token1] = ACTIONS(117),
-    [anon_sym_0x] = ACTIONS(119),
-    [sym_sample] = ACTIONS(119),
-    [anon_sym_BSLASH] = ACTIONS(119),
-    [anon_sym_AMP] = ACTIONS(119),
-    [anon_sym_BANG] = ACTIONS(117),
-    [anon_sym_COLON_COLON] = ACTIONS(119),
-    [anon_sym_COMMA] = ACTIONS(119),
-    [anon_sym_inl] = ACTIONS(117),
-    [anon_sym_inr] = ACTIONS(117),
-    [anon_sym_case] = ACTIONS(117),
-    [anon_sym_LBRACE] = ACTIONS(117),
-    [anon_sym_PIPE] = ACTIONS(119),
-    [anon_sym_RBRACE] = ACTIONS(119),
-    [anon_sym_LBRACK] = ACTIONS(119),
-    [anon_sym_RBRACK] = ACTIONS(119),
-    [anon_sym_PERCENT] = ACTIONS(119),
-    [sym_unit_expression] = ACTIONS(119),
-    [anon_sym_BQUOTE] = ACTIONS(119),
-    [anon_sym_box] = ACTIONS(117),
-    [anon_sym_unbox] = ACTIONS(117),
-    [anon_sym_AT] = ACTIONS(119),
-    [anon_sym_DOLLAR] = ACTIONS(119),
-    [anon_sym_STAR] = ACTIONS(119),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(119),
-    [anon_sym_SLASH] = ACTIONS(119),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(119),
-    [anon_sym_PLUS] = ACTIONS(117),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(119),
-    [anon_sym_DASH] = ACTIONS(117),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(119),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(119),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(119),
-    [anon_sym_GT] = ACTIONS(117),
-    [anon_sym_GT_EQ] = ACTIONS(119),
-    [anon_sym_LT] = ACTIONS(117),
-    [anon_sym_LT_EQ] = ACTIONS(119),
-    [anon_sym_EQ_EQ] = ACTIONS(119),
-    [anon_sym_BANG_EQ] = ACTIONS(119),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(119),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_SEMI_SEMI] = ACTIONS(117),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(117),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(117),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(119),
+    [anon_sym_PIPE] = ACTIONS(117),
+    [anon_sym_RBRACE] = ACTIONS(117),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(117),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [18] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
+    [anon_sym_COLON] = ACTIONS(89),
     [anon_sym_SEMI_SEMI] = ACTIONS(121),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
     [anon_sym_RPAREN] = ACTIONS(121),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(121),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
     [anon_sym_LBRACE] = ACTIONS(123),
     [anon_sym_PIPE] = ACTIONS(121),
     [anon_sym_RBRACE] = ACTIONS(121),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_LBRACK] = ACTIONS(73),
     [anon_sym_RBRACK] = ACTIONS(121),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [19] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(125),
-    [anon_sym_SEMI_SEMI] = ACTIONS(127),
-    [anon_sym_let] = ACTIONS(125),
-    [anon_sym_clock] = ACTIONS(125),
-    [anon_sym_LPAREN] = ACTIONS(125),
-    [anon_sym_RPAREN] = ACTIONS(127),
-    [sym_identifier] = ACTIONS(125),
-    [aux_sym_literal_token1] = ACTIONS(125),
-    [anon_sym_0x] = ACTIONS(127),
-    [sym_sample] = ACTIONS(127),
-    [anon_sym_BSLASH] = ACTIONS(127),
-    [anon_sym_AMP] = ACTIONS(127),
-    [anon_sym_BANG] = ACTIONS(125),
-    [anon_sym_COLON_COLON] = ACTIONS(127),
-    [anon_sym_COMMA] = ACTIONS(127),
-    [anon_sym_inl] = ACTIONS(125),
-    [anon_sym_inr] = ACTIONS(125),
-    [anon_sym_case] = ACTIONS(125),
-    [anon_sym_LBRACE] = ACTIONS(125),
-    [anon_sym_PIPE] = ACTIONS(127),
-    [anon_sym_RBRACE] = ACTIONS(127),
-    [anon_sym_LBRACK] = ACTIONS(127),
-    [anon_sym_RBRACK] = ACTIONS(127),
-    [anon_sym_PERCENT] = ACTIONS(127),
-    [sym_unit_expression] = ACTIONS(127),
-    [anon_sym_BQUOTE] = ACTIONS(127),
-    [anon_sym_box] = ACTIONS(125),
-    [anon_sym_unbox] = ACTIONS(125),
-    [anon_sym_AT] = ACTIONS(127),
-    [anon_sym_DOLLAR] = ACTIONS(127),
-    [anon_sym_STAR] = ACTIONS(127),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(127),
-    [anon_sym_SLASH] = ACTIONS(127),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(127),
-    [anon_sym_PLUS] = ACTIONS(125),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(127),
-    [anon_sym_DASH] = ACTIONS(125),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(127),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(127),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(127),
-    [anon_sym_GT] = ACTIONS(125),
-    [anon_sym_GT_EQ] = ACTIONS(127),
-    [anon_sym_LT] = ACTIONS(125),
-    [anon_sym_LT_EQ] = ACTIONS(127),
-    [anon_sym_EQ_EQ] = ACTIONS(127),
-    [anon_sym_BANG_EQ] = ACTIONS(127),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(127),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_SEMI_SEMI] = ACTIONS(125),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(125),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(125),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(127),
+    [anon_sym_PIPE] = ACTIONS(125),
+    [anon_sym_RBRACE] = ACTIONS(125),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(125),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [20] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
+    [anon_sym_COLON] = ACTIONS(89),
     [anon_sym_SEMI_SEMI] = ACTIONS(129),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
     [anon_sym_RPAREN] = ACTIONS(129),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(129),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
     [anon_sym_LBRACE] = ACTIONS(131),
     [anon_sym_PIPE] = ACTIONS(129),
     [anon_sym_RBRACE] = ACTIONS(129),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_LBRACK] = ACTIONS(73),
     [anon_sym_RBRACK] = ACTIONS(129),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [21] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(133),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
     [anon_sym_RBRACK] = ACTIONS(135),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [22] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
     [anon_sym_RPAREN] = ACTIONS(137),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
     [anon_sym_COMMA] = ACTIONS(139),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [23] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(141),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(143),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(133),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_RBRACK] = ACTIONS(141),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [24] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(133),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_RBRACK] = ACTIONS(145),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(143),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(145),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [25] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_RBRACE] = ACTIONS(147),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [26] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -4938,135 +5018,366 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(117),
-    [anon_sym_let] = ACTIONS(117),
-    [anon_sym_clock] = ACTIONS(117),
-    [anon_sym_LPAREN] = ACTIONS(117),
-    [sym_identifier] = ACTIONS(117),
-    [aux_sym_literal_token1] = ACTIONS(117),
-    [anon_sym_0x] = ACTIONS(119),
-    [sym_sample] = ACTIONS(119),
-    [anon_sym_BSLASH] = ACTIONS(119),
-    [anon_sym_AMP] = ACTIONS(119),
-    [anon_sym_BANG] = ACTIONS(117),
-    [anon_sym_COLON_COLON] = ACTIONS(119),
-    [anon_sym_in] = ACTIONS(117),
-    [anon_sym_inl] = ACTIONS(117),
-    [anon_sym_inr] = ACTIONS(117),
-    [anon_sym_case] = ACTIONS(117),
-    [anon_sym_LBRACK] = ACTIONS(119),
-    [anon_sym_PERCENT] = ACTIONS(119),
-    [sym_unit_expression] = ACTIONS(119),
-    [anon_sym_BQUOTE] = ACTIONS(119),
-    [anon_sym_box] = ACTIONS(117),
-    [anon_sym_unbox] = ACTIONS(117),
-    [anon_sym_AT] = ACTIONS(119),
-    [anon_sym_DOLLAR] = ACTIONS(119),
-    [anon_sym_STAR] = ACTIONS(119),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(119),
-    [anon_sym_SLASH] = ACTIONS(119),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(119),
-    [anon_sym_PLUS] = ACTIONS(117),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(119),
-    [anon_sym_DASH] = ACTIONS(117),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(119),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(119),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(119),
-    [anon_sym_GT] = ACTIONS(117),
-    [anon_sym_GT_EQ] = ACTIONS(119),
-    [anon_sym_LT] = ACTIONS(117),
-    [anon_sym_LT_EQ] = ACTIONS(119),
-    [anon_sym_EQ_EQ] = ACTIONS(119),
-    [anon_sym_BANG_EQ] = ACTIONS(119),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(119),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(119),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(119),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(173),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
+  },
+  [27] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_SEMI_SEMI] = ACTIONS(205),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [28] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(207),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
-  [26] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+  [29] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_SEMI_SEMI] = ACTIONS(147),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(209),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
-  [27] = {
-    [sym_expression] = STATE(47),
+  [30] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_COMMA] = ACTIONS(133),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [31] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5104,46 +5415,46 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(95),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(211),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [28] = {
-    [sym_expression] = STATE(47),
+  [32] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5181,46 +5492,123 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(107),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(213),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [29] = {
-    [sym_expression] = STATE(47),
+  [33] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_PIPE] = ACTIONS(215),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [34] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5258,46 +5646,123 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(111),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(217),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [30] = {
-    [sym_expression] = STATE(47),
+  [35] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACE] = ACTIONS(219),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [36] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5323,366 +5788,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(131),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
-  },
-  [31] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_PIPE] = ACTIONS(203),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [32] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_RBRACE] = ACTIONS(205),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [33] = {
-    [sym_expression] = STATE(47),
-    [sym_wrap_expression] = STATE(110),
-    [sym_literal] = STATE(110),
-    [sym_application_expression] = STATE(110),
-    [sym_lambda_expression] = STATE(110),
-    [sym_lob_expression] = STATE(110),
-    [sym_force_expression] = STATE(110),
-    [sym_gen_expression] = STATE(110),
-    [sym_let_expression] = STATE(110),
-    [sym_annotate_expression] = STATE(110),
-    [sym_pair_expression] = STATE(110),
-    [sym_unpair_expression] = STATE(110),
-    [sym_inl_expression] = STATE(110),
-    [sym_inr_expression] = STATE(110),
-    [sym_case_expression] = STATE(110),
-    [sym_array_expression] = STATE(110),
-    [sym_ungen_expression] = STATE(110),
-    [sym_delay_expression] = STATE(110),
-    [sym_box_expression] = STATE(110),
-    [sym_unbox_expression] = STATE(110),
-    [sym_clockapp_expression] = STATE(110),
-    [sym_typeapp_expression] = STATE(110),
-    [sym_binop_expression] = STATE(110),
-    [sym_ex_intro] = STATE(110),
-    [sym_ex_elim] = STATE(110),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(207),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
-  },
-  [34] = {
-    [sym_expression] = STATE(47),
-    [sym_wrap_expression] = STATE(110),
-    [sym_literal] = STATE(110),
-    [sym_application_expression] = STATE(110),
-    [sym_lambda_expression] = STATE(110),
-    [sym_lob_expression] = STATE(110),
-    [sym_force_expression] = STATE(110),
-    [sym_gen_expression] = STATE(110),
-    [sym_let_expression] = STATE(110),
-    [sym_annotate_expression] = STATE(110),
-    [sym_pair_expression] = STATE(110),
-    [sym_unpair_expression] = STATE(110),
-    [sym_inl_expression] = STATE(110),
-    [sym_inr_expression] = STATE(110),
-    [sym_case_expression] = STATE(110),
-    [sym_array_expression] = STATE(110),
-    [sym_ungen_expression] = STATE(110),
-    [sym_delay_expression] = STATE(110),
-    [sym_box_expression] = STATE(110),
-    [sym_unbox_expression] = STATE(110),
-    [sym_clockapp_expression] = STATE(110),
-    [sym_typeapp_expression] = STATE(110),
-    [sym_binop_expression] = STATE(110),
-    [sym_ex_intro] = STATE(110),
-    [sym_ex_elim] = STATE(110),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(87),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_COLON] = ACTIONS(15),
+    [anon_sym_let] = ACTIONS(15),
+    [anon_sym_clock] = ACTIONS(15),
+    [anon_sym_LPAREN] = ACTIONS(15),
+    [sym_identifier] = ACTIONS(15),
+    [aux_sym_literal_token1] = ACTIONS(15),
+    [anon_sym_0x] = ACTIONS(17),
+    [sym_sample] = ACTIONS(17),
+    [anon_sym_BSLASH] = ACTIONS(17),
+    [anon_sym_AMP] = ACTIONS(17),
+    [anon_sym_BANG] = ACTIONS(15),
+    [anon_sym_COLON_COLON] = ACTIONS(17),
+    [anon_sym_in] = ACTIONS(15),
+    [anon_sym_inl] = ACTIONS(15),
+    [anon_sym_inr] = ACTIONS(15),
+    [anon_sym_case] = ACTIONS(15),
+    [anon_sym_LBRACK] = ACTIONS(17),
+    [anon_sym_PERCENT] = ACTIONS(17),
+    [sym_unit_expression] = ACTIONS(17),
+    [anon_sym_BQUOTE] = ACTIONS(17),
+    [anon_sym_box] = ACTIONS(15),
+    [anon_sym_unbox] = ACTIONS(15),
+    [anon_sym_AT] = ACTIONS(17),
+    [anon_sym_DOLLAR] = ACTIONS(17),
+    [anon_sym_STAR] = ACTIONS(17),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(17),
+    [anon_sym_SLASH] = ACTIONS(17),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(17),
+    [anon_sym_PLUS] = ACTIONS(15),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(17),
+    [anon_sym_DASH] = ACTIONS(15),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(17),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(17),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(17),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(17),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(17),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(17),
+    [anon_sym_GT] = ACTIONS(15),
+    [anon_sym_GT_EQ] = ACTIONS(17),
+    [anon_sym_LT] = ACTIONS(15),
+    [anon_sym_LT_EQ] = ACTIONS(17),
+    [anon_sym_EQ_EQ] = ACTIONS(17),
+    [anon_sym_BANG_EQ] = ACTIONS(17),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(17),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(17),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(17),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(17),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(17),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(17),
   },
-  [35] = {
-    [sym_expression] = STATE(47),
+  [37] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5708,58 +5865,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(75),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_COLON] = ACTIONS(19),
+    [anon_sym_let] = ACTIONS(19),
+    [anon_sym_clock] = ACTIONS(19),
+    [anon_sym_LPAREN] = ACTIONS(19),
+    [sym_identifier] = ACTIONS(19),
+    [aux_sym_literal_token1] = ACTIONS(19),
+    [anon_sym_0x] = ACTIONS(21),
+    [sym_sample] = ACTIONS(21),
+    [anon_sym_BSLASH] = ACTIONS(21),
+    [anon_sym_AMP] = ACTIONS(21),
+    [anon_sym_BANG] = ACTIONS(19),
+    [anon_sym_COLON_COLON] = ACTIONS(21),
+    [anon_sym_in] = ACTIONS(19),
+    [anon_sym_inl] = ACTIONS(19),
+    [anon_sym_inr] = ACTIONS(19),
+    [anon_sym_case] = ACTIONS(19),
+    [anon_sym_LBRACK] = ACTIONS(21),
+    [anon_sym_PERCENT] = ACTIONS(21),
+    [sym_unit_expression] = ACTIONS(21),
+    [anon_sym_BQUOTE] = ACTIONS(21),
+    [anon_sym_box] = ACTIONS(19),
+    [anon_sym_unbox] = ACTIONS(19),
+    [anon_sym_AT] = ACTIONS(21),
+    [anon_sym_DOLLAR] = ACTIONS(21),
+    [anon_sym_STAR] = ACTIONS(21),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(21),
+    [anon_sym_SLASH] = ACTIONS(21),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(21),
+    [anon_sym_PLUS] = ACTIONS(19),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(21),
+    [anon_sym_DASH] = ACTIONS(19),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(21),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(21),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(21),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(21),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(21),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(21),
+    [anon_sym_GT] = ACTIONS(19),
+    [anon_sym_GT_EQ] = ACTIONS(21),
+    [anon_sym_LT] = ACTIONS(19),
+    [anon_sym_LT_EQ] = ACTIONS(21),
+    [anon_sym_EQ_EQ] = ACTIONS(21),
+    [anon_sym_BANG_EQ] = ACTIONS(21),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(21),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(21),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(21),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(21),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(21),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(21),
   },
-  [36] = {
-    [sym_expression] = STATE(47),
+  [38] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5785,58 +5942,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(209),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_COLON] = ACTIONS(23),
+    [anon_sym_let] = ACTIONS(23),
+    [anon_sym_clock] = ACTIONS(23),
+    [anon_sym_LPAREN] = ACTIONS(23),
+    [sym_identifier] = ACTIONS(23),
+    [aux_sym_literal_token1] = ACTIONS(23),
+    [anon_sym_0x] = ACTIONS(25),
+    [sym_sample] = ACTIONS(25),
+    [anon_sym_BSLASH] = ACTIONS(25),
+    [anon_sym_AMP] = ACTIONS(25),
+    [anon_sym_BANG] = ACTIONS(23),
+    [anon_sym_COLON_COLON] = ACTIONS(25),
+    [anon_sym_in] = ACTIONS(23),
+    [anon_sym_inl] = ACTIONS(23),
+    [anon_sym_inr] = ACTIONS(23),
+    [anon_sym_case] = ACTIONS(23),
+    [anon_sym_LBRACK] = ACTIONS(25),
+    [anon_sym_PERCENT] = ACTIONS(25),
+    [sym_unit_expression] = ACTIONS(25),
+    [anon_sym_BQUOTE] = ACTIONS(25),
+    [anon_sym_box] = ACTIONS(23),
+    [anon_sym_unbox] = ACTIONS(23),
+    [anon_sym_AT] = ACTIONS(25),
+    [anon_sym_DOLLAR] = ACTIONS(25),
+    [anon_sym_STAR] = ACTIONS(25),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(25),
+    [anon_sym_SLASH] = ACTIONS(25),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(25),
+    [anon_sym_PLUS] = ACTIONS(23),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(25),
+    [anon_sym_DASH] = ACTIONS(23),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(25),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(25),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(25),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(25),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(25),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(25),
+    [anon_sym_GT] = ACTIONS(23),
+    [anon_sym_GT_EQ] = ACTIONS(25),
+    [anon_sym_LT] = ACTIONS(23),
+    [anon_sym_LT_EQ] = ACTIONS(25),
+    [anon_sym_EQ_EQ] = ACTIONS(25),
+    [anon_sym_BANG_EQ] = ACTIONS(25),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
   },
-  [37] = {
-    [sym_expression] = STATE(47),
+  [39] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5862,58 +6019,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(211),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_COLON] = ACTIONS(27),
+    [anon_sym_let] = ACTIONS(27),
+    [anon_sym_clock] = ACTIONS(27),
+    [anon_sym_LPAREN] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(27),
+    [aux_sym_literal_token1] = ACTIONS(27),
+    [anon_sym_0x] = ACTIONS(29),
+    [sym_sample] = ACTIONS(29),
+    [anon_sym_BSLASH] = ACTIONS(29),
+    [anon_sym_AMP] = ACTIONS(29),
+    [anon_sym_BANG] = ACTIONS(27),
+    [anon_sym_COLON_COLON] = ACTIONS(29),
+    [anon_sym_in] = ACTIONS(27),
+    [anon_sym_inl] = ACTIONS(27),
+    [anon_sym_inr] = ACTIONS(27),
+    [anon_sym_case] = ACTIONS(27),
+    [anon_sym_LBRACK] = ACTIONS(29),
+    [anon_sym_PERCENT] = ACTIONS(29),
+    [sym_unit_expression] = ACTIONS(29),
+    [anon_sym_BQUOTE] = ACTIONS(29),
+    [anon_sym_box] = ACTIONS(27),
+    [anon_sym_unbox] = ACTIONS(27),
+    [anon_sym_AT] = ACTIONS(29),
+    [anon_sym_DOLLAR] = ACTIONS(29),
+    [anon_sym_STAR] = ACTIONS(29),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(29),
+    [anon_sym_SLASH] = ACTIONS(29),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(29),
+    [anon_sym_PLUS] = ACTIONS(27),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(29),
+    [anon_sym_DASH] = ACTIONS(27),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(29),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(29),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(29),
+    [anon_sym_GT] = ACTIONS(27),
+    [anon_sym_GT_EQ] = ACTIONS(29),
+    [anon_sym_LT] = ACTIONS(27),
+    [anon_sym_LT_EQ] = ACTIONS(29),
+    [anon_sym_EQ_EQ] = ACTIONS(29),
+    [anon_sym_BANG_EQ] = ACTIONS(29),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(29),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(29),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(29),
   },
-  [38] = {
-    [sym_expression] = STATE(47),
+  [40] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -5939,58 +6096,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(213),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_COLON] = ACTIONS(11),
+    [anon_sym_let] = ACTIONS(11),
+    [anon_sym_clock] = ACTIONS(11),
+    [anon_sym_LPAREN] = ACTIONS(11),
+    [sym_identifier] = ACTIONS(11),
+    [aux_sym_literal_token1] = ACTIONS(11),
+    [anon_sym_0x] = ACTIONS(13),
+    [sym_sample] = ACTIONS(13),
+    [anon_sym_BSLASH] = ACTIONS(13),
+    [anon_sym_AMP] = ACTIONS(13),
+    [anon_sym_BANG] = ACTIONS(11),
+    [anon_sym_COLON_COLON] = ACTIONS(13),
+    [anon_sym_in] = ACTIONS(11),
+    [anon_sym_inl] = ACTIONS(11),
+    [anon_sym_inr] = ACTIONS(11),
+    [anon_sym_case] = ACTIONS(11),
+    [anon_sym_LBRACK] = ACTIONS(13),
+    [anon_sym_PERCENT] = ACTIONS(13),
+    [sym_unit_expression] = ACTIONS(13),
+    [anon_sym_BQUOTE] = ACTIONS(13),
+    [anon_sym_box] = ACTIONS(11),
+    [anon_sym_unbox] = ACTIONS(11),
+    [anon_sym_AT] = ACTIONS(13),
+    [anon_sym_DOLLAR] = ACTIONS(13),
+    [anon_sym_STAR] = ACTIONS(13),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(13),
+    [anon_sym_SLASH] = ACTIONS(13),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(13),
+    [anon_sym_PLUS] = ACTIONS(11),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(13),
+    [anon_sym_DASH] = ACTIONS(11),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(13),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(13),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(13),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(13),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(13),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(13),
+    [anon_sym_GT] = ACTIONS(11),
+    [anon_sym_GT_EQ] = ACTIONS(13),
+    [anon_sym_LT] = ACTIONS(11),
+    [anon_sym_LT_EQ] = ACTIONS(13),
+    [anon_sym_EQ_EQ] = ACTIONS(13),
+    [anon_sym_BANG_EQ] = ACTIONS(13),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(13),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(13),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(13),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(13),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(13),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(13),
   },
-  [39] = {
-    [sym_expression] = STATE(47),
+  [41] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6016,58 +6173,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(23),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(25),
-    [anon_sym_in] = ACTIONS(23),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(25),
-    [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(23),
-    [anon_sym_GT_EQ] = ACTIONS(25),
-    [anon_sym_LT] = ACTIONS(23),
-    [anon_sym_LT_EQ] = ACTIONS(25),
-    [anon_sym_EQ_EQ] = ACTIONS(25),
-    [anon_sym_BANG_EQ] = ACTIONS(25),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
+    [anon_sym_COLON] = ACTIONS(31),
+    [anon_sym_let] = ACTIONS(31),
+    [anon_sym_clock] = ACTIONS(31),
+    [anon_sym_LPAREN] = ACTIONS(31),
+    [sym_identifier] = ACTIONS(31),
+    [aux_sym_literal_token1] = ACTIONS(31),
+    [anon_sym_0x] = ACTIONS(33),
+    [sym_sample] = ACTIONS(33),
+    [anon_sym_BSLASH] = ACTIONS(33),
+    [anon_sym_AMP] = ACTIONS(33),
+    [anon_sym_BANG] = ACTIONS(31),
+    [anon_sym_COLON_COLON] = ACTIONS(33),
+    [anon_sym_in] = ACTIONS(31),
+    [anon_sym_inl] = ACTIONS(31),
+    [anon_sym_inr] = ACTIONS(31),
+    [anon_sym_case] = ACTIONS(31),
+    [anon_sym_LBRACK] = ACTIONS(33),
+    [anon_sym_PERCENT] = ACTIONS(33),
+    [sym_unit_expression] = ACTIONS(33),
+    [anon_sym_BQUOTE] = ACTIONS(33),
+    [anon_sym_box] = ACTIONS(31),
+    [anon_sym_unbox] = ACTIONS(31),
+    [anon_sym_AT] = ACTIONS(33),
+    [anon_sym_DOLLAR] = ACTIONS(33),
+    [anon_sym_STAR] = ACTIONS(33),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(33),
+    [anon_sym_SLASH] = ACTIONS(33),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(33),
+    [anon_sym_PLUS] = ACTIONS(31),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(33),
+    [anon_sym_DASH] = ACTIONS(31),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(33),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(33),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(33),
+    [anon_sym_GT] = ACTIONS(31),
+    [anon_sym_GT_EQ] = ACTIONS(33),
+    [anon_sym_LT] = ACTIONS(31),
+    [anon_sym_LT_EQ] = ACTIONS(33),
+    [anon_sym_EQ_EQ] = ACTIONS(33),
+    [anon_sym_BANG_EQ] = ACTIONS(33),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(33),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(33),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(33),
   },
-  [40] = {
-    [sym_expression] = STATE(47),
+  [42] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6090,61 +6247,61 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_clockapp_expression] = STATE(110),
     [sym_typeapp_expression] = STATE(110),
     [sym_binop_expression] = STATE(110),
-    [sym_ex_intro] = STATE(110),
-    [sym_ex_elim] = STATE(110),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(23),
-    [anon_sym_let] = ACTIONS(151),
-    [anon_sym_clock] = ACTIONS(153),
-    [anon_sym_LPAREN] = ACTIONS(155),
-    [sym_identifier] = ACTIONS(157),
-    [aux_sym_literal_token1] = ACTIONS(159),
-    [anon_sym_0x] = ACTIONS(161),
-    [sym_sample] = ACTIONS(163),
-    [anon_sym_BSLASH] = ACTIONS(165),
-    [anon_sym_AMP] = ACTIONS(167),
-    [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(25),
-    [anon_sym_in] = ACTIONS(23),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
-    [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(25),
-    [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(23),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(25),
-    [anon_sym_DASH] = ACTIONS(23),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(25),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(25),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(25),
-    [anon_sym_GT] = ACTIONS(23),
-    [anon_sym_GT_EQ] = ACTIONS(25),
-    [anon_sym_LT] = ACTIONS(23),
-    [anon_sym_LT_EQ] = ACTIONS(25),
-    [anon_sym_EQ_EQ] = ACTIONS(25),
-    [anon_sym_BANG_EQ] = ACTIONS(25),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
+    [sym_ex_intro] = STATE(110),
+    [sym_ex_elim] = STATE(110),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(35),
+    [anon_sym_let] = ACTIONS(35),
+    [anon_sym_clock] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(35),
+    [sym_identifier] = ACTIONS(35),
+    [aux_sym_literal_token1] = ACTIONS(35),
+    [anon_sym_0x] = ACTIONS(37),
+    [sym_sample] = ACTIONS(37),
+    [anon_sym_BSLASH] = ACTIONS(37),
+    [anon_sym_AMP] = ACTIONS(37),
+    [anon_sym_BANG] = ACTIONS(35),
+    [anon_sym_COLON_COLON] = ACTIONS(37),
+    [anon_sym_in] = ACTIONS(35),
+    [anon_sym_inl] = ACTIONS(35),
+    [anon_sym_inr] = ACTIONS(35),
+    [anon_sym_case] = ACTIONS(35),
+    [anon_sym_LBRACK] = ACTIONS(37),
+    [anon_sym_PERCENT] = ACTIONS(37),
+    [sym_unit_expression] = ACTIONS(37),
+    [anon_sym_BQUOTE] = ACTIONS(37),
+    [anon_sym_box] = ACTIONS(35),
+    [anon_sym_unbox] = ACTIONS(35),
+    [anon_sym_AT] = ACTIONS(37),
+    [anon_sym_DOLLAR] = ACTIONS(37),
+    [anon_sym_STAR] = ACTIONS(37),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(37),
+    [anon_sym_SLASH] = ACTIONS(37),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(37),
+    [anon_sym_PLUS] = ACTIONS(35),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(37),
+    [anon_sym_DASH] = ACTIONS(35),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(37),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(37),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(37),
+    [anon_sym_GT] = ACTIONS(35),
+    [anon_sym_GT_EQ] = ACTIONS(37),
+    [anon_sym_LT] = ACTIONS(35),
+    [anon_sym_LT_EQ] = ACTIONS(37),
+    [anon_sym_EQ_EQ] = ACTIONS(37),
+    [anon_sym_BANG_EQ] = ACTIONS(37),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(37),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(37),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(37),
   },
-  [41] = {
-    [sym_expression] = STATE(47),
+  [43] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6170,7 +6327,84 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(23),
+    [anon_sym_COLON] = ACTIONS(39),
+    [anon_sym_let] = ACTIONS(39),
+    [anon_sym_clock] = ACTIONS(39),
+    [anon_sym_LPAREN] = ACTIONS(39),
+    [sym_identifier] = ACTIONS(39),
+    [aux_sym_literal_token1] = ACTIONS(39),
+    [anon_sym_0x] = ACTIONS(41),
+    [sym_sample] = ACTIONS(41),
+    [anon_sym_BSLASH] = ACTIONS(41),
+    [anon_sym_AMP] = ACTIONS(41),
+    [anon_sym_BANG] = ACTIONS(39),
+    [anon_sym_COLON_COLON] = ACTIONS(41),
+    [anon_sym_in] = ACTIONS(39),
+    [anon_sym_inl] = ACTIONS(39),
+    [anon_sym_inr] = ACTIONS(39),
+    [anon_sym_case] = ACTIONS(39),
+    [anon_sym_LBRACK] = ACTIONS(41),
+    [anon_sym_PERCENT] = ACTIONS(41),
+    [sym_unit_expression] = ACTIONS(41),
+    [anon_sym_BQUOTE] = ACTIONS(41),
+    [anon_sym_box] = ACTIONS(39),
+    [anon_sym_unbox] = ACTIONS(39),
+    [anon_sym_AT] = ACTIONS(41),
+    [anon_sym_DOLLAR] = ACTIONS(41),
+    [anon_sym_STAR] = ACTIONS(41),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(41),
+    [anon_sym_SLASH] = ACTIONS(41),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(41),
+    [anon_sym_PLUS] = ACTIONS(39),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(41),
+    [anon_sym_DASH] = ACTIONS(39),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(41),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(41),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(41),
+    [anon_sym_GT] = ACTIONS(39),
+    [anon_sym_GT_EQ] = ACTIONS(41),
+    [anon_sym_LT] = ACTIONS(39),
+    [anon_sym_LT_EQ] = ACTIONS(41),
+    [anon_sym_EQ_EQ] = ACTIONS(41),
+    [anon_sym_BANG_EQ] = ACTIONS(41),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(41),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(41),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(41),
+  },
+  [44] = {
+    [sym_expression] = STATE(43),
+    [sym_wrap_expression] = STATE(110),
+    [sym_literal] = STATE(110),
+    [sym_application_expression] = STATE(110),
+    [sym_lambda_expression] = STATE(110),
+    [sym_lob_expression] = STATE(110),
+    [sym_force_expression] = STATE(110),
+    [sym_gen_expression] = STATE(110),
+    [sym_let_expression] = STATE(110),
+    [sym_annotate_expression] = STATE(110),
+    [sym_pair_expression] = STATE(110),
+    [sym_unpair_expression] = STATE(110),
+    [sym_inl_expression] = STATE(110),
+    [sym_inr_expression] = STATE(110),
+    [sym_case_expression] = STATE(110),
+    [sym_array_expression] = STATE(110),
+    [sym_ungen_expression] = STATE(110),
+    [sym_delay_expression] = STATE(110),
+    [sym_box_expression] = STATE(110),
+    [sym_unbox_expression] = STATE(110),
+    [sym_clockapp_expression] = STATE(110),
+    [sym_typeapp_expression] = STATE(110),
+    [sym_binop_expression] = STATE(110),
+    [sym_ex_intro] = STATE(110),
+    [sym_ex_elim] = STATE(110),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(149),
     [anon_sym_let] = ACTIONS(151),
     [anon_sym_clock] = ACTIONS(153),
     [anon_sym_LPAREN] = ACTIONS(155),
@@ -6181,47 +6415,47 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_BSLASH] = ACTIONS(165),
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(25),
-    [anon_sym_in] = ACTIONS(23),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(107),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(25),
-    [anon_sym_DOLLAR] = ACTIONS(25),
-    [anon_sym_STAR] = ACTIONS(25),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(25),
-    [anon_sym_SLASH] = ACTIONS(25),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(25),
-    [anon_sym_PLUS] = ACTIONS(23),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(25),
-    [anon_sym_DASH] = ACTIONS(23),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(25),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(25),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(25),
-    [anon_sym_GT] = ACTIONS(23),
-    [anon_sym_GT_EQ] = ACTIONS(25),
-    [anon_sym_LT] = ACTIONS(23),
-    [anon_sym_LT_EQ] = ACTIONS(25),
-    [anon_sym_EQ_EQ] = ACTIONS(25),
-    [anon_sym_BANG_EQ] = ACTIONS(25),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(25),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(25),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(25),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [42] = {
-    [sym_expression] = STATE(47),
+  [45] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6247,7 +6481,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_COLON] = ACTIONS(43),
     [anon_sym_let] = ACTIONS(151),
     [anon_sym_clock] = ACTIONS(153),
     [anon_sym_LPAREN] = ACTIONS(155),
@@ -6258,201 +6492,47 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_BSLASH] = ACTIONS(165),
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(123),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_in] = ACTIONS(43),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
-  },
-  [43] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(215),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [44] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [anon_sym_RPAREN] = ACTIONS(217),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(45),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(45),
+    [anon_sym_SLASH] = ACTIONS(45),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(45),
+    [anon_sym_PLUS] = ACTIONS(43),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(45),
+    [anon_sym_DASH] = ACTIONS(43),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(45),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(45),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(45),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
-  [45] = {
-    [sym_expression] = STATE(47),
+  [46] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6478,7 +6558,7 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_COLON] = ACTIONS(43),
     [anon_sym_let] = ACTIONS(151),
     [anon_sym_clock] = ACTIONS(153),
     [anon_sym_LPAREN] = ACTIONS(155),
@@ -6489,124 +6569,47 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_BSLASH] = ACTIONS(165),
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
-    [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(219),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_in] = ACTIONS(43),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
-  },
-  [46] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_COMMA] = ACTIONS(133),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(43),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(45),
+    [anon_sym_DASH] = ACTIONS(43),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(45),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(45),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(45),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
   [47] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6632,58 +6635,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(19),
-    [anon_sym_let] = ACTIONS(19),
-    [anon_sym_clock] = ACTIONS(19),
-    [anon_sym_LPAREN] = ACTIONS(19),
-    [sym_identifier] = ACTIONS(19),
-    [aux_sym_literal_token1] = ACTIONS(19),
-    [anon_sym_0x] = ACTIONS(21),
-    [sym_sample] = ACTIONS(21),
-    [anon_sym_BSLASH] = ACTIONS(21),
-    [anon_sym_AMP] = ACTIONS(21),
-    [anon_sym_BANG] = ACTIONS(19),
-    [anon_sym_COLON_COLON] = ACTIONS(21),
-    [anon_sym_in] = ACTIONS(19),
-    [anon_sym_inl] = ACTIONS(19),
-    [anon_sym_inr] = ACTIONS(19),
-    [anon_sym_case] = ACTIONS(19),
-    [anon_sym_LBRACK] = ACTIONS(21),
-    [anon_sym_PERCENT] = ACTIONS(21),
-    [sym_unit_expression] = ACTIONS(21),
-    [anon_sym_BQUOTE] = ACTIONS(21),
-    [anon_sym_box] = ACTIONS(19),
-    [anon_sym_unbox] = ACTIONS(19),
-    [anon_sym_AT] = ACTIONS(21),
-    [anon_sym_DOLLAR] = ACTIONS(21),
-    [anon_sym_STAR] = ACTIONS(21),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(21),
-    [anon_sym_SLASH] = ACTIONS(21),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(21),
-    [anon_sym_PLUS] = ACTIONS(19),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(21),
-    [anon_sym_DASH] = ACTIONS(19),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(21),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(21),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(21),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(21),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(21),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(21),
-    [anon_sym_GT] = ACTIONS(19),
-    [anon_sym_GT_EQ] = ACTIONS(21),
-    [anon_sym_LT] = ACTIONS(19),
-    [anon_sym_LT_EQ] = ACTIONS(21),
-    [anon_sym_EQ_EQ] = ACTIONS(21),
-    [anon_sym_BANG_EQ] = ACTIONS(21),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(21),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(21),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(21),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(21),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(21),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(21),
+    [anon_sym_COLON] = ACTIONS(43),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(45),
+    [anon_sym_in] = ACTIONS(43),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(45),
+    [anon_sym_DOLLAR] = ACTIONS(45),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(43),
+    [anon_sym_GT_EQ] = ACTIONS(45),
+    [anon_sym_LT] = ACTIONS(43),
+    [anon_sym_LT_EQ] = ACTIONS(45),
+    [anon_sym_EQ_EQ] = ACTIONS(45),
+    [anon_sym_BANG_EQ] = ACTIONS(45),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(45),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(45),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(45),
   },
   [48] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6709,135 +6712,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(89),
-    [anon_sym_let] = ACTIONS(89),
-    [anon_sym_clock] = ACTIONS(89),
-    [anon_sym_LPAREN] = ACTIONS(89),
-    [sym_identifier] = ACTIONS(89),
-    [aux_sym_literal_token1] = ACTIONS(89),
-    [anon_sym_0x] = ACTIONS(91),
-    [sym_sample] = ACTIONS(91),
-    [anon_sym_BSLASH] = ACTIONS(91),
-    [anon_sym_AMP] = ACTIONS(91),
-    [anon_sym_BANG] = ACTIONS(89),
-    [anon_sym_COLON_COLON] = ACTIONS(91),
-    [anon_sym_in] = ACTIONS(89),
-    [anon_sym_inl] = ACTIONS(89),
-    [anon_sym_inr] = ACTIONS(89),
-    [anon_sym_case] = ACTIONS(89),
-    [anon_sym_LBRACK] = ACTIONS(91),
-    [anon_sym_PERCENT] = ACTIONS(91),
-    [sym_unit_expression] = ACTIONS(91),
-    [anon_sym_BQUOTE] = ACTIONS(91),
-    [anon_sym_box] = ACTIONS(89),
-    [anon_sym_unbox] = ACTIONS(89),
-    [anon_sym_AT] = ACTIONS(91),
-    [anon_sym_DOLLAR] = ACTIONS(91),
-    [anon_sym_STAR] = ACTIONS(91),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(91),
-    [anon_sym_SLASH] = ACTIONS(91),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(91),
-    [anon_sym_PLUS] = ACTIONS(89),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(91),
-    [anon_sym_DASH] = ACTIONS(89),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(91),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(91),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(91),
-    [anon_sym_GT] = ACTIONS(89),
-    [anon_sym_GT_EQ] = ACTIONS(91),
-    [anon_sym_LT] = ACTIONS(89),
-    [anon_sym_LT_EQ] = ACTIONS(91),
-    [anon_sym_EQ_EQ] = ACTIONS(91),
-    [anon_sym_BANG_EQ] = ACTIONS(91),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(91),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(91),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(91),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(95),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [49] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(221),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [50] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6875,46 +6801,46 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(223),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(131),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [51] = {
-    [sym_expression] = STATE(47),
+  [50] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -6940,58 +6866,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(97),
-    [anon_sym_let] = ACTIONS(97),
-    [anon_sym_clock] = ACTIONS(97),
-    [anon_sym_LPAREN] = ACTIONS(97),
-    [sym_identifier] = ACTIONS(97),
-    [aux_sym_literal_token1] = ACTIONS(97),
-    [anon_sym_0x] = ACTIONS(99),
-    [sym_sample] = ACTIONS(99),
-    [anon_sym_BSLASH] = ACTIONS(99),
-    [anon_sym_AMP] = ACTIONS(99),
-    [anon_sym_BANG] = ACTIONS(97),
-    [anon_sym_COLON_COLON] = ACTIONS(99),
-    [anon_sym_in] = ACTIONS(97),
-    [anon_sym_inl] = ACTIONS(97),
-    [anon_sym_inr] = ACTIONS(97),
-    [anon_sym_case] = ACTIONS(97),
-    [anon_sym_LBRACK] = ACTIONS(99),
-    [anon_sym_PERCENT] = ACTIONS(99),
-    [sym_unit_expression] = ACTIONS(99),
-    [anon_sym_BQUOTE] = ACTIONS(99),
-    [anon_sym_box] = ACTIONS(97),
-    [anon_sym_unbox] = ACTIONS(97),
-    [anon_sym_AT] = ACTIONS(99),
-    [anon_sym_DOLLAR] = ACTIONS(99),
-    [anon_sym_STAR] = ACTIONS(99),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(99),
-    [anon_sym_SLASH] = ACTIONS(99),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(99),
-    [anon_sym_PLUS] = ACTIONS(97),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(99),
-    [anon_sym_DASH] = ACTIONS(97),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(99),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(99),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(99),
-    [anon_sym_GT] = ACTIONS(97),
-    [anon_sym_GT_EQ] = ACTIONS(99),
-    [anon_sym_LT] = ACTIONS(97),
-    [anon_sym_LT_EQ] = ACTIONS(99),
-    [anon_sym_EQ_EQ] = ACTIONS(99),
-    [anon_sym_BANG_EQ] = ACTIONS(99),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(99),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(99),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(99),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(111),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [52] = {
-    [sym_expression] = STATE(47),
+  [51] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7017,58 +6943,135 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(11),
-    [anon_sym_let] = ACTIONS(11),
-    [anon_sym_clock] = ACTIONS(11),
-    [anon_sym_LPAREN] = ACTIONS(11),
-    [sym_identifier] = ACTIONS(11),
-    [aux_sym_literal_token1] = ACTIONS(11),
-    [anon_sym_0x] = ACTIONS(13),
-    [sym_sample] = ACTIONS(13),
-    [anon_sym_BSLASH] = ACTIONS(13),
-    [anon_sym_AMP] = ACTIONS(13),
-    [anon_sym_BANG] = ACTIONS(11),
-    [anon_sym_COLON_COLON] = ACTIONS(13),
-    [anon_sym_in] = ACTIONS(11),
-    [anon_sym_inl] = ACTIONS(11),
-    [anon_sym_inr] = ACTIONS(11),
-    [anon_sym_case] = ACTIONS(11),
-    [anon_sym_LBRACK] = ACTIONS(13),
-    [anon_sym_PERCENT] = ACTIONS(13),
-    [sym_unit_expression] = ACTIONS(13),
-    [anon_sym_BQUOTE] = ACTIONS(13),
-    [anon_sym_box] = ACTIONS(11),
-    [anon_sym_unbox] = ACTIONS(11),
-    [anon_sym_AT] = ACTIONS(13),
-    [anon_sym_DOLLAR] = ACTIONS(13),
-    [anon_sym_STAR] = ACTIONS(13),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(13),
-    [anon_sym_SLASH] = ACTIONS(13),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(13),
-    [anon_sym_PLUS] = ACTIONS(11),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(13),
-    [anon_sym_DASH] = ACTIONS(11),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(13),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(13),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(13),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(13),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(13),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(13),
-    [anon_sym_GT] = ACTIONS(11),
-    [anon_sym_GT_EQ] = ACTIONS(13),
-    [anon_sym_LT] = ACTIONS(11),
-    [anon_sym_LT_EQ] = ACTIONS(13),
-    [anon_sym_EQ_EQ] = ACTIONS(13),
-    [anon_sym_BANG_EQ] = ACTIONS(13),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(13),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(13),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(13),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(13),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(13),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(13),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(115),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
+  },
+  [52] = {
+    [sym_expression] = STATE(43),
+    [sym_wrap_expression] = STATE(110),
+    [sym_literal] = STATE(110),
+    [sym_application_expression] = STATE(110),
+    [sym_lambda_expression] = STATE(110),
+    [sym_lob_expression] = STATE(110),
+    [sym_force_expression] = STATE(110),
+    [sym_gen_expression] = STATE(110),
+    [sym_let_expression] = STATE(110),
+    [sym_annotate_expression] = STATE(110),
+    [sym_pair_expression] = STATE(110),
+    [sym_unpair_expression] = STATE(110),
+    [sym_inl_expression] = STATE(110),
+    [sym_inr_expression] = STATE(110),
+    [sym_case_expression] = STATE(110),
+    [sym_array_expression] = STATE(110),
+    [sym_ungen_expression] = STATE(110),
+    [sym_delay_expression] = STATE(110),
+    [sym_box_expression] = STATE(110),
+    [sym_unbox_expression] = STATE(110),
+    [sym_clockapp_expression] = STATE(110),
+    [sym_typeapp_expression] = STATE(110),
+    [sym_binop_expression] = STATE(110),
+    [sym_ex_intro] = STATE(110),
+    [sym_ex_elim] = STATE(110),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(119),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [53] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7094,58 +7097,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(125),
-    [anon_sym_let] = ACTIONS(125),
-    [anon_sym_clock] = ACTIONS(125),
-    [anon_sym_LPAREN] = ACTIONS(125),
-    [sym_identifier] = ACTIONS(125),
-    [aux_sym_literal_token1] = ACTIONS(125),
-    [anon_sym_0x] = ACTIONS(127),
-    [sym_sample] = ACTIONS(127),
-    [anon_sym_BSLASH] = ACTIONS(127),
-    [anon_sym_AMP] = ACTIONS(127),
-    [anon_sym_BANG] = ACTIONS(125),
-    [anon_sym_COLON_COLON] = ACTIONS(127),
-    [anon_sym_in] = ACTIONS(125),
-    [anon_sym_inl] = ACTIONS(125),
-    [anon_sym_inr] = ACTIONS(125),
-    [anon_sym_case] = ACTIONS(125),
-    [anon_sym_LBRACK] = ACTIONS(127),
-    [anon_sym_PERCENT] = ACTIONS(127),
-    [sym_unit_expression] = ACTIONS(127),
-    [anon_sym_BQUOTE] = ACTIONS(127),
-    [anon_sym_box] = ACTIONS(125),
-    [anon_sym_unbox] = ACTIONS(125),
-    [anon_sym_AT] = ACTIONS(127),
-    [anon_sym_DOLLAR] = ACTIONS(127),
-    [anon_sym_STAR] = ACTIONS(127),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(127),
-    [anon_sym_SLASH] = ACTIONS(127),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(127),
-    [anon_sym_PLUS] = ACTIONS(125),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(127),
-    [anon_sym_DASH] = ACTIONS(125),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(127),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(127),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(127),
-    [anon_sym_GT] = ACTIONS(125),
-    [anon_sym_GT_EQ] = ACTIONS(127),
-    [anon_sym_LT] = ACTIONS(125),
-    [anon_sym_LT_EQ] = ACTIONS(127),
-    [anon_sym_EQ_EQ] = ACTIONS(127),
-    [anon_sym_BANG_EQ] = ACTIONS(127),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(127),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(127),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(127),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(123),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [54] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7183,46 +7186,46 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(225),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(127),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [55] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7248,135 +7251,135 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(113),
-    [anon_sym_let] = ACTIONS(113),
-    [anon_sym_clock] = ACTIONS(113),
-    [anon_sym_LPAREN] = ACTIONS(113),
-    [sym_identifier] = ACTIONS(113),
-    [aux_sym_literal_token1] = ACTIONS(113),
-    [anon_sym_0x] = ACTIONS(115),
-    [sym_sample] = ACTIONS(115),
-    [anon_sym_BSLASH] = ACTIONS(115),
-    [anon_sym_AMP] = ACTIONS(115),
-    [anon_sym_BANG] = ACTIONS(113),
-    [anon_sym_COLON_COLON] = ACTIONS(115),
-    [anon_sym_in] = ACTIONS(113),
-    [anon_sym_inl] = ACTIONS(113),
-    [anon_sym_inr] = ACTIONS(113),
-    [anon_sym_case] = ACTIONS(113),
-    [anon_sym_LBRACK] = ACTIONS(115),
-    [anon_sym_PERCENT] = ACTIONS(115),
-    [sym_unit_expression] = ACTIONS(115),
-    [anon_sym_BQUOTE] = ACTIONS(115),
-    [anon_sym_box] = ACTIONS(113),
-    [anon_sym_unbox] = ACTIONS(113),
-    [anon_sym_AT] = ACTIONS(115),
-    [anon_sym_DOLLAR] = ACTIONS(115),
-    [anon_sym_STAR] = ACTIONS(115),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(115),
-    [anon_sym_SLASH] = ACTIONS(115),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(115),
-    [anon_sym_PLUS] = ACTIONS(113),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(115),
-    [anon_sym_DASH] = ACTIONS(113),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(115),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(115),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(115),
-    [anon_sym_GT] = ACTIONS(113),
-    [anon_sym_GT_EQ] = ACTIONS(115),
-    [anon_sym_LT] = ACTIONS(113),
-    [anon_sym_LT_EQ] = ACTIONS(115),
-    [anon_sym_EQ_EQ] = ACTIONS(115),
-    [anon_sym_BANG_EQ] = ACTIONS(115),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(115),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(115),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(115),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(221),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [56] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACE] = ACTIONS(227),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [anon_sym_RPAREN] = ACTIONS(223),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [57] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7402,135 +7405,58 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [sym_ex_intro] = STATE(110),
     [sym_ex_elim] = STATE(110),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(15),
-    [anon_sym_let] = ACTIONS(15),
-    [anon_sym_clock] = ACTIONS(15),
-    [anon_sym_LPAREN] = ACTIONS(15),
-    [sym_identifier] = ACTIONS(15),
-    [aux_sym_literal_token1] = ACTIONS(15),
-    [anon_sym_0x] = ACTIONS(17),
-    [sym_sample] = ACTIONS(17),
-    [anon_sym_BSLASH] = ACTIONS(17),
-    [anon_sym_AMP] = ACTIONS(17),
-    [anon_sym_BANG] = ACTIONS(15),
-    [anon_sym_COLON_COLON] = ACTIONS(17),
-    [anon_sym_in] = ACTIONS(15),
-    [anon_sym_inl] = ACTIONS(15),
-    [anon_sym_inr] = ACTIONS(15),
-    [anon_sym_case] = ACTIONS(15),
-    [anon_sym_LBRACK] = ACTIONS(17),
-    [anon_sym_PERCENT] = ACTIONS(17),
-    [sym_unit_expression] = ACTIONS(17),
-    [anon_sym_BQUOTE] = ACTIONS(17),
-    [anon_sym_box] = ACTIONS(15),
-    [anon_sym_unbox] = ACTIONS(15),
-    [anon_sym_AT] = ACTIONS(17),
-    [anon_sym_DOLLAR] = ACTIONS(17),
-    [anon_sym_STAR] = ACTIONS(17),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(17),
-    [anon_sym_SLASH] = ACTIONS(17),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(17),
-    [anon_sym_PLUS] = ACTIONS(15),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(17),
-    [anon_sym_DASH] = ACTIONS(15),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(17),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(17),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(17),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(17),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(17),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(17),
-    [anon_sym_GT] = ACTIONS(15),
-    [anon_sym_GT_EQ] = ACTIONS(17),
-    [anon_sym_LT] = ACTIONS(15),
-    [anon_sym_LT_EQ] = ACTIONS(17),
-    [anon_sym_EQ_EQ] = ACTIONS(17),
-    [anon_sym_BANG_EQ] = ACTIONS(17),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(17),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(17),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(17),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(17),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(17),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(17),
+    [anon_sym_COLON] = ACTIONS(149),
+    [anon_sym_let] = ACTIONS(151),
+    [anon_sym_clock] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(157),
+    [aux_sym_literal_token1] = ACTIONS(159),
+    [anon_sym_0x] = ACTIONS(161),
+    [sym_sample] = ACTIONS(163),
+    [anon_sym_BSLASH] = ACTIONS(165),
+    [anon_sym_AMP] = ACTIONS(167),
+    [anon_sym_BANG] = ACTIONS(169),
+    [anon_sym_COLON_COLON] = ACTIONS(171),
+    [anon_sym_in] = ACTIONS(225),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
+    [sym_unit_expression] = ACTIONS(163),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
   [58] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_PIPE] = ACTIONS(229),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
-  },
-  [59] = {
-    [sym_expression] = STATE(47),
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7568,123 +7494,46 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(231),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(227),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
-  },
-  [60] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
-    [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_RBRACE] = ACTIONS(233),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
   },
-  [61] = {
-    [sym_expression] = STATE(47),
+  [59] = {
+    [sym_expression] = STATE(43),
     [sym_wrap_expression] = STATE(110),
     [sym_literal] = STATE(110),
     [sym_application_expression] = STATE(110),
@@ -7722,120 +7571,274 @@ static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
     [anon_sym_AMP] = ACTIONS(167),
     [anon_sym_BANG] = ACTIONS(169),
     [anon_sym_COLON_COLON] = ACTIONS(171),
-    [anon_sym_in] = ACTIONS(103),
-    [anon_sym_inl] = ACTIONS(173),
-    [anon_sym_inr] = ACTIONS(175),
-    [anon_sym_case] = ACTIONS(177),
-    [anon_sym_LBRACK] = ACTIONS(179),
-    [anon_sym_PERCENT] = ACTIONS(181),
+    [anon_sym_in] = ACTIONS(229),
+    [anon_sym_inl] = ACTIONS(175),
+    [anon_sym_inr] = ACTIONS(177),
+    [anon_sym_case] = ACTIONS(179),
+    [anon_sym_LBRACK] = ACTIONS(181),
+    [anon_sym_PERCENT] = ACTIONS(183),
     [sym_unit_expression] = ACTIONS(163),
-    [anon_sym_BQUOTE] = ACTIONS(183),
-    [anon_sym_box] = ACTIONS(185),
-    [anon_sym_unbox] = ACTIONS(187),
-    [anon_sym_AT] = ACTIONS(189),
-    [anon_sym_DOLLAR] = ACTIONS(191),
-    [anon_sym_STAR] = ACTIONS(193),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(193),
-    [anon_sym_SLASH] = ACTIONS(193),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(193),
-    [anon_sym_PLUS] = ACTIONS(195),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(197),
-    [anon_sym_DASH] = ACTIONS(195),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(197),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(197),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(197),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(197),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(197),
-    [anon_sym_GT] = ACTIONS(199),
-    [anon_sym_GT_EQ] = ACTIONS(201),
-    [anon_sym_LT] = ACTIONS(199),
-    [anon_sym_LT_EQ] = ACTIONS(201),
-    [anon_sym_EQ_EQ] = ACTIONS(201),
-    [anon_sym_BANG_EQ] = ACTIONS(201),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(201),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(201),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(201),
+    [anon_sym_BQUOTE] = ACTIONS(185),
+    [anon_sym_box] = ACTIONS(187),
+    [anon_sym_unbox] = ACTIONS(189),
+    [anon_sym_AT] = ACTIONS(191),
+    [anon_sym_DOLLAR] = ACTIONS(193),
+    [anon_sym_STAR] = ACTIONS(195),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(195),
+    [anon_sym_SLASH] = ACTIONS(195),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(195),
+    [anon_sym_PLUS] = ACTIONS(197),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(199),
+    [anon_sym_DASH] = ACTIONS(197),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(199),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(199),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(199),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(199),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(199),
+    [anon_sym_GT] = ACTIONS(201),
+    [anon_sym_GT_EQ] = ACTIONS(203),
+    [anon_sym_LT] = ACTIONS(201),
+    [anon_sym_LT_EQ] = ACTIONS(203),
+    [anon_sym_EQ_EQ] = ACTIONS(203),
+    [anon_sym_BANG_EQ] = ACTIONS(203),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(203),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(203),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(203),
+  },
+  [60] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_RBRACE] = ACTIONS(231),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
+  },
+  [61] = {
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
+    [sym_comment] = ACTIONS(3),
+    [anon_sym_COLON] = ACTIONS(89),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_PIPE] = ACTIONS(233),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
   [62] = {
-    [sym_expression] = STATE(4),
-    [sym_wrap_expression] = STATE(81),
-    [sym_literal] = STATE(81),
-    [sym_application_expression] = STATE(81),
-    [sym_lambda_expression] = STATE(81),
-    [sym_lob_expression] = STATE(81),
-    [sym_force_expression] = STATE(81),
-    [sym_gen_expression] = STATE(81),
-    [sym_let_expression] = STATE(81),
-    [sym_annotate_expression] = STATE(81),
-    [sym_pair_expression] = STATE(81),
-    [sym_unpair_expression] = STATE(81),
-    [sym_inl_expression] = STATE(81),
-    [sym_inr_expression] = STATE(81),
-    [sym_case_expression] = STATE(81),
-    [sym_array_expression] = STATE(81),
-    [sym_ungen_expression] = STATE(81),
-    [sym_delay_expression] = STATE(81),
-    [sym_box_expression] = STATE(81),
-    [sym_unbox_expression] = STATE(81),
-    [sym_clockapp_expression] = STATE(81),
-    [sym_typeapp_expression] = STATE(81),
-    [sym_binop_expression] = STATE(81),
-    [sym_ex_intro] = STATE(81),
-    [sym_ex_elim] = STATE(81),
+    [sym_expression] = STATE(9),
+    [sym_wrap_expression] = STATE(85),
+    [sym_literal] = STATE(85),
+    [sym_application_expression] = STATE(85),
+    [sym_lambda_expression] = STATE(85),
+    [sym_lob_expression] = STATE(85),
+    [sym_force_expression] = STATE(85),
+    [sym_gen_expression] = STATE(85),
+    [sym_let_expression] = STATE(85),
+    [sym_annotate_expression] = STATE(85),
+    [sym_pair_expression] = STATE(85),
+    [sym_unpair_expression] = STATE(85),
+    [sym_inl_expression] = STATE(85),
+    [sym_inr_expression] = STATE(85),
+    [sym_case_expression] = STATE(85),
+    [sym_array_expression] = STATE(85),
+    [sym_ungen_expression] = STATE(85),
+    [sym_delay_expression] = STATE(85),
+    [sym_box_expression] = STATE(85),
+    [sym_unbox_expression] = STATE(85),
+    [sym_clockapp_expression] = STATE(85),
+    [sym_typeapp_expression] = STATE(85),
+    [sym_binop_expression] = STATE(85),
+    [sym_ex_intro] = STATE(85),
+    [sym_ex_elim] = STATE(85),
     [sym_comment] = ACTIONS(3),
-    [anon_sym_COLON] = ACTIONS(69),
+    [anon_sym_COLON] = ACTIONS(89),
     [anon_sym_SEMI_SEMI] = ACTIONS(235),
-    [anon_sym_let] = ACTIONS(27),
-    [anon_sym_clock] = ACTIONS(29),
-    [anon_sym_LPAREN] = ACTIONS(31),
-    [sym_identifier] = ACTIONS(33),
-    [aux_sym_literal_token1] = ACTIONS(35),
-    [anon_sym_0x] = ACTIONS(37),
-    [sym_sample] = ACTIONS(39),
-    [anon_sym_BSLASH] = ACTIONS(41),
-    [anon_sym_AMP] = ACTIONS(43),
-    [anon_sym_BANG] = ACTIONS(45),
-    [anon_sym_COLON_COLON] = ACTIONS(73),
-    [anon_sym_inl] = ACTIONS(47),
-    [anon_sym_inr] = ACTIONS(49),
-    [anon_sym_case] = ACTIONS(51),
-    [anon_sym_LBRACK] = ACTIONS(53),
-    [anon_sym_PERCENT] = ACTIONS(55),
-    [sym_unit_expression] = ACTIONS(39),
-    [anon_sym_BQUOTE] = ACTIONS(57),
-    [anon_sym_box] = ACTIONS(59),
-    [anon_sym_unbox] = ACTIONS(61),
-    [anon_sym_AT] = ACTIONS(77),
-    [anon_sym_DOLLAR] = ACTIONS(79),
-    [anon_sym_STAR] = ACTIONS(63),
-    [anon_sym_DOT_STAR_DOT] = ACTIONS(63),
-    [anon_sym_SLASH] = ACTIONS(63),
-    [anon_sym_DOT_SLASH_DOT] = ACTIONS(63),
-    [anon_sym_PLUS] = ACTIONS(65),
-    [anon_sym_DOT_PLUS_DOT] = ACTIONS(67),
-    [anon_sym_DASH] = ACTIONS(65),
-    [anon_sym_DOT_DASH_DOT] = ACTIONS(67),
-    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(67),
-    [anon_sym_DOT_AMP_DOT] = ACTIONS(67),
-    [anon_sym_DOT_CARET_DOT] = ACTIONS(67),
-    [anon_sym_DOT_PIPE_DOT] = ACTIONS(67),
-    [anon_sym_GT] = ACTIONS(81),
-    [anon_sym_GT_EQ] = ACTIONS(83),
-    [anon_sym_LT] = ACTIONS(81),
-    [anon_sym_LT_EQ] = ACTIONS(83),
-    [anon_sym_EQ_EQ] = ACTIONS(83),
-    [anon_sym_BANG_EQ] = ACTIONS(83),
-    [anon_sym_DOT_GT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_DOT] = ACTIONS(83),
-    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(83),
-    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(83),
+    [anon_sym_let] = ACTIONS(47),
+    [anon_sym_clock] = ACTIONS(49),
+    [anon_sym_LPAREN] = ACTIONS(51),
+    [sym_identifier] = ACTIONS(53),
+    [aux_sym_literal_token1] = ACTIONS(55),
+    [anon_sym_0x] = ACTIONS(57),
+    [sym_sample] = ACTIONS(59),
+    [anon_sym_BSLASH] = ACTIONS(61),
+    [anon_sym_AMP] = ACTIONS(63),
+    [anon_sym_BANG] = ACTIONS(65),
+    [anon_sym_COLON_COLON] = ACTIONS(93),
+    [anon_sym_inl] = ACTIONS(67),
+    [anon_sym_inr] = ACTIONS(69),
+    [anon_sym_case] = ACTIONS(71),
+    [anon_sym_LBRACK] = ACTIONS(73),
+    [anon_sym_PERCENT] = ACTIONS(75),
+    [sym_unit_expression] = ACTIONS(59),
+    [anon_sym_BQUOTE] = ACTIONS(77),
+    [anon_sym_box] = ACTIONS(79),
+    [anon_sym_unbox] = ACTIONS(81),
+    [anon_sym_AT] = ACTIONS(97),
+    [anon_sym_DOLLAR] = ACTIONS(99),
+    [anon_sym_STAR] = ACTIONS(83),
+    [anon_sym_DOT_STAR_DOT] = ACTIONS(83),
+    [anon_sym_SLASH] = ACTIONS(83),
+    [anon_sym_DOT_SLASH_DOT] = ACTIONS(83),
+    [anon_sym_PLUS] = ACTIONS(85),
+    [anon_sym_DOT_PLUS_DOT] = ACTIONS(87),
+    [anon_sym_DASH] = ACTIONS(85),
+    [anon_sym_DOT_DASH_DOT] = ACTIONS(87),
+    [anon_sym_DOT_LT_LT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_GT_GT_DOT] = ACTIONS(87),
+    [anon_sym_DOT_AMP_DOT] = ACTIONS(87),
+    [anon_sym_DOT_CARET_DOT] = ACTIONS(87),
+    [anon_sym_DOT_PIPE_DOT] = ACTIONS(87),
+    [anon_sym_GT] = ACTIONS(101),
+    [anon_sym_GT_EQ] = ACTIONS(103),
+    [anon_sym_LT] = ACTIONS(101),
+    [anon_sym_LT_EQ] = ACTIONS(103),
+    [anon_sym_EQ_EQ] = ACTIONS(103),
+    [anon_sym_BANG_EQ] = ACTIONS(103),
+    [anon_sym_DOT_GT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_GT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_DOT] = ACTIONS(103),
+    [anon_sym_DOT_LT_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_EQ_EQ_DOT] = ACTIONS(103),
+    [anon_sym_DOT_BANG_EQ_DOT] = ACTIONS(103),
   },
 };
 
@@ -7965,14 +7968,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [134] = 5,
+  [134] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(245), 1,
-      anon_sym_STAR,
-    ACTIONS(247), 1,
-      anon_sym_PLUS,
-    ACTIONS(251), 16,
+    ACTIONS(251), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -7986,10 +7985,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(253), 38,
+    ACTIONS(253), 39,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8007,6 +8007,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8028,11 +8029,9 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [202] = 4,
+  [198] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(245), 1,
-      anon_sym_STAR,
     ACTIONS(255), 17,
       anon_sym_COLON,
       anon_sym_let,
@@ -8051,7 +8050,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(257), 38,
+    ACTIONS(257), 39,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8069,6 +8068,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8090,7 +8090,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [268] = 3,
+  [262] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(259), 17,
@@ -8151,16 +8151,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [332] = 6,
+  [326] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(245), 1,
-      anon_sym_STAR,
-    ACTIONS(247), 1,
-      anon_sym_PLUS,
-    ACTIONS(249), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(263), 16,
+    ACTIONS(263), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -8174,10 +8168,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(265), 37,
+    ACTIONS(265), 39,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8195,6 +8190,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8215,7 +8211,8 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [402] = 3,
+      anon_sym_DASH_GT,
+  [390] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(267), 17,
@@ -8276,7 +8273,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [466] = 3,
+  [454] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(271), 17,
@@ -8337,7 +8334,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [530] = 3,
+  [518] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(275), 17,
@@ -8398,9 +8395,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [594] = 3,
+  [582] = 4,
     ACTIONS(3), 1,
       sym_comment,
+    ACTIONS(245), 1,
+      anon_sym_STAR,
     ACTIONS(279), 17,
       anon_sym_COLON,
       anon_sym_let,
@@ -8419,7 +8418,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(281), 39,
+    ACTIONS(281), 38,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8437,7 +8436,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8459,10 +8457,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [658] = 3,
+  [648] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(283), 17,
+    ACTIONS(245), 1,
+      anon_sym_STAR,
+    ACTIONS(247), 1,
+      anon_sym_PLUS,
+    ACTIONS(283), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -8476,11 +8478,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(285), 39,
+    ACTIONS(285), 38,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8498,7 +8499,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8520,10 +8520,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [722] = 3,
+  [716] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(287), 17,
+    ACTIONS(245), 1,
+      anon_sym_STAR,
+    ACTIONS(247), 1,
+      anon_sym_PLUS,
+    ACTIONS(249), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(287), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -8537,11 +8543,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_LBRACE,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(289), 39,
+    ACTIONS(289), 37,
       anon_sym_SEMI_SEMI,
       anon_sym_RPAREN,
       anon_sym_0x,
@@ -8559,7 +8564,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -8580,7 +8584,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-      anon_sym_DASH_GT,
   [786] = 6,
     ACTIONS(3), 1,
       sym_comment,
@@ -9309,10 +9312,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [1556] = 3,
+  [1556] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(279), 17,
+    ACTIONS(339), 1,
+      anon_sym_STAR,
+    ACTIONS(341), 1,
+      anon_sym_PLUS,
+    ACTIONS(343), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(291), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9326,11 +9335,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(281), 33,
+    ACTIONS(293), 31,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9342,7 +9350,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9363,11 +9370,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-      anon_sym_DASH_GT,
-  [1614] = 3,
+  [1620] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(267), 17,
+    ACTIONS(339), 1,
+      anon_sym_STAR,
+    ACTIONS(341), 1,
+      anon_sym_PLUS,
+    ACTIONS(343), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(295), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9381,11 +9393,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(269), 33,
+    ACTIONS(297), 31,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9397,7 +9408,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9418,17 +9428,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-      anon_sym_DASH_GT,
-  [1672] = 6,
+  [1684] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 1,
-      anon_sym_STAR,
-    ACTIONS(341), 1,
-      anon_sym_PLUS,
-    ACTIONS(343), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(295), 16,
+    ACTIONS(251), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9442,10 +9445,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(297), 31,
+    ACTIONS(253), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9457,6 +9461,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9477,10 +9482,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [1736] = 3,
+      anon_sym_DASH_GT,
+  [1742] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(275), 17,
+    ACTIONS(255), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9498,7 +9504,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(277), 33,
+    ACTIONS(257), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9532,12 +9538,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [1794] = 4,
+  [1800] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 1,
-      anon_sym_STAR,
-    ACTIONS(255), 17,
+    ACTIONS(259), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9555,7 +9559,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(257), 32,
+    ACTIONS(261), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9567,6 +9571,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9588,16 +9593,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [1854] = 6,
+  [1858] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 1,
-      anon_sym_STAR,
-    ACTIONS(341), 1,
-      anon_sym_PLUS,
-    ACTIONS(343), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(263), 16,
+    ACTIONS(271), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9611,10 +9610,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(265), 31,
+    ACTIONS(273), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9626,6 +9626,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9646,10 +9647,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [1918] = 3,
+      anon_sym_DASH_GT,
+  [1916] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(271), 17,
+    ACTIONS(275), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9667,7 +9669,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(273), 33,
+    ACTIONS(277), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9701,10 +9703,12 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [1976] = 3,
+  [1974] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(259), 17,
+    ACTIONS(339), 1,
+      anon_sym_STAR,
+    ACTIONS(279), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9722,7 +9726,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(261), 33,
+    ACTIONS(281), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9734,7 +9738,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9756,10 +9759,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [2034] = 3,
+  [2034] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(283), 17,
+    ACTIONS(339), 1,
+      anon_sym_STAR,
+    ACTIONS(341), 1,
+      anon_sym_PLUS,
+    ACTIONS(283), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9773,11 +9780,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(285), 33,
+    ACTIONS(285), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9789,7 +9795,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9811,10 +9816,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [2092] = 3,
+  [2096] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(287), 17,
+    ACTIONS(339), 1,
+      anon_sym_STAR,
+    ACTIONS(341), 1,
+      anon_sym_PLUS,
+    ACTIONS(343), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(287), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9828,11 +9839,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
-      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(289), 33,
+    ACTIONS(289), 31,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9844,7 +9854,6 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
-      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9865,17 +9874,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-      anon_sym_DASH_GT,
-  [2150] = 6,
+  [2160] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 1,
-      anon_sym_STAR,
-    ACTIONS(341), 1,
-      anon_sym_PLUS,
-    ACTIONS(343), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(291), 16,
+    ACTIONS(237), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9889,10 +9891,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(293), 31,
+    ACTIONS(239), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9904,6 +9907,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9924,16 +9928,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [2214] = 6,
+      anon_sym_DASH_GT,
+  [2218] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(339), 1,
-      anon_sym_STAR,
-    ACTIONS(341), 1,
-      anon_sym_PLUS,
-    ACTIONS(343), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(241), 16,
+    ACTIONS(263), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -9947,10 +9946,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_case,
       anon_sym_box,
       anon_sym_unbox,
+      anon_sym_PLUS,
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(243), 31,
+    ACTIONS(265), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -9962,6 +9962,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BQUOTE,
       anon_sym_AT,
       anon_sym_DOLLAR,
+      anon_sym_STAR,
       anon_sym_DOT_STAR_DOT,
       anon_sym_SLASH,
       anon_sym_DOT_SLASH_DOT,
@@ -9982,10 +9983,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-  [2278] = 3,
+      anon_sym_DASH_GT,
+  [2276] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(237), 17,
+    ACTIONS(267), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10003,7 +10005,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(239), 33,
+    ACTIONS(269), 33,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10037,14 +10039,16 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
       anon_sym_DASH_GT,
-  [2336] = 5,
+  [2334] = 6,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(339), 1,
       anon_sym_STAR,
     ACTIONS(341), 1,
       anon_sym_PLUS,
-    ACTIONS(251), 16,
+    ACTIONS(343), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(241), 16,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10061,7 +10065,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(253), 32,
+    ACTIONS(243), 31,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10093,11 +10097,10 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DOT_LT_EQ_DOT,
       anon_sym_DOT_EQ_EQ_DOT,
       anon_sym_DOT_BANG_EQ_DOT,
-      anon_sym_DASH_GT,
   [2398] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(311), 17,
+    ACTIONS(307), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10115,7 +10118,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(313), 32,
+    ACTIONS(309), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10151,7 +10154,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2455] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(307), 17,
+    ACTIONS(327), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10169,7 +10172,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(309), 32,
+    ACTIONS(329), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10205,7 +10208,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2512] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(327), 17,
+    ACTIONS(335), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10223,7 +10226,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(329), 32,
+    ACTIONS(337), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10259,7 +10262,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2569] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(331), 17,
+    ACTIONS(323), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10277,7 +10280,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(333), 32,
+    ACTIONS(325), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10313,7 +10316,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2626] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(299), 17,
+    ACTIONS(311), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10331,7 +10334,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(301), 32,
+    ACTIONS(313), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10421,7 +10424,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2740] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(323), 17,
+    ACTIONS(303), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10439,7 +10442,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(325), 32,
+    ACTIONS(305), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10475,7 +10478,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2797] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(303), 17,
+    ACTIONS(315), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10493,7 +10496,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(305), 32,
+    ACTIONS(317), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10529,7 +10532,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2854] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(335), 17,
+    ACTIONS(299), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10547,7 +10550,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(337), 32,
+    ACTIONS(301), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10583,7 +10586,7 @@ static const uint16_t ts_small_parse_table[] = {
   [2911] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(315), 17,
+    ACTIONS(331), 17,
       anon_sym_COLON,
       anon_sym_let,
       anon_sym_clock,
@@ -10601,7 +10604,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH,
       anon_sym_GT,
       anon_sym_LT,
-    ACTIONS(317), 32,
+    ACTIONS(333), 32,
       anon_sym_0x,
       sym_sample,
       anon_sym_BSLASH,
@@ -10637,37 +10640,37 @@ static const uint16_t ts_small_parse_table[] = {
   [2968] = 24,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
@@ -10675,14 +10678,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
     STATE(21), 1,
       sym_expression,
-    STATE(113), 1,
+    STATE(114), 1,
       aux_sym_array_inner_repeat1,
-    STATE(300), 1,
+    STATE(297), 1,
       sym_array_inner,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -10710,37 +10713,37 @@ static const uint16_t ts_small_parse_table[] = {
   [3065] = 24,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
@@ -10748,14 +10751,14 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_RBRACK,
     STATE(21), 1,
       sym_expression,
-    STATE(113), 1,
+    STATE(114), 1,
       aux_sym_array_inner_repeat1,
-    STATE(288), 1,
+    STATE(286), 1,
       sym_array_inner,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -10783,48 +10786,48 @@ static const uint16_t ts_small_parse_table[] = {
   [3162] = 22,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(351), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(354), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(357), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(360), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(363), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(366), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(372), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(375), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(378), 1,
+      anon_sym_BANG,
+    ACTIONS(381), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(384), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(387), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(390), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(393), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(396), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(399), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(402), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
-      anon_sym_BANG,
-    STATE(24), 1,
+    STATE(30), 1,
       sym_expression,
-    STATE(114), 1,
+    STATE(113), 1,
       aux_sym_array_inner_repeat1,
-    ACTIONS(39), 2,
+    ACTIONS(369), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -10852,48 +10855,48 @@ static const uint16_t ts_small_parse_table[] = {
   [3253] = 22,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(351), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(354), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(357), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(360), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(363), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(366), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(372), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(375), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(378), 1,
-      anon_sym_BANG,
-    ACTIONS(381), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(384), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(387), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(390), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(393), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(396), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(399), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(402), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    STATE(46), 1,
+    ACTIONS(345), 1,
+      anon_sym_BANG,
+    STATE(23), 1,
       sym_expression,
-    STATE(114), 1,
+    STATE(113), 1,
       aux_sym_array_inner_repeat1,
-    ACTIONS(369), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -10921,46 +10924,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3344] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(2), 1,
+    STATE(7), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -10988,46 +10991,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3432] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(53), 1,
+    STATE(17), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11055,46 +11058,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3520] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(27), 1,
+    STATE(28), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11122,46 +11125,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3608] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(28), 1,
+    STATE(18), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11189,46 +11192,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3696] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(31), 1,
+    STATE(20), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11256,46 +11259,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3784] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
     STATE(29), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11323,46 +11326,46 @@ static const uint16_t ts_small_parse_table[] = {
   [3872] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(26), 1,
+    STATE(15), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11406,25 +11409,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(38), 1,
+    STATE(41), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -11457,46 +11460,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4048] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(7), 1,
+    STATE(19), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11524,46 +11527,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4136] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(44), 1,
+    STATE(50), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11591,46 +11594,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4224] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
     STATE(11), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11658,46 +11661,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4312] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(30), 1,
+    STATE(12), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11725,46 +11728,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4400] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(62), 1,
+    STATE(4), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11792,46 +11795,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4488] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(34), 1,
+    STATE(6), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -11875,25 +11878,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(61), 1,
+    STATE(51), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -11942,25 +11945,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(35), 1,
+    STATE(52), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -11993,46 +11996,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4752] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(22), 1,
+    STATE(53), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12060,46 +12063,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4840] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(39), 1,
+    STATE(25), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12127,46 +12130,46 @@ static const uint16_t ts_small_parse_table[] = {
   [4928] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(40), 1,
+    STATE(2), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12194,46 +12197,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5016] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(41), 1,
+    STATE(62), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12277,25 +12280,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(42), 1,
+    STATE(54), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -12328,46 +12331,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5192] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(6), 1,
+    STATE(42), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12395,46 +12398,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5280] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(3), 1,
+    STATE(32), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12462,46 +12465,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5368] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(17), 1,
+    STATE(26), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12529,46 +12532,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5456] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(16), 1,
+    STATE(40), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12596,46 +12599,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5544] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(49), 1,
+    STATE(33), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12679,25 +12682,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(45), 1,
+    STATE(44), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -12730,46 +12733,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5720] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(56), 1,
+    STATE(45), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12797,46 +12800,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5808] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(8), 1,
+    STATE(46), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12864,46 +12867,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5896] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(5), 1,
+    STATE(47), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12931,46 +12934,46 @@ static const uint16_t ts_small_parse_table[] = {
   [5984] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(14), 1,
+    STATE(5), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -12998,46 +13001,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6072] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(9), 1,
+    STATE(8), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13081,25 +13084,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(48), 1,
+    STATE(34), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -13148,25 +13151,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(51), 1,
+    STATE(48), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -13199,46 +13202,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6336] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(52), 1,
+    STATE(14), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13266,46 +13269,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6424] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(18), 1,
+    STATE(49), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13333,46 +13336,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6512] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(13), 1,
+    STATE(10), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13400,46 +13403,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6600] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(55), 1,
+    STATE(27), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13467,46 +13470,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6688] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(25), 1,
+    STATE(22), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13534,46 +13537,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6776] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(32), 1,
+    STATE(3), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13617,25 +13620,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(50), 1,
+    STATE(31), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -13668,46 +13671,46 @@ static const uint16_t ts_small_parse_table[] = {
   [6952] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(20), 1,
+    STATE(16), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13751,25 +13754,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(57), 1,
+    STATE(36), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -13818,25 +13821,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(33), 1,
+    STATE(37), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -13869,46 +13872,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7216] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(36), 1,
+    STATE(24), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -13936,46 +13939,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7304] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(60), 1,
+    STATE(55), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14003,46 +14006,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7392] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(37), 1,
+    STATE(56), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14086,25 +14089,25 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_BSLASH,
     ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
-      anon_sym_inl,
     ACTIONS(175), 1,
-      anon_sym_inr,
+      anon_sym_inl,
     ACTIONS(177), 1,
-      anon_sym_case,
+      anon_sym_inr,
     ACTIONS(179), 1,
-      anon_sym_LBRACK,
+      anon_sym_case,
     ACTIONS(181), 1,
-      anon_sym_PERCENT,
+      anon_sym_LBRACK,
     ACTIONS(183), 1,
-      anon_sym_BQUOTE,
+      anon_sym_PERCENT,
     ACTIONS(185), 1,
-      anon_sym_box,
+      anon_sym_BQUOTE,
     ACTIONS(187), 1,
+      anon_sym_box,
+    ACTIONS(189), 1,
       anon_sym_unbox,
     ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(54), 1,
+    STATE(57), 1,
       sym_expression,
     ACTIONS(163), 2,
       sym_sample,
@@ -14137,46 +14140,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7568] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(12), 1,
+    STATE(58), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14204,46 +14207,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7656] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(10), 1,
+    STATE(59), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14271,46 +14274,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7744] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(43), 1,
+    STATE(60), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14338,46 +14341,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7832] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(19), 1,
+    STATE(38), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14405,46 +14408,46 @@ static const uint16_t ts_small_parse_table[] = {
   [7920] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(58), 1,
+    STATE(61), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14472,46 +14475,46 @@ static const uint16_t ts_small_parse_table[] = {
   [8008] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(151), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(153), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(155), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(157), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(159), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(161), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(165), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(167), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(175), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(177), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(179), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(181), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(183), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(185), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(187), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(189), 1,
       anon_sym_unbox,
-    ACTIONS(345), 1,
+    ACTIONS(405), 1,
       anon_sym_BANG,
-    STATE(23), 1,
+    STATE(39), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(163), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(110), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14539,46 +14542,46 @@ static const uint16_t ts_small_parse_table[] = {
   [8096] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(151), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(153), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(155), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(157), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(159), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(161), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(165), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(167), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(173), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(175), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(177), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(179), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(181), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(183), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(185), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(187), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
-    ACTIONS(405), 1,
+    ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(59), 1,
+    STATE(35), 1,
       sym_expression,
-    ACTIONS(163), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(110), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14606,46 +14609,46 @@ static const uint16_t ts_small_parse_table[] = {
   [8184] = 21,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(27), 1,
+    ACTIONS(47), 1,
       anon_sym_let,
-    ACTIONS(29), 1,
+    ACTIONS(49), 1,
       anon_sym_clock,
-    ACTIONS(31), 1,
+    ACTIONS(51), 1,
       anon_sym_LPAREN,
-    ACTIONS(33), 1,
+    ACTIONS(53), 1,
       sym_identifier,
-    ACTIONS(35), 1,
+    ACTIONS(55), 1,
       aux_sym_literal_token1,
-    ACTIONS(37), 1,
+    ACTIONS(57), 1,
       anon_sym_0x,
-    ACTIONS(41), 1,
+    ACTIONS(61), 1,
       anon_sym_BSLASH,
-    ACTIONS(43), 1,
+    ACTIONS(63), 1,
       anon_sym_AMP,
-    ACTIONS(47), 1,
+    ACTIONS(67), 1,
       anon_sym_inl,
-    ACTIONS(49), 1,
+    ACTIONS(69), 1,
       anon_sym_inr,
-    ACTIONS(51), 1,
+    ACTIONS(71), 1,
       anon_sym_case,
-    ACTIONS(53), 1,
+    ACTIONS(73), 1,
       anon_sym_LBRACK,
-    ACTIONS(55), 1,
+    ACTIONS(75), 1,
       anon_sym_PERCENT,
-    ACTIONS(57), 1,
+    ACTIONS(77), 1,
       anon_sym_BQUOTE,
-    ACTIONS(59), 1,
+    ACTIONS(79), 1,
       anon_sym_box,
-    ACTIONS(61), 1,
+    ACTIONS(81), 1,
       anon_sym_unbox,
     ACTIONS(345), 1,
       anon_sym_BANG,
-    STATE(15), 1,
+    STATE(13), 1,
       sym_expression,
-    ACTIONS(39), 2,
+    ACTIONS(59), 2,
       sym_sample,
       sym_unit_expression,
-    STATE(81), 24,
+    STATE(85), 24,
       sym_wrap_expression,
       sym_literal,
       sym_application_expression,
@@ -14689,13 +14692,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(98), 1,
+    STATE(224), 1,
       sym_type,
     ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14711,29 +14714,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8322] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(65), 1,
+    STATE(246), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14749,29 +14752,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8372] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(407), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(409), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(415), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(417), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(419), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(421), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(423), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(91), 1,
+    STATE(72), 1,
       sym_type,
-    ACTIONS(413), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14787,29 +14790,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8422] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(407), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(409), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(415), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(417), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(419), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(421), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(423), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(88), 1,
+    STATE(73), 1,
       sym_type,
-    ACTIONS(413), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14825,29 +14828,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8472] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(212), 1,
+    STATE(74), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14863,29 +14866,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8522] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(224), 1,
+    STATE(75), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14901,29 +14904,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8572] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(231), 1,
+    STATE(220), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14939,29 +14942,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8622] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(248), 1,
+    STATE(76), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -14977,29 +14980,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8672] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(243), 1,
+    STATE(212), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15015,29 +15018,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8722] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(407), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(409), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(415), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(417), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(419), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(421), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(423), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(89), 1,
+    STATE(68), 1,
       sym_type,
-    ACTIONS(413), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15053,29 +15056,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8772] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(225), 1,
+    STATE(69), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15091,29 +15094,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8822] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(407), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(409), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(415), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(417), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(419), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(421), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(423), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(92), 1,
+    STATE(64), 1,
       sym_type,
-    ACTIONS(413), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15129,29 +15132,29 @@ static const uint16_t ts_small_parse_table[] = {
   [8872] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(214), 1,
+    STATE(213), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15183,13 +15186,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(96), 1,
+    STATE(217), 1,
       sym_type,
     ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15221,13 +15224,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(100), 1,
+    STATE(215), 1,
       sym_type,
     ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15243,29 +15246,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9022] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(229), 1,
+    STATE(238), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15281,29 +15284,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9072] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(245), 1,
+    STATE(216), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15319,29 +15322,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9122] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(69), 1,
+    STATE(214), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15357,29 +15360,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9172] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(213), 1,
+    STATE(228), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15411,13 +15414,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(251), 1,
+    STATE(92), 1,
       sym_type,
     ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15433,29 +15436,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9272] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(443), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(445), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(447), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(451), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(453), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(455), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(457), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(66), 1,
+    STATE(94), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15487,13 +15490,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(244), 1,
+    STATE(95), 1,
       sym_type,
     ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15509,29 +15512,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9372] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(443), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(445), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(447), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(451), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(453), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(455), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(457), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(76), 1,
+    STATE(96), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15563,13 +15566,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(222), 1,
+    STATE(87), 1,
       sym_type,
     ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15585,29 +15588,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9472] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(443), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(445), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(447), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(451), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(453), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(455), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(457), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(68), 1,
+    STATE(98), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15623,29 +15626,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9522] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(407), 1,
+    ACTIONS(443), 1,
       anon_sym_LPAREN,
-    ACTIONS(409), 1,
+    ACTIONS(445), 1,
       sym_identifier,
-    ACTIONS(411), 1,
+    ACTIONS(447), 1,
       anon_sym_LBRACK,
-    ACTIONS(415), 1,
+    ACTIONS(451), 1,
       anon_sym_TILDE,
-    ACTIONS(417), 1,
+    ACTIONS(453), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(419), 1,
+    ACTIONS(455), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(421), 1,
+    ACTIONS(457), 1,
       anon_sym_for,
-    ACTIONS(423), 1,
+    ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(97), 1,
+    STATE(99), 1,
       sym_type,
-    ACTIONS(413), 3,
+    ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15677,13 +15680,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(219), 1,
+    STATE(100), 1,
       sym_type,
     ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15699,29 +15702,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9622] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(443), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(445), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(447), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(451), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(453), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(455), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(457), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(459), 1,
       anon_sym_QMARK,
-    STATE(64), 1,
+    STATE(88), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(449), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(91), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15753,13 +15756,13 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_for,
     ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(95), 1,
+    STATE(226), 1,
       sym_type,
     ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(93), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15775,29 +15778,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9722] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(246), 1,
+    STATE(232), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15813,29 +15816,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9772] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(217), 1,
+    STATE(229), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15851,29 +15854,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9822] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(216), 1,
+    STATE(233), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15889,29 +15892,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9872] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(235), 1,
+    STATE(231), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15927,29 +15930,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9922] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(75), 1,
+    STATE(225), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -15965,29 +15968,29 @@ static const uint16_t ts_small_parse_table[] = {
   [9972] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(74), 1,
+    STATE(234), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -16003,29 +16006,29 @@ static const uint16_t ts_small_parse_table[] = {
   [10022] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(425), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(427), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(429), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(433), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(435), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(437), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(439), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(441), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(73), 1,
+    STATE(244), 1,
       sym_type,
-    ACTIONS(431), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(70), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -16041,29 +16044,29 @@ static const uint16_t ts_small_parse_table[] = {
   [10072] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(407), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(409), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(411), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(415), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(417), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(419), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(421), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(423), 1,
       anon_sym_QMARK,
-    STATE(237), 1,
+    STATE(245), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(413), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(218), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -16079,29 +16082,29 @@ static const uint16_t ts_small_parse_table[] = {
   [10122] = 12,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(443), 1,
+    ACTIONS(425), 1,
       anon_sym_LPAREN,
-    ACTIONS(445), 1,
+    ACTIONS(427), 1,
       sym_identifier,
-    ACTIONS(447), 1,
+    ACTIONS(429), 1,
       anon_sym_LBRACK,
-    ACTIONS(451), 1,
+    ACTIONS(433), 1,
       anon_sym_TILDE,
-    ACTIONS(453), 1,
+    ACTIONS(435), 1,
       anon_sym_PIPE_GT,
-    ACTIONS(455), 1,
+    ACTIONS(437), 1,
       anon_sym_LBRACK_RBRACK,
-    ACTIONS(457), 1,
+    ACTIONS(439), 1,
       anon_sym_for,
-    ACTIONS(459), 1,
+    ACTIONS(441), 1,
       anon_sym_QMARK,
-    STATE(249), 1,
+    STATE(70), 1,
       sym_type,
-    ACTIONS(449), 3,
+    ACTIONS(431), 3,
       anon_sym_sample,
       anon_sym_index,
       anon_sym_unit,
-    STATE(215), 12,
+    STATE(67), 12,
       sym_wrap_type,
       sym_base_type,
       sym_function_type,
@@ -16141,15 +16144,15 @@ static const uint16_t ts_small_parse_table[] = {
   [10199] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(465), 1,
-      ts_builtin_sym_end,
-    ACTIONS(467), 1,
+    ACTIONS(5), 1,
       anon_sym_def,
-    ACTIONS(470), 1,
+    ACTIONS(7), 1,
       anon_sym_let,
-    ACTIONS(473), 1,
+    ACTIONS(9), 1,
       anon_sym_clock,
-    STATE(210), 4,
+    ACTIONS(465), 1,
+      ts_builtin_sym_end,
+    STATE(211), 4,
       sym_top_level_def,
       sym_top_level_let,
       sym_top_level_clock,
@@ -16157,61 +16160,68 @@ static const uint16_t ts_small_parse_table[] = {
   [10221] = 6,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(5), 1,
+    ACTIONS(467), 1,
+      ts_builtin_sym_end,
+    ACTIONS(469), 1,
       anon_sym_def,
-    ACTIONS(7), 1,
+    ACTIONS(472), 1,
       anon_sym_let,
-    ACTIONS(9), 1,
+    ACTIONS(475), 1,
       anon_sym_clock,
-    ACTIONS(476), 1,
-      ts_builtin_sym_end,
-    STATE(210), 4,
+    STATE(211), 4,
       sym_top_level_def,
       sym_top_level_let,
       sym_top_level_clock,
       aux_sym_source_file_repeat1,
-  [10243] = 2,
+  [10243] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(289), 6,
-      anon_sym_EQ,
-      anon_sym_RPAREN,
+    ACTIONS(478), 1,
       anon_sym_STAR,
+    ACTIONS(480), 1,
       anon_sym_PLUS,
+    ACTIONS(285), 4,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10255] = 3,
+  [10259] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(257), 5,
+    ACTIONS(265), 6,
       anon_sym_EQ,
       anon_sym_RPAREN,
+      anon_sym_STAR,
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10269] = 2,
+  [10271] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(285), 6,
-      anon_sym_EQ,
-      anon_sym_RPAREN,
+    ACTIONS(478), 1,
       anon_sym_STAR,
+    ACTIONS(480), 1,
       anon_sym_PLUS,
+    ACTIONS(482), 1,
       anon_sym_DASH_GT,
+    ACTIONS(293), 3,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
       anon_sym_SEMI,
-  [10281] = 2,
+  [10289] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(273), 6,
-      anon_sym_EQ,
-      anon_sym_RPAREN,
+    ACTIONS(478), 1,
       anon_sym_STAR,
+    ACTIONS(480), 1,
       anon_sym_PLUS,
+    ACTIONS(482), 1,
       anon_sym_DASH_GT,
+    ACTIONS(243), 3,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
       anon_sym_SEMI,
-  [10293] = 5,
+  [10307] = 5,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(478), 1,
@@ -16220,11 +16230,11 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(265), 3,
+    ACTIONS(289), 3,
       anon_sym_EQ,
       anon_sym_RPAREN,
       anon_sym_SEMI,
-  [10311] = 2,
+  [10325] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(269), 6,
@@ -16234,40 +16244,37 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10323] = 2,
+  [10337] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(281), 6,
+    ACTIONS(261), 6,
       anon_sym_EQ,
       anon_sym_RPAREN,
       anon_sym_STAR,
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10335] = 5,
+  [10349] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
+    ACTIONS(239), 6,
+      anon_sym_EQ,
+      anon_sym_RPAREN,
       anon_sym_STAR,
-    ACTIONS(480), 1,
       anon_sym_PLUS,
-    ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(297), 3,
-      anon_sym_EQ,
-      anon_sym_RPAREN,
       anon_sym_SEMI,
-  [10353] = 2,
+  [10361] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(239), 6,
+    ACTIONS(273), 6,
       anon_sym_EQ,
       anon_sym_RPAREN,
       anon_sym_STAR,
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10365] = 2,
+  [10373] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(277), 6,
@@ -16277,40 +16284,36 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10377] = 4,
+  [10385] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(253), 4,
+    ACTIONS(253), 6,
       anon_sym_EQ,
       anon_sym_RPAREN,
+      anon_sym_STAR,
+      anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10393] = 2,
+  [10397] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(261), 6,
+    ACTIONS(257), 6,
       anon_sym_EQ,
       anon_sym_RPAREN,
       anon_sym_STAR,
       anon_sym_PLUS,
       anon_sym_DASH_GT,
       anon_sym_SEMI,
-  [10405] = 5,
+  [10409] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(478), 1,
       anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(482), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(293), 3,
+    ACTIONS(281), 5,
       anon_sym_EQ,
       anon_sym_RPAREN,
+      anon_sym_PLUS,
+      anon_sym_DASH_GT,
       anon_sym_SEMI,
   [10423] = 5,
     ACTIONS(3), 1,
@@ -16323,36 +16326,36 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_DASH_GT,
     ACTIONS(484), 1,
       anon_sym_SEMI,
-  [10439] = 2,
+  [10439] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(478), 1,
+      anon_sym_STAR,
+    ACTIONS(480), 1,
+      anon_sym_PLUS,
+    ACTIONS(482), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(486), 1,
+      anon_sym_EQ,
+  [10455] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(486), 4,
+    ACTIONS(488), 4,
       ts_builtin_sym_end,
       anon_sym_def,
       anon_sym_let,
       anon_sym_clock,
-  [10449] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-    ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(313), 1,
-      sym_clock,
   [10465] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
+    ACTIONS(478), 1,
+      anon_sym_STAR,
+    ACTIONS(480), 1,
+      anon_sym_PLUS,
+    ACTIONS(482), 1,
+      anon_sym_DASH_GT,
     ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(286), 1,
-      sym_clock,
-    STATE(290), 1,
-      sym_clock_coeff,
+      anon_sym_RPAREN,
   [10481] = 5,
     ACTIONS(3), 1,
       sym_comment,
@@ -16363,18 +16366,18 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
     ACTIONS(492), 1,
-      anon_sym_EQ,
+      anon_sym_RPAREN,
   [10497] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(303), 1,
+    STATE(312), 1,
       sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10513] = 5,
     ACTIONS(3), 1,
       sym_comment,
@@ -16384,42 +16387,31 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(494), 1,
-      anon_sym_SEMI,
+    ACTIONS(498), 1,
+      anon_sym_RPAREN,
   [10529] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-    ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(293), 1,
-      sym_clock,
+    ACTIONS(478), 1,
+      anon_sym_STAR,
+    ACTIONS(480), 1,
+      anon_sym_PLUS,
+    ACTIONS(482), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(500), 1,
+      anon_sym_EQ,
   [10545] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-    ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(289), 1,
-      sym_clock,
-    STATE(290), 1,
-      sym_clock_coeff,
+    ACTIONS(478), 1,
+      anon_sym_STAR,
+    ACTIONS(480), 1,
+      anon_sym_PLUS,
+    ACTIONS(482), 1,
+      anon_sym_DASH_GT,
+    ACTIONS(502), 1,
+      anon_sym_RPAREN,
   [10561] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-    ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(292), 1,
-      sym_clock,
-  [10577] = 5,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(478), 1,
@@ -16428,20 +16420,42 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
+    ACTIONS(504), 1,
+      anon_sym_RPAREN,
+  [10577] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(494), 1,
+      sym_identifier,
     ACTIONS(496), 1,
-      anon_sym_EQ,
+      aux_sym_size_token1,
+    STATE(280), 1,
+      sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10593] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(302), 1,
+    STATE(281), 1,
       sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10609] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(494), 1,
+      sym_identifier,
+    ACTIONS(496), 1,
+      aux_sym_size_token1,
+    STATE(290), 1,
+      sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
+  [10625] = 5,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(478), 1,
@@ -16450,68 +16464,57 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(498), 1,
-      anon_sym_RPAREN,
-  [10625] = 2,
+    ACTIONS(506), 1,
+      anon_sym_EQ,
+  [10641] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(500), 4,
+    ACTIONS(508), 4,
       ts_builtin_sym_end,
       anon_sym_def,
       anon_sym_let,
       anon_sym_clock,
-  [10635] = 2,
+  [10651] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(502), 4,
+    ACTIONS(510), 4,
       ts_builtin_sym_end,
       anon_sym_def,
       anon_sym_let,
       anon_sym_clock,
-  [10645] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(488), 1,
-      sym_identifier,
-    ACTIONS(490), 1,
-      aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(326), 1,
-      sym_clock,
   [10661] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(266), 1,
+    STATE(267), 1,
       sym_clock,
-    STATE(290), 1,
+    STATE(314), 1,
       sym_clock_coeff,
   [10677] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(290), 1,
-      sym_clock_coeff,
-    STATE(328), 1,
+    STATE(300), 1,
       sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10693] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(482), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(504), 1,
-      anon_sym_RPAREN,
+    ACTIONS(494), 1,
+      sym_identifier,
+    ACTIONS(496), 1,
+      aux_sym_size_token1,
+    STATE(301), 1,
+      sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10709] = 5,
     ACTIONS(3), 1,
       sym_comment,
@@ -16521,8 +16524,8 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(506), 1,
-      anon_sym_RPAREN,
+    ACTIONS(512), 1,
+      anon_sym_SEMI,
   [10725] = 5,
     ACTIONS(3), 1,
       sym_comment,
@@ -16532,7 +16535,7 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(508), 1,
+    ACTIONS(514), 1,
       anon_sym_EQ,
   [10741] = 5,
     ACTIONS(3), 1,
@@ -16543,63 +16546,63 @@ static const uint16_t ts_small_parse_table[] = {
       anon_sym_PLUS,
     ACTIONS(482), 1,
       anon_sym_DASH_GT,
-    ACTIONS(510), 1,
-      anon_sym_RPAREN,
+    ACTIONS(516), 1,
+      anon_sym_SEMI,
   [10757] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(283), 1,
+    STATE(273), 1,
       sym_clock,
-    STATE(290), 1,
+    STATE(314), 1,
       sym_clock_coeff,
   [10773] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(482), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(512), 1,
-      anon_sym_EQ,
+    ACTIONS(494), 1,
+      sym_identifier,
+    ACTIONS(496), 1,
+      aux_sym_size_token1,
+    STATE(309), 1,
+      sym_clock,
+    STATE(314), 1,
+      sym_clock_coeff,
   [10789] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(482), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(514), 1,
-      anon_sym_SEMI,
+    ACTIONS(494), 1,
+      sym_identifier,
+    ACTIONS(496), 1,
+      aux_sym_size_token1,
+    STATE(314), 1,
+      sym_clock_coeff,
+    STATE(335), 1,
+      sym_clock,
   [10805] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(488), 1,
+    ACTIONS(494), 1,
+      sym_identifier,
+    ACTIONS(496), 1,
+      aux_sym_size_token1,
+    STATE(314), 1,
+      sym_clock_coeff,
+    STATE(327), 1,
+      sym_clock,
+  [10821] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(494), 1,
       sym_identifier,
-    ACTIONS(490), 1,
+    ACTIONS(496), 1,
       aux_sym_size_token1,
-    STATE(284), 1,
+    STATE(283), 1,
       sym_clock,
-    STATE(290), 1,
+    STATE(314), 1,
       sym_clock_coeff,
-  [10821] = 5,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(478), 1,
-      anon_sym_STAR,
-    ACTIONS(480), 1,
-      anon_sym_PLUS,
-    ACTIONS(482), 1,
-      anon_sym_DASH_GT,
-    ACTIONS(516), 1,
-      anon_sym_RPAREN,
   [10837] = 4,
     ACTIONS(3), 1,
       sym_comment,
@@ -16612,42 +16615,42 @@ static const uint16_t ts_small_parse_table[] = {
   [10850] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    STATE(308), 1,
+    STATE(302), 1,
       sym_kind,
     ACTIONS(524), 2,
       anon_sym_clock,
       anon_sym_type,
-  [10861] = 3,
+  [10861] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    STATE(285), 1,
-      sym_kind,
-    ACTIONS(524), 2,
+    ACTIONS(526), 1,
       anon_sym_clock,
-      anon_sym_type,
-  [10872] = 3,
+    ACTIONS(528), 1,
+      anon_sym_LPAREN,
+    ACTIONS(530), 1,
+      sym_identifier,
+  [10874] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    STATE(304), 1,
+    STATE(282), 1,
       sym_kind,
     ACTIONS(524), 2,
       anon_sym_clock,
       anon_sym_type,
-  [10883] = 4,
+  [10885] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(526), 1,
+    STATE(271), 1,
+      sym_kind,
+    ACTIONS(524), 2,
       anon_sym_clock,
-    ACTIONS(528), 1,
-      anon_sym_LPAREN,
-    ACTIONS(530), 1,
-      sym_identifier,
+      anon_sym_type,
   [10896] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(532), 1,
       aux_sym_size_token1,
-    STATE(282), 1,
+    STATE(279), 1,
       sym_size,
   [10906] = 2,
     ACTIONS(3), 1,
@@ -16655,62 +16658,62 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(534), 2,
       anon_sym_RPAREN,
       anon_sym_and,
-  [10914] = 3,
-    ACTIONS(3), 1,
-      sym_comment,
-    ACTIONS(532), 1,
-      aux_sym_size_token1,
-    STATE(279), 1,
-      sym_size,
-  [10924] = 2,
+  [10914] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(536), 2,
       anon_sym_RPAREN,
       anon_sym_and,
-  [10932] = 3,
+  [10922] = 3,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(538), 1,
-      sym_identifier,
+      anon_sym_COLON,
     ACTIONS(540), 1,
-      anon_sym_SLASH,
+      anon_sym_EQ,
+  [10932] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(532), 1,
+      aux_sym_size_token1,
+    STATE(274), 1,
+      sym_size,
   [10942] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(542), 1,
-      anon_sym_COLON,
-    ACTIONS(544), 1,
-      anon_sym_EQ,
+    ACTIONS(532), 1,
+      aux_sym_size_token1,
+    STATE(299), 1,
+      sym_size,
   [10952] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(546), 1,
+    ACTIONS(542), 1,
       anon_sym_COLON,
-    ACTIONS(548), 1,
+    ACTIONS(544), 1,
       anon_sym_EQ,
   [10962] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(532), 1,
-      aux_sym_size_token1,
-    STATE(301), 1,
-      sym_size,
+    ACTIONS(546), 1,
+      sym_identifier,
+    ACTIONS(548), 1,
+      anon_sym_SLASH,
   [10972] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(550), 1,
-      anon_sym_DOT,
+      anon_sym_and,
   [10979] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(552), 1,
-      anon_sym_RPAREN,
+      anon_sym_CARET,
   [10986] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(554), 1,
-      sym_identifier,
+      anon_sym_RPAREN,
   [10993] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16720,52 +16723,52 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(558), 1,
-      anon_sym_DOT,
+      sym_identifier,
   [11007] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(560), 1,
-      anon_sym_CARET,
+      sym_identifier,
   [11014] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(562), 1,
-      anon_sym_CARET,
+      anon_sym_DOT,
   [11021] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(564), 1,
-      anon_sym_Hz,
+      anon_sym_COLON,
   [11028] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(566), 1,
-      aux_sym_literal_token2,
+      anon_sym_RPAREN,
   [11035] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(568), 1,
-      sym_identifier,
+      anon_sym_RBRACK,
   [11042] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(570), 1,
-      anon_sym_CARET,
+      anon_sym_and,
   [11049] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(572), 1,
-      anon_sym_SEMI_SEMI,
+      anon_sym_DOT,
   [11056] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(574), 1,
-      anon_sym_RBRACK,
+      aux_sym_literal_token2,
   [11063] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(576), 1,
-      anon_sym_frequency,
+      anon_sym_EQ,
   [11070] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16775,22 +16778,22 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(580), 1,
-      anon_sym_DOT,
+      anon_sym_RPAREN,
   [11084] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(582), 1,
-      aux_sym_literal_token2,
+      anon_sym_RPAREN,
   [11091] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(584), 1,
-      anon_sym_RBRACK,
+      anon_sym_DOT,
   [11098] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(586), 1,
-      anon_sym_RPAREN,
+      anon_sym_and,
   [11105] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16805,127 +16808,127 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(592), 1,
-      anon_sym_and,
+      anon_sym_RBRACK,
   [11126] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(594), 1,
-      anon_sym_DOT,
+      anon_sym_COMMA,
   [11133] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(596), 1,
-      anon_sym_RBRACK,
+      anon_sym_of,
   [11140] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(598), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11147] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(600), 1,
-      sym_identifier,
+      anon_sym_RPAREN,
   [11154] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(602), 1,
-      anon_sym_of,
+      sym_frequency,
   [11161] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(604), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11168] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(606), 1,
-      anon_sym_RPAREN,
+      anon_sym_DOT,
   [11175] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(608), 1,
-      anon_sym_COLON,
+      sym_identifier,
   [11182] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(610), 1,
-      anon_sym_DOT,
+      anon_sym_EQ_GT,
   [11189] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(612), 1,
-      anon_sym_COLON,
+      anon_sym_Hz,
   [11196] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(614), 1,
-      anon_sym_EQ_GT,
+      anon_sym_RBRACK,
   [11203] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(616), 1,
-      ts_builtin_sym_end,
+      anon_sym_DOT,
   [11210] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(618), 1,
-      anon_sym_DOT,
+      anon_sym_RBRACK,
   [11217] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(620), 1,
-      anon_sym_RBRACK,
+      anon_sym_RPAREN,
   [11224] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(622), 1,
-      anon_sym_RBRACK,
+      anon_sym_RPAREN,
   [11231] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(624), 1,
-      anon_sym_RPAREN,
+      anon_sym_DOT,
   [11238] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(626), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11245] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(628), 1,
-      anon_sym_DOT,
+      sym_identifier,
   [11252] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(630), 1,
-      sym_identifier,
+      anon_sym_inr,
   [11259] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(632), 1,
-      sym_identifier,
+      anon_sym_inl,
   [11266] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(634), 1,
-      aux_sym_size_token1,
+      anon_sym_LPAREN,
   [11273] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(636), 1,
-      anon_sym_DOT,
+      anon_sym_LPAREN,
   [11280] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(638), 1,
-      anon_sym_inr,
+      anon_sym_RPAREN,
   [11287] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(640), 1,
-      sym_identifier,
+      anon_sym_frequency,
   [11294] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16935,22 +16938,22 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(644), 1,
-      sym_identifier,
+      anon_sym_and,
   [11308] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(646), 1,
-      anon_sym_and,
+      anon_sym_EQ,
   [11315] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(648), 1,
-      anon_sym_LPAREN,
+      sym_identifier,
   [11322] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(650), 1,
-      anon_sym_DOT,
+      anon_sym_COLON,
   [11329] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16965,7 +16968,7 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(656), 1,
-      sym_frequency,
+      anon_sym_LPAREN,
   [11350] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16975,17 +16978,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(660), 1,
-      anon_sym_EQ_GT,
+      anon_sym_DOT,
   [11364] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(662), 1,
-      sym_identifier,
+      anon_sym_RBRACK,
   [11371] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(664), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11378] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -16995,27 +16998,27 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(668), 1,
-      anon_sym_LPAREN,
+      sym_identifier,
   [11392] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(670), 1,
-      anon_sym_EQ,
+      sym_identifier,
   [11399] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(672), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11406] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(674), 1,
-      sym_identifier,
+      anon_sym_RPAREN,
   [11413] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(676), 1,
-      anon_sym_RPAREN,
+      anon_sym_LPAREN,
   [11420] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17025,17 +17028,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(680), 1,
-      anon_sym_LPAREN,
+      anon_sym_COLON,
   [11434] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(682), 1,
-      anon_sym_COLON,
+      sym_identifier,
   [11441] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(684), 1,
-      sym_identifier,
+      anon_sym_LPAREN,
   [11448] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17045,17 +17048,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(688), 1,
-      anon_sym_LPAREN,
+      anon_sym_CARET,
   [11462] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(690), 1,
-      anon_sym_LPAREN,
+      anon_sym_RPAREN,
   [11469] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(692), 1,
-      sym_identifier,
+      anon_sym_EQ,
   [11476] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17065,12 +17068,12 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(696), 1,
-      anon_sym_EQ,
+      anon_sym_EQ_GT,
   [11490] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(698), 1,
-      anon_sym_EQ_GT,
+      sym_identifier,
   [11497] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17085,17 +17088,17 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(704), 1,
-      anon_sym_LPAREN,
+      anon_sym_COLON,
   [11518] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(706), 1,
-      anon_sym_COLON,
+      anon_sym_EQ_GT,
   [11525] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(708), 1,
-      sym_identifier,
+      anon_sym_CARET,
   [11532] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17105,47 +17108,47 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(712), 1,
-      anon_sym_CARET,
+      sym_identifier,
   [11546] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(714), 1,
-      sym_identifier,
+      aux_sym_size_token1,
   [11553] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(716), 1,
-      sym_identifier,
+      anon_sym_DOT,
   [11560] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(718), 1,
-      sym_identifier,
+      anon_sym_DOT,
   [11567] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(720), 1,
-      anon_sym_COLON,
+      sym_identifier,
   [11574] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(722), 1,
-      sym_identifier,
+      anon_sym_RPAREN,
   [11581] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(724), 1,
-      anon_sym_RPAREN,
+      sym_identifier,
   [11588] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(726), 1,
-      sym_identifier,
+      anon_sym_COLON,
   [11595] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(728), 1,
-      anon_sym_inl,
+      anon_sym_CARET,
   [11602] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17155,47 +17158,47 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(732), 1,
-      anon_sym_CARET,
+      sym_identifier,
   [11616] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(734), 1,
-      sym_identifier,
+      anon_sym_LPAREN,
   [11623] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(736), 1,
-      anon_sym_LPAREN,
+      anon_sym_SEMI_SEMI,
   [11630] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(738), 1,
-      anon_sym_and,
+      sym_identifier,
   [11637] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(740), 1,
-      sym_identifier,
+      anon_sym_inr,
   [11644] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(742), 1,
-      anon_sym_inr,
+      anon_sym_CARET,
   [11651] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(744), 1,
-      anon_sym_CARET,
+      sym_identifier,
   [11658] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(746), 1,
-      anon_sym_EQ,
+      anon_sym_COMMA,
   [11665] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(748), 1,
-      anon_sym_COMMA,
+      anon_sym_LPAREN,
   [11672] = 2,
     ACTIONS(3), 1,
       sym_comment,
@@ -17205,52 +17208,52 @@ static const uint16_t ts_small_parse_table[] = {
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(752), 1,
-      sym_identifier,
+      aux_sym_literal_token2,
   [11686] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(754), 1,
-      anon_sym_COMMA,
+      anon_sym_EQ_GT,
   [11693] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(756), 1,
-      anon_sym_EQ_GT,
+      sym_identifier,
   [11700] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(758), 1,
-      sym_identifier,
+      anon_sym_inl,
   [11707] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(760), 1,
-      anon_sym_inl,
+      sym_identifier,
   [11714] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(762), 1,
-      sym_identifier,
+      anon_sym_CARET,
   [11721] = 2,
     ACTIONS(3), 1,
       sym_comment,
     ACTIONS(764), 1,
-      anon_sym_and,
+      ts_builtin_sym_end,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(63)] = 0,
   [SMALL_STATE(64)] = 64,
   [SMALL_STATE(65)] = 134,
-  [SMALL_STATE(66)] = 202,
-  [SMALL_STATE(67)] = 268,
-  [SMALL_STATE(68)] = 332,
-  [SMALL_STATE(69)] = 402,
-  [SMALL_STATE(70)] = 466,
-  [SMALL_STATE(71)] = 530,
-  [SMALL_STATE(72)] = 594,
-  [SMALL_STATE(73)] = 658,
-  [SMALL_STATE(74)] = 722,
+  [SMALL_STATE(66)] = 198,
+  [SMALL_STATE(67)] = 262,
+  [SMALL_STATE(68)] = 326,
+  [SMALL_STATE(69)] = 390,
+  [SMALL_STATE(70)] = 454,
+  [SMALL_STATE(71)] = 518,
+  [SMALL_STATE(72)] = 582,
+  [SMALL_STATE(73)] = 648,
+  [SMALL_STATE(74)] = 716,
   [SMALL_STATE(75)] = 786,
   [SMALL_STATE(76)] = 856,
   [SMALL_STATE(77)] = 926,
@@ -17264,19 +17267,19 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(85)] = 1430,
   [SMALL_STATE(86)] = 1493,
   [SMALL_STATE(87)] = 1556,
-  [SMALL_STATE(88)] = 1614,
-  [SMALL_STATE(89)] = 1672,
-  [SMALL_STATE(90)] = 1736,
-  [SMALL_STATE(91)] = 1794,
-  [SMALL_STATE(92)] = 1854,
-  [SMALL_STATE(93)] = 1918,
-  [SMALL_STATE(94)] = 1976,
+  [SMALL_STATE(88)] = 1620,
+  [SMALL_STATE(89)] = 1684,
+  [SMALL_STATE(90)] = 1742,
+  [SMALL_STATE(91)] = 1800,
+  [SMALL_STATE(92)] = 1858,
+  [SMALL_STATE(93)] = 1916,
+  [SMALL_STATE(94)] = 1974,
   [SMALL_STATE(95)] = 2034,
-  [SMALL_STATE(96)] = 2092,
-  [SMALL_STATE(97)] = 2150,
-  [SMALL_STATE(98)] = 2214,
-  [SMALL_STATE(99)] = 2278,
-  [SMALL_STATE(100)] = 2336,
+  [SMALL_STATE(96)] = 2096,
+  [SMALL_STATE(97)] = 2160,
+  [SMALL_STATE(98)] = 2218,
+  [SMALL_STATE(99)] = 2276,
+  [SMALL_STATE(100)] = 2334,
   [SMALL_STATE(101)] = 2398,
   [SMALL_STATE(102)] = 2455,
   [SMALL_STATE(103)] = 2512,
@@ -17389,21 +17392,21 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(210)] = 10199,
   [SMALL_STATE(211)] = 10221,
   [SMALL_STATE(212)] = 10243,
-  [SMALL_STATE(213)] = 10255,
-  [SMALL_STATE(214)] = 10269,
-  [SMALL_STATE(215)] = 10281,
-  [SMALL_STATE(216)] = 10293,
-  [SMALL_STATE(217)] = 10311,
-  [SMALL_STATE(218)] = 10323,
-  [SMALL_STATE(219)] = 10335,
-  [SMALL_STATE(220)] = 10353,
-  [SMALL_STATE(221)] = 10365,
-  [SMALL_STATE(222)] = 10377,
-  [SMALL_STATE(223)] = 10393,
-  [SMALL_STATE(224)] = 10405,
+  [SMALL_STATE(213)] = 10259,
+  [SMALL_STATE(214)] = 10271,
+  [SMALL_STATE(215)] = 10289,
+  [SMALL_STATE(216)] = 10307,
+  [SMALL_STATE(217)] = 10325,
+  [SMALL_STATE(218)] = 10337,
+  [SMALL_STATE(219)] = 10349,
+  [SMALL_STATE(220)] = 10361,
+  [SMALL_STATE(221)] = 10373,
+  [SMALL_STATE(222)] = 10385,
+  [SMALL_STATE(223)] = 10397,
+  [SMALL_STATE(224)] = 10409,
   [SMALL_STATE(225)] = 10423,
   [SMALL_STATE(226)] = 10439,
-  [SMALL_STATE(227)] = 10449,
+  [SMALL_STATE(227)] = 10455,
   [SMALL_STATE(228)] = 10465,
   [SMALL_STATE(229)] = 10481,
   [SMALL_STATE(230)] = 10497,
@@ -17415,8 +17418,8 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(236)] = 10593,
   [SMALL_STATE(237)] = 10609,
   [SMALL_STATE(238)] = 10625,
-  [SMALL_STATE(239)] = 10635,
-  [SMALL_STATE(240)] = 10645,
+  [SMALL_STATE(239)] = 10641,
+  [SMALL_STATE(240)] = 10651,
   [SMALL_STATE(241)] = 10661,
   [SMALL_STATE(242)] = 10677,
   [SMALL_STATE(243)] = 10693,
@@ -17431,12 +17434,12 @@ static const uint32_t ts_small_parse_table_map[] = {
   [SMALL_STATE(252)] = 10837,
   [SMALL_STATE(253)] = 10850,
   [SMALL_STATE(254)] = 10861,
-  [SMALL_STATE(255)] = 10872,
-  [SMALL_STATE(256)] = 10883,
+  [SMALL_STATE(255)] = 10874,
+  [SMALL_STATE(256)] = 10885,
   [SMALL_STATE(257)] = 10896,
   [SMALL_STATE(258)] = 10906,
   [SMALL_STATE(259)] = 10914,
-  [SMALL_STATE(260)] = 10924,
+  [SMALL_STATE(260)] = 10922,
   [SMALL_STATE(261)] = 10932,
   [SMALL_STATE(262)] = 10942,
   [SMALL_STATE(263)] = 10952,
@@ -17555,376 +17558,376 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [0] = {.entry = {.count = 0, .reusable = false}},
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
-  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(267),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [5] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(292),
   [11] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_delay_expression, 2, .production_id = 6),
   [13] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_delay_expression, 2, .production_id = 6),
   [15] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_force_expression, 2, .production_id = 6),
   [17] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_force_expression, 2, .production_id = 6),
-  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_application_expression, 2, .production_id = 9),
-  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application_expression, 2, .production_id = 9),
-  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binop_expression, 3, .production_id = 18),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binop_expression, 3, .production_id = 18),
-  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(256),
-  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(227),
-  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(131),
-  [33] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
-  [35] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
-  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(273),
-  [39] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
-  [41] = {.entry = {.count = 1, .reusable = true}}, SHIFT(274),
-  [43] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
-  [45] = {.entry = {.count = 1, .reusable = false}}, SHIFT(137),
-  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(138),
-  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(139),
-  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(140),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
-  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(115),
-  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
-  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
-  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(145),
-  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
-  [67] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
-  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(198),
-  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_intro, 4, .production_id = 21),
-  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
-  [75] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_intro, 4, .production_id = 21),
-  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(335),
-  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
-  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
-  [85] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda_expression, 4, .production_id = 22),
-  [87] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda_expression, 4, .production_id = 22),
-  [89] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unbox_expression, 2, .production_id = 6),
-  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unbox_expression, 2, .production_id = 6),
-  [93] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_elim, 9, .production_id = 29),
-  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_elim, 9, .production_id = 29),
-  [97] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_box_expression, 2, .production_id = 6),
-  [99] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_box_expression, 2, .production_id = 6),
-  [101] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unpair_expression, 10, .production_id = 30),
-  [103] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unpair_expression, 10, .production_id = 30),
-  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lob_expression, 8, .production_id = 28),
-  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lob_expression, 8, .production_id = 28),
-  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 8, .production_id = 27),
-  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_expression, 8, .production_id = 27),
-  [113] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inr_expression, 2, .production_id = 6),
-  [115] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inr_expression, 2, .production_id = 6),
-  [117] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inl_expression, 2, .production_id = 6),
-  [119] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inl_expression, 2, .production_id = 6),
-  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_gen_expression, 3, .production_id = 17),
-  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_gen_expression, 3, .production_id = 17),
-  [125] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ungen_expression, 2, .production_id = 6),
-  [127] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ungen_expression, 2, .production_id = 6),
-  [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 6, .production_id = 26),
-  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_expression, 6, .production_id = 26),
+  [19] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inl_expression, 2, .production_id = 6),
+  [21] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inl_expression, 2, .production_id = 6),
+  [23] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_inr_expression, 2, .production_id = 6),
+  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_inr_expression, 2, .production_id = 6),
+  [27] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ungen_expression, 2, .production_id = 6),
+  [29] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ungen_expression, 2, .production_id = 6),
+  [31] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_box_expression, 2, .production_id = 6),
+  [33] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_box_expression, 2, .production_id = 6),
+  [35] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unbox_expression, 2, .production_id = 6),
+  [37] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unbox_expression, 2, .production_id = 6),
+  [39] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_application_expression, 2, .production_id = 9),
+  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application_expression, 2, .production_id = 9),
+  [43] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_binop_expression, 3, .production_id = 18),
+  [45] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_binop_expression, 3, .production_id = 18),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(252),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(230),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(153),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
+  [57] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [59] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
+  [61] = {.entry = {.count = 1, .reusable = true}}, SHIFT(370),
+  [63] = {.entry = {.count = 1, .reusable = true}}, SHIFT(266),
+  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(154),
+  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(127),
+  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(145),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(120),
+  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(111),
+  [75] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
+  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(115),
+  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(146),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [85] = {.entry = {.count = 1, .reusable = false}}, SHIFT(125),
+  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(178),
+  [91] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_intro, 4, .production_id = 21),
+  [93] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [95] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_intro, 4, .production_id = 21),
+  [97] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
+  [99] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
+  [101] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
+  [103] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [105] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_gen_expression, 3, .production_id = 17),
+  [107] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_gen_expression, 3, .production_id = 17),
+  [109] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 6, .production_id = 26),
+  [111] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_expression, 6, .production_id = 26),
+  [113] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_let_expression, 8, .production_id = 27),
+  [115] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_let_expression, 8, .production_id = 27),
+  [117] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lob_expression, 8, .production_id = 28),
+  [119] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lob_expression, 8, .production_id = 28),
+  [121] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_elim, 9, .production_id = 29),
+  [123] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_elim, 9, .production_id = 29),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_unpair_expression, 10, .production_id = 30),
+  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_unpair_expression, 10, .production_id = 30),
+  [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda_expression, 4, .production_id = 22),
+  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda_expression, 4, .production_id = 22),
   [133] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
   [135] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_inner, 1, .production_id = 7),
-  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(80),
-  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(124),
-  [141] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
-  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
-  [145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_inner, 2, .production_id = 14),
-  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(238),
-  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(171),
-  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(252),
-  [153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(228),
-  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(168),
+  [137] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
+  [139] = {.entry = {.count = 1, .reusable = true}}, SHIFT(117),
+  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_inner, 2, .production_id = 14),
+  [143] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
+  [145] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
+  [147] = {.entry = {.count = 1, .reusable = true}}, SHIFT(77),
+  [149] = {.entry = {.count = 1, .reusable = false}}, SHIFT(198),
+  [151] = {.entry = {.count = 1, .reusable = false}}, SHIFT(254),
+  [153] = {.entry = {.count = 1, .reusable = false}}, SHIFT(251),
+  [155] = {.entry = {.count = 1, .reusable = false}}, SHIFT(159),
   [157] = {.entry = {.count = 1, .reusable = false}}, SHIFT(110),
   [159] = {.entry = {.count = 1, .reusable = false}}, SHIFT(105),
-  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(281),
+  [161] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
   [163] = {.entry = {.count = 1, .reusable = true}}, SHIFT(110),
-  [165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(312),
-  [167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(362),
+  [165] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
+  [167] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
   [169] = {.entry = {.count = 1, .reusable = false}}, SHIFT(157),
-  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
-  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(153),
-  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(152),
-  [177] = {.entry = {.count = 1, .reusable = false}}, SHIFT(142),
-  [179] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
-  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
-  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
-  [185] = {.entry = {.count = 1, .reusable = false}}, SHIFT(148),
-  [187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(147),
-  [189] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
-  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
-  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
-  [195] = {.entry = {.count = 1, .reusable = false}}, SHIFT(133),
-  [197] = {.entry = {.count = 1, .reusable = true}}, SHIFT(133),
-  [199] = {.entry = {.count = 1, .reusable = false}}, SHIFT(132),
-  [201] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
-  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(361),
-  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
-  [207] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
-  [209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(117),
-  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(120),
-  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(156),
-  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
-  [217] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
-  [219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(126),
-  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(354),
-  [223] = {.entry = {.count = 1, .reusable = false}}, SHIFT(170),
-  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(164),
-  [227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(370),
-  [229] = {.entry = {.count = 1, .reusable = true}}, SHIFT(309),
-  [231] = {.entry = {.count = 1, .reusable = false}}, SHIFT(163),
-  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
-  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(226),
-  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wrap_type, 3, .production_id = 1),
-  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wrap_type, 3, .production_id = 1),
-  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_annotate_expression, 3, .production_id = 16),
-  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_annotate_expression, 3, .production_id = 16),
-  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
-  [247] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
-  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
-  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sum_type, 3, .production_id = 2),
-  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sum_type, 3, .production_id = 2),
-  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 3, .production_id = 2),
-  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 3, .production_id = 2),
-  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_type, 5, .production_id = 11),
-  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 5, .production_id = 11),
-  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_type, 4, .production_id = 5),
-  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_type, 4, .production_id = 5),
-  [267] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_box_type, 2, .production_id = 1),
-  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_box_type, 2, .production_id = 1),
-  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type, 1),
-  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1),
-  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
-  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
-  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_type, 1),
-  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_type, 1),
-  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stream_type, 6, .production_id = 19),
-  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stream_type, 6, .production_id = 19),
-  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_later_type, 6, .production_id = 19),
-  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_later_type, 6, .production_id = 19),
-  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forall_type, 6, .production_id = 20),
-  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forall_type, 6, .production_id = 20),
-  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_type, 3, .production_id = 3),
-  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_type, 3, .production_id = 3),
-  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1),
-  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1),
-  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_expression, 13, .production_id = 31),
-  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_expression, 13, .production_id = 31),
-  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_expression, 3, .production_id = 13),
-  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_expression, 3, .production_id = 13),
-  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wrap_expression, 3, .production_id = 6),
-  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wrap_expression, 3, .production_id = 6),
-  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
-  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [171] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [173] = {.entry = {.count = 1, .reusable = false}}, SHIFT(121),
+  [175] = {.entry = {.count = 1, .reusable = false}}, SHIFT(158),
+  [177] = {.entry = {.count = 1, .reusable = false}}, SHIFT(166),
+  [179] = {.entry = {.count = 1, .reusable = false}}, SHIFT(169),
+  [181] = {.entry = {.count = 1, .reusable = true}}, SHIFT(112),
+  [183] = {.entry = {.count = 1, .reusable = true}}, SHIFT(168),
+  [185] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [187] = {.entry = {.count = 1, .reusable = false}}, SHIFT(122),
+  [189] = {.entry = {.count = 1, .reusable = false}}, SHIFT(136),
+  [191] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [193] = {.entry = {.count = 1, .reusable = true}}, SHIFT(333),
+  [195] = {.entry = {.count = 1, .reusable = true}}, SHIFT(142),
+  [197] = {.entry = {.count = 1, .reusable = false}}, SHIFT(143),
+  [199] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [201] = {.entry = {.count = 1, .reusable = false}}, SHIFT(144),
+  [203] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [205] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
+  [207] = {.entry = {.count = 1, .reusable = true}}, SHIFT(82),
+  [209] = {.entry = {.count = 1, .reusable = false}}, SHIFT(306),
+  [211] = {.entry = {.count = 1, .reusable = false}}, SHIFT(156),
+  [213] = {.entry = {.count = 1, .reusable = false}}, SHIFT(118),
+  [215] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
+  [217] = {.entry = {.count = 1, .reusable = false}}, SHIFT(123),
+  [219] = {.entry = {.count = 1, .reusable = false}}, SHIFT(369),
+  [221] = {.entry = {.count = 1, .reusable = false}}, SHIFT(124),
+  [223] = {.entry = {.count = 1, .reusable = true}}, SHIFT(106),
+  [225] = {.entry = {.count = 1, .reusable = false}}, SHIFT(129),
+  [227] = {.entry = {.count = 1, .reusable = false}}, SHIFT(131),
+  [229] = {.entry = {.count = 1, .reusable = false}}, SHIFT(135),
+  [231] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
+  [233] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
+  [235] = {.entry = {.count = 1, .reusable = true}}, SHIFT(227),
+  [237] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_type, 5, .production_id = 11),
+  [239] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_type, 5, .production_id = 11),
+  [241] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_forall_type, 6, .production_id = 20),
+  [243] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_forall_type, 6, .production_id = 20),
+  [245] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [247] = {.entry = {.count = 1, .reusable = false}}, SHIFT(174),
+  [249] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_var_type, 1),
+  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_var_type, 1),
+  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
+  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
+  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_type, 1),
+  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_type, 1),
+  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_stream_type, 6, .production_id = 19),
+  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_stream_type, 6, .production_id = 19),
+  [267] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_later_type, 6, .production_id = 19),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_later_type, 6, .production_id = 19),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_box_type, 2, .production_id = 1),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_box_type, 2, .production_id = 1),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wrap_type, 3, .production_id = 1),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wrap_type, 3, .production_id = 1),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_product_type, 3, .production_id = 2),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_product_type, 3, .production_id = 2),
+  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sum_type, 3, .production_id = 2),
+  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sum_type, 3, .production_id = 2),
+  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_type, 3, .production_id = 3),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_type, 3, .production_id = 3),
+  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ex_type, 4, .production_id = 5),
+  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ex_type, 4, .production_id = 5),
+  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_annotate_expression, 3, .production_id = 16),
+  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_annotate_expression, 3, .production_id = 16),
+  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_case_expression, 13, .production_id = 31),
+  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_case_expression, 13, .production_id = 31),
+  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_clockapp_expression, 5, .production_id = 24),
+  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clockapp_expression, 5, .production_id = 24),
+  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 2),
+  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 2),
+  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 1),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 1),
+  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_typeapp_expression, 5, .production_id = 25),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typeapp_expression, 5, .production_id = 25),
   [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pair_expression, 5, .production_id = 23),
   [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pair_expression, 5, .production_id = 23),
-  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_clockapp_expression, 5, .production_id = 24),
-  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clockapp_expression, 5, .production_id = 24),
-  [327] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_literal, 2),
-  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_literal, 2),
-  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_expression, 2),
-  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_expression, 2),
-  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_typeapp_expression, 5, .production_id = 25),
-  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typeapp_expression, 5, .production_id = 25),
-  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
-  [341] = {.entry = {.count = 1, .reusable = false}}, SHIFT(185),
-  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
-  [345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
-  [347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(85),
-  [349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
-  [351] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(256),
-  [354] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(227),
-  [357] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(131),
-  [360] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(81),
-  [363] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(77),
-  [366] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(273),
-  [369] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(81),
-  [372] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(274),
-  [375] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(275),
-  [378] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(137),
-  [381] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(138),
-  [384] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(139),
-  [387] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(140),
+  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_expression, 3, .production_id = 13),
+  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_expression, 3, .production_id = 13),
+  [327] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_array_expression, 2),
+  [329] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_array_expression, 2),
+  [331] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [333] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [335] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_wrap_expression, 3, .production_id = 6),
+  [337] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_wrap_expression, 3, .production_id = 6),
+  [339] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [341] = {.entry = {.count = 1, .reusable = false}}, SHIFT(192),
+  [343] = {.entry = {.count = 1, .reusable = true}}, SHIFT(193),
+  [345] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [347] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
+  [349] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
+  [351] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(252),
+  [354] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(230),
+  [357] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(153),
+  [360] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(85),
+  [363] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(80),
+  [366] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(366),
+  [369] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(85),
+  [372] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(370),
+  [375] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(266),
+  [378] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(154),
+  [381] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(127),
+  [384] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(145),
+  [387] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(120),
   [390] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(111),
-  [393] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(166),
-  [396] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(115),
-  [399] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(125),
+  [393] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(128),
+  [396] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(133),
+  [399] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(115),
   [402] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 15), SHIFT_REPEAT(146),
   [405] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
-  [407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
-  [409] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
-  [411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(177),
-  [413] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
-  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
-  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(356),
-  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
-  [421] = {.entry = {.count = 1, .reusable = false}}, SHIFT(357),
-  [423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(323),
-  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
-  [427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
-  [429] = {.entry = {.count = 1, .reusable = false}}, SHIFT(181),
-  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
-  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
-  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(346),
-  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
-  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(347),
-  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(306),
-  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
-  [445] = {.entry = {.count = 1, .reusable = false}}, SHIFT(218),
-  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(208),
-  [449] = {.entry = {.count = 1, .reusable = false}}, SHIFT(221),
-  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(271),
-  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
-  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
-  [457] = {.entry = {.count = 1, .reusable = false}}, SHIFT(268),
-  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(332),
+  [407] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
+  [409] = {.entry = {.count = 1, .reusable = false}}, SHIFT(222),
+  [411] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [413] = {.entry = {.count = 1, .reusable = false}}, SHIFT(223),
+  [415] = {.entry = {.count = 1, .reusable = true}}, SHIFT(371),
+  [417] = {.entry = {.count = 1, .reusable = true}}, SHIFT(334),
+  [419] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [421] = {.entry = {.count = 1, .reusable = false}}, SHIFT(269),
+  [423] = {.entry = {.count = 1, .reusable = true}}, SHIFT(270),
+  [425] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [427] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
+  [429] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [431] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
+  [433] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
+  [435] = {.entry = {.count = 1, .reusable = true}}, SHIFT(345),
+  [437] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [439] = {.entry = {.count = 1, .reusable = false}}, SHIFT(346),
+  [441] = {.entry = {.count = 1, .reusable = true}}, SHIFT(304),
+  [443] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
+  [445] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [447] = {.entry = {.count = 1, .reusable = false}}, SHIFT(172),
+  [449] = {.entry = {.count = 1, .reusable = false}}, SHIFT(90),
+  [451] = {.entry = {.count = 1, .reusable = true}}, SHIFT(354),
+  [453] = {.entry = {.count = 1, .reusable = true}}, SHIFT(355),
+  [455] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [457] = {.entry = {.count = 1, .reusable = false}}, SHIFT(356),
+  [459] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
   [461] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 7),
   [463] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_array_inner_repeat1, 2, .production_id = 7),
-  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2),
-  [467] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(267),
-  [470] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(371),
-  [473] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(365),
-  [476] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
-  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
-  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
-  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
-  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
-  [486] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_let, 7, .production_id = 8),
-  [488] = {.entry = {.count = 1, .reusable = true}}, SHIFT(260),
-  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
-  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
-  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
-  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(161),
-  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(86),
-  [500] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_def, 7, .production_id = 8),
-  [502] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_clock, 7, .production_id = 10),
-  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
-  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(109),
-  [508] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
-  [510] = {.entry = {.count = 1, .reusable = true}}, SHIFT(99),
-  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
-  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
-  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(220),
-  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(317),
-  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(366),
+  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source_file, 1),
+  [467] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2),
+  [469] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(323),
+  [472] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(325),
+  [475] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_file_repeat1, 2), SHIFT_REPEAT(292),
+  [478] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [480] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [482] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [484] = {.entry = {.count = 1, .reusable = true}}, SHIFT(261),
+  [486] = {.entry = {.count = 1, .reusable = true}}, SHIFT(134),
+  [488] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_def, 7, .production_id = 8),
+  [490] = {.entry = {.count = 1, .reusable = true}}, SHIFT(81),
+  [492] = {.entry = {.count = 1, .reusable = true}}, SHIFT(71),
+  [494] = {.entry = {.count = 1, .reusable = true}}, SHIFT(259),
+  [496] = {.entry = {.count = 1, .reusable = true}}, SHIFT(264),
+  [498] = {.entry = {.count = 1, .reusable = true}}, SHIFT(108),
+  [500] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [502] = {.entry = {.count = 1, .reusable = true}}, SHIFT(221),
+  [504] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [506] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [508] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_let, 7, .production_id = 8),
+  [510] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_top_level_clock, 7, .production_id = 10),
+  [512] = {.entry = {.count = 1, .reusable = true}}, SHIFT(257),
+  [514] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [516] = {.entry = {.count = 1, .reusable = true}}, SHIFT(262),
+  [518] = {.entry = {.count = 1, .reusable = false}}, SHIFT(289),
+  [520] = {.entry = {.count = 1, .reusable = true}}, SHIFT(303),
   [522] = {.entry = {.count = 1, .reusable = false}}, SHIFT(263),
-  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(299),
-  [526] = {.entry = {.count = 1, .reusable = false}}, SHIFT(310),
-  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(311),
-  [530] = {.entry = {.count = 1, .reusable = false}}, SHIFT(262),
-  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [524] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [526] = {.entry = {.count = 1, .reusable = false}}, SHIFT(362),
+  [528] = {.entry = {.count = 1, .reusable = true}}, SHIFT(365),
+  [530] = {.entry = {.count = 1, .reusable = false}}, SHIFT(260),
+  [532] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
   [534] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock, 2, .production_id = 12),
   [536] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock, 1, .production_id = 4),
-  [538] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock_coeff, 1),
-  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
-  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(178),
-  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
-  [546] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
-  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
-  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
-  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
-  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
-  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
-  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(202),
-  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
-  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(314),
-  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
-  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(84),
-  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
-  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(316),
-  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(239),
-  [574] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_size, 1),
-  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(318),
-  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(223),
-  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
-  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(103),
-  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(67),
-  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(206),
-  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(205),
-  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(204),
-  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
-  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(128),
-  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(102),
-  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
-  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
-  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
-  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
-  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
-  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
-  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(118),
-  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
-  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
-  [616] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [618] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_kind, 1),
-  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
-  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(94),
-  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
-  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
-  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
-  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(297),
-  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(280),
-  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
-  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
-  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(305),
-  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(372),
-  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
-  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
-  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
-  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(233),
-  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
-  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
-  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
-  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
-  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
-  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
-  [662] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
-  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
-  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
-  [668] = {.entry = {.count = 1, .reusable = true}}, SHIFT(232),
-  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
-  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
-  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
-  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(321),
-  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
-  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
-  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(254),
-  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(269),
-  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(234),
-  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
-  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
-  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
-  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(159),
-  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
-  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
-  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
-  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
-  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(230),
-  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
-  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(322),
+  [538] = {.entry = {.count = 1, .reusable = true}}, SHIFT(207),
+  [540] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [542] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [544] = {.entry = {.count = 1, .reusable = true}}, SHIFT(138),
+  [546] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock_coeff, 1),
+  [548] = {.entry = {.count = 1, .reusable = true}}, SHIFT(347),
+  [550] = {.entry = {.count = 1, .reusable = true}}, SHIFT(350),
+  [552] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
+  [554] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
+  [556] = {.entry = {.count = 1, .reusable = true}}, SHIFT(295),
+  [558] = {.entry = {.count = 1, .reusable = true}}, SHIFT(315),
+  [560] = {.entry = {.count = 1, .reusable = true}}, SHIFT(320),
+  [562] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [564] = {.entry = {.count = 1, .reusable = true}}, SHIFT(200),
+  [566] = {.entry = {.count = 1, .reusable = true}}, SHIFT(78),
+  [568] = {.entry = {.count = 1, .reusable = true}}, SHIFT(219),
+  [570] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
+  [572] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [574] = {.entry = {.count = 1, .reusable = true}}, SHIFT(101),
+  [576] = {.entry = {.count = 1, .reusable = true}}, SHIFT(137),
+  [578] = {.entry = {.count = 1, .reusable = true}}, SHIFT(63),
+  [580] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [582] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [584] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [586] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [588] = {.entry = {.count = 1, .reusable = true}}, SHIFT(313),
+  [590] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [592] = {.entry = {.count = 1, .reusable = true}}, SHIFT(104),
+  [594] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
+  [596] = {.entry = {.count = 1, .reusable = true}}, SHIFT(310),
+  [598] = {.entry = {.count = 1, .reusable = true}}, SHIFT(275),
+  [600] = {.entry = {.count = 1, .reusable = true}}, SHIFT(107),
+  [602] = {.entry = {.count = 1, .reusable = true}}, SHIFT(296),
+  [604] = {.entry = {.count = 1, .reusable = true}}, SHIFT(288),
+  [606] = {.entry = {.count = 1, .reusable = true}}, SHIFT(130),
+  [608] = {.entry = {.count = 1, .reusable = true}}, SHIFT(319),
+  [610] = {.entry = {.count = 1, .reusable = true}}, SHIFT(140),
+  [612] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
+  [614] = {.entry = {.count = 1, .reusable = true}}, SHIFT(83),
+  [616] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [618] = {.entry = {.count = 1, .reusable = true}}, SHIFT(97),
+  [620] = {.entry = {.count = 1, .reusable = true}}, SHIFT(195),
+  [622] = {.entry = {.count = 1, .reusable = true}}, SHIFT(196),
+  [624] = {.entry = {.count = 1, .reusable = true}}, SHIFT(197),
+  [626] = {.entry = {.count = 1, .reusable = true}}, SHIFT(287),
+  [628] = {.entry = {.count = 1, .reusable = true}}, SHIFT(276),
+  [630] = {.entry = {.count = 1, .reusable = true}}, SHIFT(326),
+  [632] = {.entry = {.count = 1, .reusable = true}}, SHIFT(268),
+  [634] = {.entry = {.count = 1, .reusable = true}}, SHIFT(248),
+  [636] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [638] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [640] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
+  [642] = {.entry = {.count = 1, .reusable = true}}, SHIFT(285),
+  [644] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [646] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [648] = {.entry = {.count = 1, .reusable = true}}, SHIFT(258),
+  [650] = {.entry = {.count = 1, .reusable = true}}, SHIFT(256),
+  [652] = {.entry = {.count = 1, .reusable = true}}, SHIFT(247),
+  [654] = {.entry = {.count = 1, .reusable = true}}, SHIFT(293),
+  [656] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [658] = {.entry = {.count = 1, .reusable = true}}, SHIFT(116),
+  [660] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [662] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_size, 1),
+  [664] = {.entry = {.count = 1, .reusable = true}}, SHIFT(298),
+  [666] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
+  [668] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock_coeff, 3),
+  [670] = {.entry = {.count = 1, .reusable = true}}, SHIFT(272),
+  [672] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
+  [674] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [676] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [678] = {.entry = {.count = 1, .reusable = true}}, SHIFT(236),
+  [680] = {.entry = {.count = 1, .reusable = true}}, SHIFT(255),
+  [682] = {.entry = {.count = 1, .reusable = true}}, SHIFT(278),
+  [684] = {.entry = {.count = 1, .reusable = true}}, SHIFT(237),
+  [686] = {.entry = {.count = 1, .reusable = true}}, SHIFT(203),
+  [688] = {.entry = {.count = 1, .reusable = true}}, SHIFT(308),
+  [690] = {.entry = {.count = 1, .reusable = true}}, SHIFT(317),
+  [692] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [694] = {.entry = {.count = 1, .reusable = true}}, SHIFT(164),
+  [696] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [698] = {.entry = {.count = 1, .reusable = true}}, SHIFT(284),
+  [700] = {.entry = {.count = 1, .reusable = true}}, SHIFT(242),
+  [702] = {.entry = {.count = 1, .reusable = true}}, SHIFT(243),
+  [704] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [706] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [708] = {.entry = {.count = 1, .reusable = true}}, SHIFT(328),
   [710] = {.entry = {.count = 1, .reusable = true}}, SHIFT(329),
   [712] = {.entry = {.count = 1, .reusable = true}}, SHIFT(330),
-  [714] = {.entry = {.count = 1, .reusable = true}}, SHIFT(331),
-  [716] = {.entry = {.count = 1, .reusable = true}}, SHIFT(325),
-  [718] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_clock_coeff, 3),
-  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(253),
+  [714] = {.entry = {.count = 1, .reusable = true}}, SHIFT(324),
+  [716] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_kind, 1),
+  [718] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [720] = {.entry = {.count = 1, .reusable = true}}, SHIFT(336),
   [722] = {.entry = {.count = 1, .reusable = true}}, SHIFT(337),
   [724] = {.entry = {.count = 1, .reusable = true}}, SHIFT(338),
-  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(339),
-  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(327),
+  [726] = {.entry = {.count = 1, .reusable = true}}, SHIFT(199),
+  [728] = {.entry = {.count = 1, .reusable = true}}, SHIFT(340),
   [730] = {.entry = {.count = 1, .reusable = true}}, SHIFT(341),
   [732] = {.entry = {.count = 1, .reusable = true}}, SHIFT(342),
-  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(343),
-  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [734] = {.entry = {.count = 1, .reusable = true}}, SHIFT(249),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(240),
   [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(351),
   [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(352),
-  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(353),
-  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(358),
-  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
-  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(360),
-  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(291),
-  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(364),
-  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(344),
-  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [742] = {.entry = {.count = 1, .reusable = true}}, SHIFT(357),
+  [744] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [746] = {.entry = {.count = 1, .reusable = true}}, SHIFT(359),
+  [748] = {.entry = {.count = 1, .reusable = true}}, SHIFT(241),
+  [750] = {.entry = {.count = 1, .reusable = true}}, SHIFT(363),
+  [752] = {.entry = {.count = 1, .reusable = true}}, SHIFT(79),
+  [754] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [756] = {.entry = {.count = 1, .reusable = true}}, SHIFT(367),
   [758] = {.entry = {.count = 1, .reusable = true}}, SHIFT(368),
-  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(369),
-  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(294),
-  [764] = {.entry = {.count = 1, .reusable = true}}, SHIFT(348),
+  [760] = {.entry = {.count = 1, .reusable = true}}, SHIFT(349),
+  [762] = {.entry = {.count = 1, .reusable = true}}, SHIFT(307),
+  [764] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
 };
 
 #ifdef __cplusplus